@@ -0,0 +1,80 @@
+//! Microbenchmarks for the core board operations and move search.
+//!
+//! Run with `cargo bench --bench board`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use harmonomino::agent::find_best_move;
+use harmonomino::game::{Board, FallingPiece, Rotation, Tetromino};
+use harmonomino::weights;
+
+/// A mid-game board: a ragged stack with a few holes and one full row.
+fn midgame_board() -> Board {
+    let heights = [6, 5, 7, 4, 6, 3, 5, 6, 2, 0];
+    let mut board = Board::new();
+    for (col, &height) in heights.iter().enumerate() {
+        for row in 0..height {
+            board[row][col] = true;
+        }
+    }
+    board[1][2] = false;
+    board[2][5] = false;
+    board[0].fill(true);
+    board
+}
+
+/// Weights resembling a trained agent: penalize height, holes and bumpiness.
+const fn bench_weights() -> [f64; weights::NUM_WEIGHTS] {
+    let mut w = [-0.1; weights::NUM_WEIGHTS];
+    w[1] = -0.8;
+    w[3] = -0.3;
+    w[8] = -0.4;
+    w[9] = -0.5;
+    w
+}
+
+fn board_ops(c: &mut Criterion) {
+    let board = midgame_board();
+    let piece = FallingPiece {
+        tetromino: Tetromino::T,
+        rotation: Rotation(0),
+        col: 3,
+        row: 12,
+    };
+
+    c.bench_function("can_place", |b| {
+        b.iter(|| black_box(&board).can_place(black_box(&piece)));
+    });
+
+    c.bench_function("hard_drop", |b| {
+        b.iter(|| black_box(&board).hard_drop(black_box(&piece)));
+    });
+
+    c.bench_function("clear_full_rows", |b| {
+        b.iter_batched(
+            || board.clone(),
+            |mut board| board.clear_full_rows(),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn move_search(c: &mut Criterion) {
+    let board = midgame_board();
+    let weights = bench_weights();
+
+    c.bench_function("find_best_move", |b| {
+        b.iter(|| {
+            find_best_move(
+                black_box(&board),
+                black_box(Tetromino::T),
+                &weights,
+                weights::NUM_WEIGHTS,
+            )
+        });
+    });
+}
+
+criterion_group!(benches, board_ops, move_search);
+criterion_main!(benches);
@@ -232,9 +232,15 @@ def run_eval(tag: str, weight_paths: list[Path], eval_cfg: dict, n_weights: int,
 # ---------------------------------------------------------------------------
 
 
+def newest_output(stem: str) -> Path | None:
+    """Return the most recent timestamped `<stem>_*.csv` written by benchmark."""
+    candidates = sorted((ROOT / "results").glob(f"{stem}_*.csv"), key=lambda p: p.stat().st_mtime)
+    return candidates[-1] if candidates else None
+
+
 def run_sweep(param: str, cfg: dict, manifest: Manifest) -> None:
-    csv_name = f"benchmark_{param.replace('-', '_')}.csv"
-    output_in_results = ROOT / "results" / csv_name
+    stem = f"benchmark_{param.replace('-', '_')}"
+    csv_name = f"{stem}.csv"
     dest = RESULTS_DIR / csv_name
     dest_rel = f"results/{csv_name}"
 
@@ -260,8 +266,9 @@ def run_sweep(param: str, cfg: dict, manifest: Manifest) -> None:
     ]
     run_cmd(cmd)
 
-    # benchmark writes to <ROOT>/results/; move into experiments/results/
-    if output_in_results.exists():
+    # benchmark writes timestamped files to <ROOT>/results/; move into experiments/results/
+    output_in_results = newest_output(stem)
+    if output_in_results is not None:
         shutil.move(str(output_in_results), str(dest))
 
     manifest.record(dest_rel, h)
@@ -274,7 +281,6 @@ def run_sweep(param: str, cfg: dict, manifest: Manifest) -> None:
 
 def run_mass_optimize(cfg: dict, manifest: Manifest) -> None:
     dest = RESULTS_DIR / "optimized_weights.csv"
-    output_in_results = ROOT / "results" / "optimized_weights.csv"
     dest_rel = "results/optimized_weights.csv"
 
     h = manifest.config_hash(mass_optimize=cfg)
@@ -299,7 +305,8 @@ def run_mass_optimize(cfg: dict, manifest: Manifest) -> None:
     ]
     run_cmd(cmd)
 
-    if output_in_results.exists():
+    output_in_results = newest_output("optimized_weights")
+    if output_in_results is not None:
         shutil.move(str(output_in_results), str(dest))
 
     manifest.record(dest_rel, h)
@@ -0,0 +1,160 @@
+use crate::agent::simulator::{Placement, all_placements, compare_placements};
+use crate::game::{Board, Tetromino};
+use crate::weights;
+
+/// A partial line of play: the board after the latest placement, and that
+/// placement's score paired with the first placement that led to it.
+#[derive(Clone)]
+struct Node {
+    board: Board,
+    scored: (f64, Placement),
+}
+
+/// Finds the placement of `pieces[0]` that leads to the best board after
+/// looking ahead through the known queue with a beam search.
+///
+/// At each ply only the `width` best boards by weighted score are expanded
+/// with the next piece. The search stops after `depth` plies, when the queue
+/// runs out, or when no surviving board can take the next piece; the best
+/// board reached at the last completed ply decides the move.
+///
+/// With `depth <= 1` this picks the same placement score as
+/// [`find_best_placement`](super::find_best_placement).
+#[must_use]
+pub fn find_best_move_beam(
+    board: &Board,
+    pieces: &[Tetromino],
+    width: usize,
+    depth: usize,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> Option<Placement> {
+    let (&first_piece, rest) = pieces.split_first()?;
+    let width = width.max(1);
+
+    let mut beam: Vec<Node> = all_placements(board, first_piece, weights, n_weights)
+        .into_iter()
+        .map(|(score, placement)| Node {
+            board: placement.board.clone(),
+            scored: (score, placement),
+        })
+        .collect();
+    prune(&mut beam, width);
+
+    for &piece in rest.iter().take(depth.saturating_sub(1)) {
+        let mut next: Vec<Node> = beam
+            .iter()
+            .flat_map(|node| {
+                all_placements(&node.board, piece, weights, n_weights)
+                    .into_iter()
+                    .map(|(score, placement)| Node {
+                        board: placement.board,
+                        scored: (score, node.scored.1.clone()),
+                    })
+            })
+            .collect();
+        if next.is_empty() {
+            break;
+        }
+        prune(&mut next, width);
+        beam = next;
+    }
+
+    beam.into_iter().next().map(|node| node.scored.1)
+}
+
+/// Keeps the `width` best nodes, best first. Ties between leaves are broken
+/// by their first placement, as in [`compare_placements`].
+fn prune(nodes: &mut Vec<Node>, width: usize) {
+    nodes.sort_by(|a, b| compare_placements(&b.scored, &a.scored));
+    nodes.truncate(width);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::simulator::{find_best_placement, score_placement};
+
+    fn test_weights() -> [f64; weights::NUM_WEIGHTS] {
+        let mut w = [0.0; weights::NUM_WEIGHTS];
+        w[0] = -1.0; // pile height
+        w[1] = -4.0; // holes
+        w[3] = -0.5; // altitude diff
+        w
+    }
+
+    fn bumpy_board() -> Board {
+        let mut board = Board::new();
+        for (col, height) in [3, 1, 0, 2, 4, 1, 1, 0, 2, 3].into_iter().enumerate() {
+            for row in 0..height {
+                board[row][col] = true;
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn depth_one_matches_find_best_placement() {
+        let weights = test_weights();
+        let board = bumpy_board();
+        let n = weights::NUM_WEIGHTS;
+
+        for piece in Tetromino::ALL {
+            let beam = find_best_move_beam(&board, &[piece], usize::MAX, 1, &weights, n)
+                .expect("beam placement");
+            let greedy = find_best_placement(&board, piece, &weights, n).expect("placement");
+
+            let score = |p: Placement| {
+                score_placement(&board, p.piece, &weights, n)
+                    .expect("legal placement")
+                    .0
+            };
+            assert!((score(beam) - score(greedy)).abs() < 1e-9, "{piece:?}");
+        }
+    }
+
+    #[test]
+    fn short_queue_falls_back_to_known_pieces() {
+        let weights = test_weights();
+        let board = bumpy_board();
+        let pieces = [Tetromino::T, Tetromino::I];
+        let n = weights::NUM_WEIGHTS;
+
+        let deep = find_best_move_beam(&board, &pieces, 4, 10, &weights, n);
+        let exact = find_best_move_beam(&board, &pieces, 4, 2, &weights, n);
+        assert_eq!(
+            deep.map(|p| p.piece.cells()),
+            exact.map(|p| p.piece.cells())
+        );
+    }
+
+    #[test]
+    fn lookahead_sets_up_a_line_clear() {
+        // Bottom row full except columns 0-3: two O pieces side by side clear it
+        let mut board = Board::new();
+        for col in 4..Board::WIDTH {
+            board[0][col] = true;
+        }
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[6] = -1.0; // blocks
+
+        let placement = find_best_move_beam(
+            &board,
+            &[Tetromino::O, Tetromino::O, Tetromino::O],
+            64,
+            3,
+            &weights,
+            weights::NUM_WEIGHTS,
+        )
+        .expect("placement");
+        assert!(placement.piece.cells().iter().all(|&(col, _)| col < 4));
+    }
+
+    #[test]
+    fn empty_queue_has_no_move() {
+        let weights = test_weights();
+        assert!(
+            find_best_move_beam(&Board::new(), &[], 3, 3, &weights, weights::NUM_WEIGHTS).is_none()
+        );
+    }
+}
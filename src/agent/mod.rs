@@ -1,3 +1,15 @@
+pub mod beam;
+pub mod noisy;
+pub mod profile;
 pub mod simulator;
+pub mod timed;
+pub mod versus;
 
-pub use simulator::find_best_move;
+pub use beam::find_best_move_beam;
+pub use noisy::find_best_move_noisy;
+pub use simulator::{
+    GameStats, Placement, ScoringMode, all_placements, find_best_move, find_best_placement,
+    worst_piece,
+};
+pub use timed::find_best_move_timed;
+pub use versus::{DuelResult, VersusSimulator};
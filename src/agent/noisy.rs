@@ -0,0 +1,92 @@
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::agent::simulator::{Placement, all_placements, compare_placements, find_best_move};
+use crate::game::{Board, Tetromino};
+use crate::weights;
+
+/// Finds a placement after adding Gaussian noise with standard deviation
+/// `temperature` to every placement's score.
+///
+/// Higher temperatures make the agent play more randomly, and so weaker. A
+/// temperature of zero (or below) is exactly [`find_best_move`] and draws
+/// nothing from `rng`.
+#[must_use]
+pub fn find_best_move_noisy<R: Rng + ?Sized>(
+    board: &Board,
+    piece: Tetromino,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+    temperature: f64,
+    rng: &mut R,
+) -> Option<(Board, u32)> {
+    if temperature <= 0.0 {
+        return find_best_move(board, piece, weights, n_weights);
+    }
+
+    all_placements(board, piece, weights, n_weights)
+        .into_iter()
+        .map(|(score, placement)| {
+            let noise: f64 = rng.sample(StandardNormal);
+            (temperature.mul_add(noise, score), placement)
+        })
+        .max_by(compare_placements)
+        .map(|(_, placement): (f64, Placement)| (placement.board, placement.rows_cleared))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn test_weights() -> [f64; weights::NUM_WEIGHTS] {
+        let mut w = [0.0; weights::NUM_WEIGHTS];
+        w[0] = -1.0; // pile height
+        w[1] = -4.0; // holes
+        w
+    }
+
+    fn stacked_board() -> Board {
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..(col % 4) {
+                board[row][col] = true;
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn zero_temperature_matches_find_best_move() {
+        let board = stacked_board();
+        let weights = test_weights();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+        for piece in Tetromino::ALL {
+            let noisy =
+                find_best_move_noisy(&board, piece, &weights, weights::NUM_WEIGHTS, 0.0, &mut rng);
+            let best = find_best_move(&board, piece, &weights, weights::NUM_WEIGHTS);
+            assert_eq!(noisy, best, "{piece:?}");
+        }
+    }
+
+    #[test]
+    fn high_temperature_sometimes_plays_worse() {
+        let board = stacked_board();
+        let weights = test_weights();
+        let best = find_best_move(&board, Tetromino::T, &weights, weights::NUM_WEIGHTS);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2);
+
+        let deviated = (0..20).any(|_| {
+            find_best_move_noisy(
+                &board,
+                Tetromino::T,
+                &weights,
+                weights::NUM_WEIGHTS,
+                1000.0,
+                &mut rng,
+            ) != best
+        });
+        assert!(deviated);
+    }
+}
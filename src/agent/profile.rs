@@ -0,0 +1,141 @@
+//! Move-search counters for `benchmark --profile`.
+//!
+//! With the `profile` feature, [`find_best_placement`](super::find_best_placement)
+//! records its wall-clock time and how many placements it scores. Without it
+//! the hooks are empty and compile away, and [`snapshot`] always returns zeros.
+
+use std::time::Duration;
+
+/// Whether the search is instrumented in this build.
+pub const ENABLED: bool = cfg!(feature = "profile");
+
+/// Totals recorded since the last [`reset`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchProfile {
+    /// Number of [`find_best_placement`](super::find_best_placement) calls.
+    pub searches: u64,
+    /// Placements scored, across all searches.
+    pub placements: u64,
+    /// Wall-clock time spent in searches.
+    pub search_time: Duration,
+    /// Time spent evaluating boards, summed over all threads.
+    pub eval_time: Duration,
+}
+
+impl SearchProfile {
+    /// Average search wall-clock time per scored placement.
+    #[must_use]
+    pub fn time_per_placement(&self) -> Duration {
+        u32::try_from(self.placements)
+            .ok()
+            .filter(|&n| n > 0)
+            .map_or(Duration::ZERO, |n| self.search_time / n)
+    }
+}
+
+#[cfg(feature = "profile")]
+mod counters {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{Duration, Instant};
+
+    use super::SearchProfile;
+
+    static SEARCHES: AtomicU64 = AtomicU64::new(0);
+    static PLACEMENTS: AtomicU64 = AtomicU64::new(0);
+    static SEARCH_NANOS: AtomicU64 = AtomicU64::new(0);
+    static EVAL_NANOS: AtomicU64 = AtomicU64::new(0);
+
+    fn add_elapsed(counter: &AtomicU64, start: Instant) {
+        let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        counter.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    pub fn reset() {
+        for counter in [&SEARCHES, &PLACEMENTS, &SEARCH_NANOS, &EVAL_NANOS] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub fn snapshot() -> SearchProfile {
+        SearchProfile {
+            searches: SEARCHES.load(Ordering::Relaxed),
+            placements: PLACEMENTS.load(Ordering::Relaxed),
+            search_time: Duration::from_nanos(SEARCH_NANOS.load(Ordering::Relaxed)),
+            eval_time: Duration::from_nanos(EVAL_NANOS.load(Ordering::Relaxed)),
+        }
+    }
+
+    pub fn time_search<T>(search: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = search();
+        add_elapsed(&SEARCH_NANOS, start);
+        SEARCHES.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    pub fn time_eval<T>(eval: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = eval();
+        add_elapsed(&EVAL_NANOS, start);
+        PLACEMENTS.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+}
+
+/// Clears all counters.
+#[cfg_attr(not(feature = "profile"), allow(clippy::missing_const_for_fn))]
+pub fn reset() {
+    #[cfg(feature = "profile")]
+    counters::reset();
+}
+
+/// Returns the totals recorded since the last [`reset`].
+#[must_use]
+pub fn snapshot() -> SearchProfile {
+    #[cfg(feature = "profile")]
+    return counters::snapshot();
+    #[cfg(not(feature = "profile"))]
+    SearchProfile::default()
+}
+
+/// Runs one move search, timing it when profiling.
+#[inline]
+pub(crate) fn time_search<T>(search: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "profile")]
+    return counters::time_search(search);
+    #[cfg(not(feature = "profile"))]
+    search()
+}
+
+/// Evaluates one placement, timing and counting it when profiling.
+#[inline]
+pub(crate) fn time_eval<T>(eval: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "profile")]
+    return counters::time_eval(eval);
+    #[cfg(not(feature = "profile"))]
+    eval()
+}
+
+#[cfg(all(test, feature = "profile"))]
+mod tests {
+    use super::*;
+    use crate::agent::find_best_placement;
+    use crate::game::{Board, Tetromino};
+    use crate::weights;
+
+    #[test]
+    fn search_records_placements_and_time() {
+        reset();
+        let weights = [-1.0; weights::NUM_WEIGHTS];
+        let placement =
+            find_best_placement(&Board::new(), Tetromino::T, &weights, weights::NUM_WEIGHTS);
+        assert!(placement.is_some());
+
+        // Counters are global, so other tests may add to them concurrently
+        let profile = snapshot();
+        assert!(profile.searches >= 1);
+        // T fits 8 columns flat (rotations 0 and 2) and 9 upright (1 and 3)
+        assert!(profile.placements >= 34);
+        assert!(profile.search_time > Duration::ZERO);
+    }
+}
@@ -1,8 +1,25 @@
-use crate::eval_fns::calculate_weighted_score_n;
-use crate::game::{Board, FallingPiece, GameState, Tetromino};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::agent::profile;
+use crate::eval_fns::{BoardSummary, EvalContext, calculate_weighted_score_ctx};
+use crate::game::{Board, FallingPiece, GameState, Rotation, Tetromino};
 use crate::weights;
+#[cfg(feature = "native")]
 use rayon::prelude::*;
 
+/// The best placement found for a piece.
+#[derive(Debug, Clone)]
+pub struct Placement {
+    /// The piece at its landing position.
+    pub piece: FallingPiece,
+    /// The resulting board, with full rows cleared.
+    pub board: Board,
+    pub rows_cleared: u32,
+}
+
 /// Finds the optimal placement for a piece on the given board.
 /// Returns the resulting board (with rows cleared) and the number of rows cleared.
 ///
@@ -10,55 +27,253 @@ use rayon::prelude::*;
 ///
 /// Panics if score comparison encounters NaN values.
 #[must_use]
-#[allow(clippy::cast_possible_truncation)]
 pub fn find_best_move(
     board: &Board,
     piece: Tetromino,
     weights: &[f64; weights::NUM_WEIGHTS],
     n_weights: usize,
 ) -> Option<(Board, u32)> {
-    let base_piece = FallingPiece::spawn(piece);
+    find_best_placement(board, piece, weights, n_weights).map(|p| (p.board, p.rows_cleared))
+}
 
-    let all_parallel_placements: Vec<_> = (0..4u8)
-        .flat_map(|rot_idx| (0..Board::HEIGHT).map(move |row_idx| (rot_idx, row_idx)))
-        .collect();
+/// Finds the optimal placement for a piece on the given board, including the
+/// landing position of the piece.
+///
+/// Every rotation and column is hard-dropped with [`Board::landing_for`];
+/// placements tucked under overhangs are not considered. Candidates are
+/// scored in parallel with the `native` feature and sequentially otherwise.
+/// Ties are broken by [`compare_placements`], so the result does not depend
+/// on scheduling.
+///
+/// # Panics
+///
+/// Panics if score comparison encounters NaN values.
+#[must_use]
+pub fn find_best_placement(
+    board: &Board,
+    piece: Tetromino,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> Option<Placement> {
+    let score_drop = |(rotation, col): (Rotation, i8)| {
+        let dropped = board.landing_for(piece, rotation, col)?;
+        score_placement(board, dropped, weights, n_weights)
+    };
 
-    let (best_score, best_board, best_rows_cleared) = all_parallel_placements
-        .into_par_iter()
-        .map(|(rot_idx, row_idx)| {
-            let mut local_max_score = -f64::INFINITY;
-            let mut local_best_board: Option<Board> = None;
-            let mut local_best_rows_cleared = 0;
+    profile::time_search(|| {
+        #[cfg(feature = "native")]
+        let candidates = drop_columns(board)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .filter_map(score_drop);
+        #[cfg(not(feature = "native"))]
+        let candidates = drop_columns(board).filter_map(score_drop);
 
-            let mut rotated_piece = base_piece;
-            rotated_piece.rotation = crate::game::Rotation(rot_idx);
-            rotated_piece.row = row_idx as i8;
+        candidates
+            .max_by(compare_placements)
+            .map(|(_, placement)| placement)
+    })
+}
 
-            for col_idx in 0..Board::WIDTH {
-                rotated_piece.col = col_idx as i8;
+/// Every `(rotation, col)` pair the move search hard-drops from on `board`.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn drop_columns(board: &Board) -> impl Iterator<Item = (Rotation, i8)> + use<> {
+    let width = board.width();
+    Rotation::all().flat_map(move |rotation| (0..width).map(move |col| (rotation, col as i8)))
+}
 
-                if board.can_lock(&rotated_piece) {
-                    let mut possible_board = board.with_piece(&rotated_piece);
-                    let current_rows_cleared = possible_board.clear_full_rows();
+/// Orders scored placements so that the better one compares greater.
+///
+/// Higher scores win. Equal scores prefer the lower landing row (the lowest
+/// cell of the piece), then the leftmost column (its leftmost cell), then the
+/// lower rotation index.
+///
+/// # Panics
+///
+/// Panics if either score is NaN.
+#[must_use]
+pub fn compare_placements(a: &(f64, Placement), b: &(f64, Placement)) -> Ordering {
+    let landing_row = |p: &Placement| p.piece.cells().iter().map(|&(_, row)| row).min();
+    let left_col = |p: &Placement| p.piece.cells().iter().map(|&(col, _)| col).min();
+    a.0.partial_cmp(&b.0)
+        .expect("NaN in score comparison")
+        .then_with(|| landing_row(&b.1).cmp(&landing_row(&a.1)))
+        .then_with(|| left_col(&b.1).cmp(&left_col(&a.1)))
+        .then_with(|| b.1.piece.rotation.0.cmp(&a.1.piece.rotation.0))
+}
 
-                    let score = calculate_weighted_score_n(&possible_board, weights, n_weights);
+/// Scores `piece` locked at its current position, or `None` if it cannot lock there.
+pub(crate) fn score_placement(
+    board: &Board,
+    piece: FallingPiece,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> Option<(f64, Placement)> {
+    if !board.can_lock(&piece) {
+        return None;
+    }
+    let pre_clear = board.with_piece(&piece);
+    let mut post_clear = pre_clear.clone();
+    let rows_cleared = post_clear.clear_full_rows();
 
-                    if score > local_max_score {
-                        local_max_score = score;
-                        local_best_board = Some(possible_board);
-                        local_best_rows_cleared = current_rows_cleared;
-                    }
-                }
+    let ctx = EvalContext {
+        pre_clear: &pre_clear,
+        post_clear: &post_clear,
+        summary: &BoardSummary::new(&post_clear),
+        piece,
+        rows_cleared,
+    };
+    let score = profile::time_eval(|| calculate_weighted_score_ctx(&ctx, weights, n_weights));
+    Some((
+        score,
+        Placement {
+            piece,
+            board: post_clear,
+            rows_cleared,
+        },
+    ))
+}
+
+/// Scores every placement of `piece` that [`find_best_placement`] considers.
+#[must_use]
+pub fn all_placements(
+    board: &Board,
+    piece: Tetromino,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> Vec<(f64, Placement)> {
+    drop_columns(board)
+        .filter_map(|(rotation, col)| board.landing_for(piece, rotation, col))
+        .filter_map(|dropped| score_placement(board, dropped, weights, n_weights))
+        .collect()
+}
+
+/// Picks the piece whose best placement on `board` scores lowest, a one-ply
+/// adversary. A piece that cannot be placed at all is the worst; ties go to
+/// the earliest piece in [`Tetromino::ALL`].
+#[must_use]
+pub fn worst_piece(
+    board: &Board,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> Tetromino {
+    let best_score = |piece: Tetromino| {
+        all_placements(board, piece, weights, n_weights)
+            .into_iter()
+            .max_by(compare_placements)
+            .map_or(f64::NEG_INFINITY, |(score, _)| score)
+    };
+    Tetromino::ALL
+        .into_iter()
+        .map(|piece| (best_score(piece), piece))
+        .reduce(|worst, next| if next.0 < worst.0 { next } else { worst })
+        .map_or(Tetromino::I, |(_, piece)| piece)
+}
+
+/// How a simulated game is turned into a fitness value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoringMode {
+    /// Rows cleared.
+    #[default]
+    Rows,
+    /// Rows cleared plus [`ScoringMode::SURVIVAL_BONUS_PER_PIECE`] for every piece placed:
+    ///
+    /// `fitness = rows_cleared + SURVIVAL_BONUS_PER_PIECE * pieces_placed`
+    ///
+    /// Games that reach `max_length` all receive the same full bonus, so it only
+    /// separates games that top out early.
+    Survival,
+}
+
+impl ScoringMode {
+    /// Fitness added per placed piece by [`ScoringMode::Survival`].
+    pub const SURVIVAL_BONUS_PER_PIECE: f64 = 0.01;
+
+    /// Computes the fitness of a finished game.
+    #[must_use]
+    pub fn fitness(self, rows_cleared: u32, pieces_placed: usize) -> f64 {
+        match self {
+            Self::Rows => f64::from(rows_cleared),
+            Self::Survival => {
+                let pieces = f64::from(u32::try_from(pieces_placed).unwrap_or(u32::MAX));
+                Self::SURVIVAL_BONUS_PER_PIECE.mul_add(pieces, f64::from(rows_cleared))
             }
-            (local_max_score, local_best_board, local_best_rows_cleared)
+        }
+    }
+}
+
+impl fmt::Display for ScoringMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Rows => "rows",
+            Self::Survival => "survival",
         })
-        .max_by(|a, b| a.0.partial_cmp(&b.0).expect("NaN in score comparison"))
-        .expect("Empty parallel iterator");
+    }
+}
+
+impl FromStr for ScoringMode {
+    type Err = String;
 
-    if best_score > -f64::INFINITY {
-        best_board.map(|b| (b, best_rows_cleared))
-    } else {
-        None
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rows" => Ok(Self::Rows),
+            "survival" => Ok(Self::Survival),
+            other => Err(format!(
+                "unknown scoring mode '{other}': expected rows or survival"
+            )),
+        }
+    }
+}
+
+/// Moves already chosen for one weight vector, keyed by board and piece.
+///
+/// Games with the same weights start from the same empty board and often
+/// repeat their first placements, so sharing a cache between them skips the
+/// repeated searches. Only reuse a cache with identical weights and
+/// `n_weights`.
+#[derive(Debug, Default)]
+pub struct MoveCache {
+    moves: HashMap<(Board, Tetromino), Option<(Board, u32)>>,
+}
+
+impl MoveCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached positions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+}
+
+/// Outcome of one simulated game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GameStats {
+    pub rows_cleared: u32,
+    pub pieces_placed: usize,
+    /// Number of single, double, triple and tetris clears, in that order.
+    pub clears_by_size: [u32; 4],
+}
+
+impl GameStats {
+    /// Records one locked piece that cleared `rows_cleared` rows.
+    fn record_lock(&mut self, rows_cleared: u32) {
+        self.rows_cleared += rows_cleared;
+        self.pieces_placed += 1;
+        if let Some(count) = (rows_cleared as usize)
+            .checked_sub(1)
+            .and_then(|size| self.clears_by_size.get_mut(size))
+        {
+            *count += 1;
+        }
     }
 }
 
@@ -66,6 +281,13 @@ pub struct Simulator {
     pub weights: [f64; weights::NUM_WEIGHTS],
     pub max_length: usize,
     pub n_weights: usize,
+    /// Fixed pieces to play in order, cycling at the end. Empty means random pieces.
+    pub piece_sequence: Vec<Tetromino>,
+    /// Relative frequency of each piece in [`Tetromino::ALL`] order. `None` means uniform.
+    pub piece_bias: Option<[f64; 7]>,
+    pub scoring: ScoringMode,
+    /// Feed the [`worst_piece`] for every board instead of sequenced or random pieces.
+    pub adversary: bool,
 }
 
 impl Simulator {
@@ -75,16 +297,51 @@ impl Simulator {
             weights,
             max_length,
             n_weights: weights::NUM_WEIGHTS,
+            piece_sequence: Vec::new(),
+            piece_bias: None,
+            scoring: ScoringMode::Rows,
+            adversary: false,
         }
     }
 
-    /// Sets the number of evaluation functions to use (default: 16).
+    /// Sets the number of evaluation functions to use (default: all).
     #[must_use]
     pub const fn with_n_weights(mut self, n: usize) -> Self {
         self.n_weights = n;
         self
     }
 
+    /// Plays `pieces` in order (cycling at the end) instead of drawing from the RNG.
+    #[must_use]
+    pub fn with_piece_sequence(mut self, pieces: Vec<Tetromino>) -> Self {
+        self.piece_sequence = pieces;
+        self
+    }
+
+    /// Draws random pieces with the given relative frequencies, in
+    /// [`Tetromino::ALL`] order, instead of uniformly.
+    #[must_use]
+    pub const fn with_piece_bias(mut self, bias: [f64; 7]) -> Self {
+        self.piece_bias = Some(bias);
+        self
+    }
+
+    /// Sets how [`Simulator::fitness_with_rng`] scores a game (default: rows cleared).
+    #[must_use]
+    pub const fn with_scoring(mut self, scoring: ScoringMode) -> Self {
+        self.scoring = scoring;
+        self
+    }
+
+    /// Lets a one-ply adversary choose every piece (see [`worst_piece`]),
+    /// overriding the piece sequence and bias. The pieces then no longer
+    /// depend on the RNG, which measures robustness to pathological sequences.
+    #[must_use]
+    pub const fn with_adversary(mut self, adversary: bool) -> Self {
+        self.adversary = adversary;
+        self
+    }
+
     /// Simulates a Tetris game using parallelized move evaluation.
     ///
     /// Returns the total number of rows cleared during the simulation.
@@ -97,30 +354,203 @@ impl Simulator {
     /// Simulates a Tetris game using a provided RNG.
     #[must_use]
     pub fn simulate_game_with_rng<R: rand::Rng + ?Sized>(self, rng: &mut R) -> u32 {
+        self.play_with_rng(rng).0
+    }
+
+    /// Simulates a Tetris game and scores it with the configured [`ScoringMode`].
+    #[must_use]
+    pub fn fitness_with_rng<R: rand::Rng + ?Sized>(self, rng: &mut R) -> f64 {
+        let stats = self.stats_with_rng(rng);
+        self.scoring
+            .fitness(stats.rows_cleared, stats.pieces_placed)
+    }
+
+    /// Like [`Simulator::fitness_with_rng`], looking moves up in `cache` first.
+    #[must_use]
+    pub fn fitness_cached_with_rng<R: rand::Rng + ?Sized>(
+        self,
+        rng: &mut R,
+        cache: &mut MoveCache,
+    ) -> f64 {
+        let stats = self.play(rng, Some(cache), self.max_length);
+        self.scoring
+            .fitness(stats.rows_cleared, stats.pieces_placed)
+    }
+
+    /// Plays one game, returning rows cleared and pieces placed.
+    pub fn play_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> (u32, usize) {
+        let stats = self.stats_with_rng(rng);
+        (stats.rows_cleared, stats.pieces_placed)
+    }
+
+    /// Plays one game, returning its [`GameStats`].
+    pub fn stats_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> GameStats {
+        self.play(rng, None, self.max_length)
+    }
+
+    /// Plays until the agent tops out, ignoring `max_length`, or until `cap`
+    /// pieces have been placed if given. Measures how long weights survive.
+    pub fn simulate_until_topout_with_rng<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        cap: Option<usize>,
+    ) -> GameStats {
+        self.play(rng, None, cap.unwrap_or(usize::MAX))
+    }
+
+    /// Plays one game of at most `max_pieces`, reusing and filling `cache` if
+    /// given. The cache only skips searches, so the game is the same either way.
+    fn play<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        mut cache: Option<&mut MoveCache>,
+        max_pieces: usize,
+    ) -> GameStats {
         let mut game = GameState::new_with_rng(rng);
-        let mut total_rows_cleared = 0;
+        let mut stats = GameStats::default();
+
+        let mut sequence = self.piece_sequence.iter().copied().cycle();
 
-        for _ in 0..self.max_length {
-            let piece = Tetromino::random_with_rng(rng);
+        for _ in 0..max_pieces {
+            let piece = if self.adversary {
+                worst_piece(&game.board, &self.weights, self.n_weights)
+            } else {
+                sequence.next().unwrap_or_else(|| match &self.piece_bias {
+                    Some(bias) => Tetromino::weighted_random_with_rng(rng, bias),
+                    None => Tetromino::random_with_rng(rng),
+                })
+            };
 
-            match find_best_move(&game.board, piece, &self.weights, self.n_weights) {
+            let best = match cache.as_deref_mut() {
+                Some(cache) => cache
+                    .moves
+                    .entry((game.board.clone(), piece))
+                    .or_insert_with(|| {
+                        find_best_move(&game.board, piece, &self.weights, self.n_weights)
+                    })
+                    .clone(),
+                None => find_best_move(&game.board, piece, &self.weights, self.n_weights),
+            };
+
+            match best {
                 Some((board, rows_cleared)) => {
-                    game = GameState::from_board_with_rng(board, rng);
-                    total_rows_cleared += rows_cleared;
-                    game.rows_cleared = total_rows_cleared;
+                    game.reset_to_board(board, rng);
+                    stats.record_lock(rows_cleared);
+                    game.rows_cleared = stats.rows_cleared;
                 }
                 None => break,
             }
         }
 
-        total_rows_cleared
+        stats
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::SeedableRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn adversary_is_deterministic() {
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[0] = -1.0;
+        weights[1] = -1.0;
+        let mut board = Board::new();
+        board[0][..6].fill(true);
+        board[1][2] = true;
+
+        let piece = worst_piece(&board, &weights, weights::NUM_WEIGHTS);
+        assert_eq!(piece, worst_piece(&board, &weights, weights::NUM_WEIGHTS));
+
+        let sim = || Simulator::new(weights, 30).with_adversary(true);
+        let a = sim().stats_with_rng(&mut rand::rngs::StdRng::seed_from_u64(1));
+        let b = sim().stats_with_rng(&mut rand::rngs::StdRng::seed_from_u64(2));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn ties_prefer_low_landing_row_then_column_then_rotation() {
+        // All-zero weights make every placement score the same
+        let weights = [0.0; weights::NUM_WEIGHTS];
+        let mut board = Board::new();
+        board[0][0] = true;
+
+        for _ in 0..3 {
+            // The O piece is identical in every rotation: the lowest index wins
+            let placement =
+                find_best_placement(&board, Tetromino::O, &weights, weights::NUM_WEIGHTS)
+                    .expect("placement");
+            let mut cells = placement.piece.cells();
+            cells.sort_unstable();
+            assert_eq!(cells, [(1, 0), (1, 1), (2, 0), (2, 1)]);
+            assert_eq!(placement.piece.rotation, Rotation(0));
+
+            // Every I placement ties; flat and upright next to the filled cell
+            // both land lowest and leftmost, and flat has the lower rotation
+            let placement =
+                find_best_placement(&board, Tetromino::I, &weights, weights::NUM_WEIGHTS)
+                    .expect("placement");
+            assert_eq!(placement.piece.cells(), [(1, 0), (2, 0), (3, 0), (4, 0)]);
+            assert_eq!(placement.piece.rotation, Rotation(0));
+        }
+    }
+
+    /// Exhaustive search: every rotation, row and column that can lock,
+    /// including origins below the floor.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn row_scan(
+        board: &Board,
+        piece: Tetromino,
+        weights: &[f64; weights::NUM_WEIGHTS],
+    ) -> Placement {
+        let mut candidates = Vec::new();
+        for rotation in Rotation::all() {
+            for row in -2..Board::HEIGHT as i8 {
+                for col in 0..Board::WIDTH {
+                    let candidate = FallingPiece {
+                        tetromino: piece,
+                        rotation,
+                        row,
+                        col: col as i8,
+                    };
+                    candidates.extend(score_placement(
+                        board,
+                        candidate,
+                        weights,
+                        weights::NUM_WEIGHTS,
+                    ));
+                }
+            }
+        }
+        candidates
+            .into_iter()
+            .max_by(compare_placements)
+            .expect("placement")
+            .1
+    }
+
+    #[test]
+    fn hard_drops_match_row_scan_without_overhangs() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        for _ in 0..50 {
+            let weights: [f64; weights::NUM_WEIGHTS] =
+                std::array::from_fn(|_| rng.random_range(-1.0..1.0));
+            let mut board = Board::new();
+            for col in 0..Board::WIDTH {
+                for row in 0..rng.random_range(0..12) {
+                    board[row][col] = true;
+                }
+            }
+            for piece in Tetromino::ALL {
+                let fast = find_best_placement(&board, piece, &weights, weights::NUM_WEIGHTS)
+                    .expect("placement");
+                let slow = row_scan(&board, piece, &weights);
+                assert_eq!(fast.board, slow.board, "{piece:?}");
+                assert_eq!(fast.piece.cells(), slow.piece.cells(), "{piece:?}");
+            }
+        }
+    }
 
     #[test]
     fn simulate_game_with_rng_is_deterministic() {
@@ -138,4 +568,118 @@ mod tests {
 
         assert_eq!(rows_a, rows_b);
     }
+
+    #[test]
+    fn injected_sequence_ignores_rng_pieces() {
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[0] = -1.0;
+        weights[1] = -1.0;
+        let sequence = vec![Tetromino::O, Tetromino::I];
+
+        let sim_a = Simulator::new(weights, 200).with_piece_sequence(sequence.clone());
+        let sim_b = Simulator::new(weights, 200).with_piece_sequence(sequence);
+
+        let rows_a = sim_a.simulate_game_with_rng(&mut rand::rngs::StdRng::seed_from_u64(1));
+        let rows_b = sim_b.simulate_game_with_rng(&mut rand::rngs::StdRng::seed_from_u64(2));
+
+        assert!(rows_a > 0);
+        assert_eq!(rows_a, rows_b);
+    }
+
+    #[test]
+    fn tall_board_places_pieces_above_row_twenty() {
+        let mut board = Board::with_dimensions(10, 40);
+        for row in 0..30 {
+            board[row][..9].fill(true);
+        }
+        let weights = [-1.0; weights::NUM_WEIGHTS];
+
+        let placement = find_best_placement(&board, Tetromino::O, &weights, weights::NUM_WEIGHTS)
+            .expect("room above the stack");
+        assert!(placement.piece.cells().iter().all(|&(_, row)| row >= 30));
+        assert_eq!(placement.board.cell_count(), board.cell_count() + 4);
+    }
+
+    #[test]
+    fn ten_vertical_i_pieces_make_one_tetris() {
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[0] = -1.0; // pile height
+        weights[18] = -0.5; // well count
+        let sim = Simulator::new(weights, 10).with_piece_sequence(vec![Tetromino::I]);
+
+        let stats = sim.stats_with_rng(&mut rand::rngs::StdRng::seed_from_u64(1));
+
+        assert_eq!(stats.pieces_placed, 10);
+        assert_eq!(stats.rows_cleared, 4);
+        assert_eq!(stats.clears_by_size, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn until_topout_is_bounded_by_cap_only() {
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[0] = -1.0; // pile height
+        weights[18] = -0.5; // well count
+        // Vertical I pieces clear a tetris every ten pieces, forever
+        let sim = Simulator::new(weights, 10).with_piece_sequence(vec![Tetromino::I]);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+        let stats = sim.simulate_until_topout_with_rng(&mut rng, Some(1000));
+        assert_eq!(stats.pieces_placed, 1000);
+        assert_eq!(stats.rows_cleared, 400);
+
+        // Rewarding pile height tops out long before any cap
+        let mut stacking = [1.0; weights::NUM_WEIGHTS];
+        stacking[20] = 0.0; // deepest well column scores highest with no wells at all
+        let stacker = Simulator::new(stacking, 10).with_piece_sequence(vec![Tetromino::O]);
+        let stats = stacker.simulate_until_topout_with_rng(&mut rng, None);
+        assert!(stats.pieces_placed > 10 && stats.pieces_placed < 200);
+    }
+
+    #[test]
+    fn piece_bias_replaces_uniform_pieces() {
+        let mut weights = [0.0; weights::NUM_WEIGHTS];
+        weights[0] = -1.0;
+        weights[1] = -1.0;
+        let only_o = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+
+        let biased = Simulator::new(weights, 50).with_piece_bias(only_o);
+        let sequenced = Simulator::new(weights, 50).with_piece_sequence(vec![Tetromino::O]);
+
+        let rng = || rand::rngs::StdRng::seed_from_u64(4);
+        assert_eq!(
+            biased.stats_with_rng(&mut rng()),
+            sequenced.stats_with_rng(&mut rng())
+        );
+    }
+
+    #[test]
+    fn rows_scoring_ignores_survival() {
+        let short = ScoringMode::Rows.fitness(5, 30);
+        let long = ScoringMode::Rows.fitness(5, 900);
+        assert_eq!(short.to_bits(), long.to_bits());
+    }
+
+    #[test]
+    fn survival_scoring_rewards_longer_games() {
+        let short = ScoringMode::Survival.fitness(5, 30);
+        let long = ScoringMode::Survival.fitness(5, 900);
+        assert!(long > short);
+        assert!((long - 14.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fitness_with_rng_counts_pieces_placed() {
+        let weights = [0.0; weights::NUM_WEIGHTS];
+        let sim_length = 10;
+        let sim = Simulator::new(weights, sim_length)
+            .with_piece_sequence(vec![Tetromino::O])
+            .with_scoring(ScoringMode::Survival);
+
+        let fitness = sim.fitness_with_rng(&mut rand::rngs::StdRng::seed_from_u64(3));
+
+        // Ten O pieces on an empty board never top out and clear no more than 4 rows
+        let rows = ScoringMode::SURVIVAL_BONUS_PER_PIECE.mul_add(-10.0, fitness);
+        assert!((rows - rows.round()).abs() < 1e-9);
+        assert!((0.0..=4.0).contains(&rows));
+    }
 }
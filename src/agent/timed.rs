@@ -0,0 +1,126 @@
+//! Move search that stops at a wall-clock deadline.
+
+use std::time::Instant;
+
+use crate::agent::simulator::{Placement, compare_placements, drop_columns, score_placement};
+use crate::game::{Board, Tetromino};
+use crate::weights;
+
+/// Finds the best placement for a piece that can be evaluated before `deadline`.
+///
+/// Searches the same hard drops as [`find_best_placement`], one rotation and
+/// column at a time, so with enough time both pick the same placement.
+/// Placements tucked under overhangs are not considered. Once the deadline
+/// passes, the best drop scored so far is returned; if none was legal yet,
+/// the search continues until it finds one or runs out of drops.
+///
+/// [`find_best_placement`]: crate::agent::simulator::find_best_placement
+#[must_use]
+pub fn find_best_move_timed(
+    board: &Board,
+    piece: Tetromino,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+    deadline: Instant,
+) -> Option<Placement> {
+    let mut best: Option<(f64, Placement)> = None;
+
+    for (rotation, col) in drop_columns(board) {
+        if best.is_some() && Instant::now() >= deadline {
+            break;
+        }
+        if let Some(scored) = board
+            .landing_for(piece, rotation, col)
+            .and_then(|dropped| score_placement(board, dropped, weights, n_weights))
+            && best
+                .as_ref()
+                .is_none_or(|current| compare_placements(&scored, current).is_gt())
+        {
+            best = Some(scored);
+        }
+    }
+
+    best.map(|(_, placement)| placement)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::agent::simulator::find_best_placement;
+
+    fn test_weights() -> [f64; weights::NUM_WEIGHTS] {
+        let mut w = [0.0; weights::NUM_WEIGHTS];
+        w[0] = -1.0; // pile height
+        w[1] = -4.0; // holes
+        w
+    }
+
+    fn stacked_board() -> Board {
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..(col % 4) {
+                board[row][col] = true;
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn expired_deadline_still_returns_legal_placement() {
+        let board = stacked_board();
+        let weights = test_weights();
+
+        for piece in Tetromino::ALL {
+            let placement = find_best_move_timed(
+                &board,
+                piece,
+                &weights,
+                weights::NUM_WEIGHTS,
+                Instant::now(),
+            )
+            .expect("a hard drop is always available here");
+            assert!(board.can_lock(&placement.piece), "{piece:?}");
+        }
+    }
+
+    #[test]
+    fn generous_deadline_matches_full_search() {
+        let board = stacked_board();
+        let weights = test_weights();
+        let n = weights::NUM_WEIGHTS;
+        let deadline = Instant::now() + Duration::from_mins(1);
+
+        for piece in Tetromino::ALL {
+            let timed = find_best_move_timed(&board, piece, &weights, n, deadline)
+                .expect("timed placement");
+            let full = find_best_placement(&board, piece, &weights, n).expect("placement");
+
+            let score = |p: &Placement| {
+                score_placement(&board, p.piece, &weights, n)
+                    .expect("legal placement")
+                    .0
+            };
+            assert!((score(&timed) - score(&full)).abs() < 1e-9, "{piece:?}");
+            assert_eq!(timed.piece.cells(), full.piece.cells(), "{piece:?}");
+        }
+    }
+
+    #[test]
+    fn pieces_are_not_tucked_under_overhangs() {
+        // A roof over all but the last column: an O cannot fit through the
+        // one-wide gap, so it can only land on top
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH - 1 {
+            board[2][col] = true;
+        }
+        let weights = test_weights();
+        let deadline = Instant::now() + Duration::from_mins(1);
+
+        let timed = find_best_move_timed(&board, Tetromino::O, &weights, 2, deadline)
+            .expect("timed placement");
+        let lowest_row = timed.piece.cells().iter().map(|&(_, row)| row).min();
+        assert_eq!(lowest_row, Some(3), "tucked under the roof: {timed:?}");
+    }
+}
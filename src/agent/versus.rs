@@ -0,0 +1,173 @@
+//! Headless agent-vs-agent games with garbage exchange.
+
+use crate::agent::simulator::find_best_move;
+use crate::game::{Board, Tetromino, garbage_lines};
+use crate::weights;
+
+/// Outcome of one [`VersusSimulator`] game. Arrays are indexed by side (0 or 1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DuelResult {
+    /// The side still standing, or `None` if both topped out on the same
+    /// piece or both survived the whole game.
+    pub winner: Option<usize>,
+    pub pieces_placed: [usize; 2],
+    pub rows_cleared: [u32; 2],
+    pub garbage_sent: [u32; 2],
+}
+
+/// Plays two weight sets against each other on the same pieces, with line
+/// clears sending garbage to the opponent as in the versus TUI.
+///
+/// Both sides place each piece at once, then exchange garbage, so neither
+/// side moves first. A side loses when it has no legal placement or garbage
+/// pushes its stack off the top.
+pub struct VersusSimulator {
+    pub weights: [[f64; weights::NUM_WEIGHTS]; 2],
+    /// Pieces per side before the game ends in a draw.
+    pub max_length: usize,
+    pub n_weights: usize,
+}
+
+impl VersusSimulator {
+    #[must_use]
+    pub const fn new(
+        weights_a: [f64; weights::NUM_WEIGHTS],
+        weights_b: [f64; weights::NUM_WEIGHTS],
+        max_length: usize,
+    ) -> Self {
+        Self {
+            weights: [weights_a, weights_b],
+            max_length,
+            n_weights: weights::NUM_WEIGHTS,
+        }
+    }
+
+    /// Sets the number of evaluation functions to use (default: all).
+    #[must_use]
+    pub const fn with_n_weights(mut self, n: usize) -> Self {
+        self.n_weights = n;
+        self
+    }
+
+    /// Plays one game, drawing pieces and garbage gaps from `rng`.
+    pub fn play_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> DuelResult {
+        let mut boards = [Board::new(), Board::new()];
+        let mut alive = [true; 2];
+        let mut result = DuelResult::default();
+
+        for _ in 0..self.max_length {
+            let piece = Tetromino::random_with_rng(rng);
+            let mut sent = [0; 2];
+            let mut topped_out = [false; 2];
+
+            for side in 0..2 {
+                match find_best_move(&boards[side], piece, &self.weights[side], self.n_weights) {
+                    Some((board, rows_cleared)) => {
+                        boards[side] = board;
+                        result.pieces_placed[side] += 1;
+                        result.rows_cleared[side] += rows_cleared;
+                        sent[side] = garbage_lines(rows_cleared);
+                    }
+                    None => topped_out[side] = true,
+                }
+            }
+
+            let buried = exchange_garbage(&mut boards, sent, &mut result, rng);
+            alive = [0, 1].map(|side| !topped_out[side] && !buried[side]);
+
+            if alive != [true; 2] {
+                break;
+            }
+        }
+
+        result.winner = match alive {
+            [true, false] => Some(0),
+            [false, true] => Some(1),
+            _ => None,
+        };
+        result
+    }
+}
+
+/// Delivers this turn's garbage to both sides before either is judged, so the
+/// order of the sides never decides who loses. Returns which sides were
+/// pushed off the top.
+fn exchange_garbage<R: rand::Rng + ?Sized>(
+    boards: &mut [Board; 2],
+    sent: [u32; 2],
+    result: &mut DuelResult,
+    rng: &mut R,
+) -> [bool; 2] {
+    let mut buried = [false; 2];
+    for (side, &lines) in sent.iter().enumerate() {
+        let opponent = 1 - side;
+        if lines == 0 {
+            continue;
+        }
+        result.garbage_sent[side] += lines;
+        buried[opponent] = boards[opponent].add_garbage_rows(lines as usize, None, rng);
+    }
+    buried
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn good_weights() -> [f64; weights::NUM_WEIGHTS] {
+        let mut w = [0.0; weights::NUM_WEIGHTS];
+        w[0] = -1.0; // pile height
+        w[1] = -4.0; // holes
+        w[3] = -0.5; // altitude difference
+        w
+    }
+
+    #[test]
+    fn mirror_match_is_symmetric() {
+        let sim = VersusSimulator::new(good_weights(), good_weights(), 200);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        let result = sim.play_with_rng(&mut rng);
+
+        assert_eq!(result.winner, None);
+        assert_eq!(result.pieces_placed[0], result.pieces_placed[1]);
+        assert_eq!(result.rows_cleared[0], result.rows_cleared[1]);
+    }
+
+    #[test]
+    fn garbage_lands_on_both_sides_before_either_loses() {
+        // Both stacks are 17 high, so four garbage rows push each off the top
+        let mut board = Board::new();
+        for row in 0..17 {
+            board[row][0] = true;
+        }
+        let mut boards = [board.clone(), board];
+        let mut result = DuelResult::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+        let buried = exchange_garbage(&mut boards, [4, 4], &mut result, &mut rng);
+
+        assert_eq!(buried, [true, true]);
+        assert_eq!(result.garbage_sent, [4, 4]);
+        assert!(
+            boards
+                .iter()
+                .all(|board| board.stack_height() == Board::HEIGHT)
+        );
+    }
+
+    #[test]
+    fn stacking_agent_loses_to_clearing_agent() {
+        // Rewarding pile height builds towers and never clears a line
+        let mut stacker = [0.0; weights::NUM_WEIGHTS];
+        stacker[0] = 1.0;
+        let sim = VersusSimulator::new(good_weights(), stacker, 500);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let result = sim.play_with_rng(&mut rng);
+
+        assert_eq!(result.winner, Some(0));
+        assert!(result.pieces_placed[1] < 500);
+        assert_eq!(result.rows_cleared[1], 0);
+    }
+}
@@ -1,14 +1,48 @@
 use std::fs::{self, File};
-use std::io::{self, BufRead, BufReader, BufWriter, Write};
-use std::path::Path;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
-use harmonomino::agent::simulator::Simulator;
+use harmonomino::agent::profile;
+use harmonomino::agent::simulator::{ScoringMode, Simulator};
+use harmonomino::agent::versus::VersusSimulator;
 use harmonomino::apply_flags;
 use harmonomino::cli::Cli;
-use harmonomino::harmony::{HarmonySearch, OptimizeConfig, optimize_weights};
+use harmonomino::eval_fns::list_evaluators;
+use harmonomino::game::Tetromino;
+use harmonomino::harmony::{
+    Aggregation, CeConfig, HarmonySearch, OptimizeConfig, Verbosity, diversity, fitness_landscape,
+    mass_optimize, optimize_weights, perturbation_sensitivity,
+};
+use harmonomino::records::{self, CsvAppender, EvalRecord};
+use harmonomino::stats::mann_whitney_u;
 use harmonomino::weights;
 use rand::SeedableRng;
 
+/// Default number of seeds evaluated by `--seed-scan`.
+const DEFAULT_SEED_SCAN_COUNT: u64 = 1000;
+
+/// Number of easiest/hardest seeds printed by `--seed-scan`.
+const SEED_SCAN_SHOWN: usize = 5;
+
+/// Default number of seeds played by `--compare` when no seeds are given.
+const DEFAULT_COMPARE_RUNS: u64 = 100;
+
+/// Significance level reported by `--compare`.
+const COMPARE_ALPHA: f64 = 0.05;
+
+/// Default number of seeds per variant in `--sensitivity` mode.
+const DEFAULT_SENSITIVITY_RUNS: u64 = 20;
+
+/// Default perturbation applied to each weight in `--sensitivity` mode.
+const DEFAULT_SENSITIVITY_DELTA: f64 = 0.1;
+
+/// Default number of seeds per grid point in `--landscape` mode.
+const DEFAULT_LANDSCAPE_RUNS: u64 = 5;
+
+/// Default grid values per axis in `--landscape` mode.
+const DEFAULT_LANDSCAPE_STEPS: usize = 20;
+
 fn usage() -> String {
     format!(
         "\
@@ -18,25 +52,64 @@ Runs simulations and prints results.
 
 Options:
   --sim-length <N>      Pieces per simulation game     [default: {}]
-  --weights <PATH>      Weights file (repeatable)
+  --weights <PATH>      Weights file, - for stdin (repeatable)
   --n-weights <N>       Number of eval functions        [default: {}]
   --averaged            Average fitness over multiple runs
   --averaged-runs <N>   Runs per averaged evaluation   [default: {}]
+  --aggregation <AGG>   Averaged statistic: mean, median, min, trimmed-mean [default: mean]
   --eval                Run deterministic evaluation to CSV
   --seeds <CSV>         Seeds for eval mode (comma-separated)
   --seeds-file <PATH>   Seeds for eval mode (one per line)
+  --seeds-range <R>     Seeds for eval mode as START:END[:STEP], END exclusive
   --output-csv <PATH>   Output CSV path for eval mode
-  --sweep <PARAM>       Parameter sweep: pitch-adj-rate, iterations, bandwidth, sim-length
-  --mass-optimize <N>   Run N optimizations and write results to CSV
+  --output-json <PATH>  Output JSON path for eval mode
+  --append              Resume eval mode: keep rows already in --output-csv, add the rest
+  --scoring-mode <MODE> Fitness recorded in eval mode: rows, survival
+                        [default: the weights file's `# scoring:` header, else rows]
+  --piece-sequence <S>  Fixed pieces for table/eval modes, cycled (e.g. IOTSZJL)
+  --sweep <PARAM>       Parameter sweep: pitch-adj-rate, iterations, bandwidth, sim-length, n-weights
+                        (HSA), or n-samples, n-elite, initial-std-dev (CES)
+  --mass-optimize <N>   Run N optimizations in parallel and write results to CSV
+  --seed <N>            Base seed for mass-optimize (run i uses seed + i - 1)
+  --threads <N>         Worker threads for mass-optimize, 0 = all cores [default: 0]
+  --quiet               Hide per-iteration progress in sweep/mass-optimize modes
+  --output-dir <DIR>    Directory for timestamped sweep/mass-optimize CSVs [default: results]
+  --seed-scan           Rank seeds by rows cleared for one weights file
+  --count <N>           Seeds to scan in seed-scan mode  [default: {}]
+  --compare <A> <B>     Compare two weights files over the same seeds
+  --tournament <PATHS>  Round-robin ranking of weights files over the same seeds
+  --duel <A> <B>        Play two weights files against each other with garbage
+  --runs <N>            Seeds 0..N for compare/tournament/duel/sensitivity/landscape [default: {}/{}/{}]
+  --sensitivity <PATH>  Per-weight fitness gradient for a weights file
+  --delta <X>           Weight perturbation in sensitivity mode [default: {}]
+  --landscape           Mean fitness over a grid of two weights of --weights
+  --axes <I,J>          Weight indices swept in landscape mode
+  --range <MIN:MAX>     Range of both swept weights   [default: -1:1]
+  --steps <N>           Grid values per axis          [default: {}]
+  --profile             Time the move search over one game (needs --features profile)
+  --list-evals          List evaluation functions in weight order
   --help                Print this help message
 
 Examples:
   benchmark --weights weights.txt --sim-length 500
   benchmark --sweep iterations --sim-length 100
-  benchmark --mass-optimize 100",
+  benchmark --mass-optimize 100 --seed 42 --threads 8 --output-dir runs
+  benchmark --seed-scan --weights weights.txt --count 1000
+  benchmark --compare a.txt b.txt --runs 100 --output-csv compare.csv
+  benchmark --tournament weights/*.txt --seeds-file seeds.txt
+  benchmark --duel a.txt b.txt --runs 100
+  benchmark --sensitivity weights.txt --delta 0.1
+  benchmark --landscape --weights base.txt --axes 0,1 --range -1:1 --steps 20
+  benchmark --profile --weights weights.txt --sim-length 1000",
         OptimizeConfig::DEFAULT_SIM_LENGTH,
         weights::NUM_WEIGHTS,
         OptimizeConfig::DEFAULT_AVERAGED_RUNS,
+        DEFAULT_SEED_SCAN_COUNT,
+        DEFAULT_COMPARE_RUNS,
+        DEFAULT_SENSITIVITY_RUNS,
+        DEFAULT_LANDSCAPE_RUNS,
+        DEFAULT_SENSITIVITY_DELTA,
+        DEFAULT_LANDSCAPE_STEPS,
     )
 }
 
@@ -48,34 +121,127 @@ fn main() -> io::Result<()> {
         return Ok(());
     }
 
+    if cli.has_flag("--list-evals") {
+        print!("{}", list_evaluators());
+        return Ok(());
+    }
+
     let mut sim_length: usize = OptimizeConfig::DEFAULT_SIM_LENGTH;
     let mut n_weights: usize = OptimizeConfig::DEFAULT_N_WEIGHTS;
     let mut averaged_runs: usize = OptimizeConfig::DEFAULT_AVERAGED_RUNS;
+    let mut aggregation = Aggregation::Mean;
     apply_flags!(cli, {
         "--sim-length"    => sim_length,
         "--n-weights"     => n_weights,
         "--averaged-runs" => averaged_runs,
+        "--aggregation"   => aggregation,
     });
     let averaged = cli.has_flag("--averaged");
+    let verbosity = if cli.has_flag("--quiet") {
+        Verbosity::Quiet
+    } else {
+        Verbosity::Normal
+    };
+    let piece_sequence = cli
+        .get("--piece-sequence")
+        .map(parse_piece_sequence)
+        .transpose()?
+        .unwrap_or_default();
 
     if cli.has_flag("--eval") {
-        return run_eval(&cli, sim_length, n_weights);
+        return run_eval(&cli, sim_length, n_weights, &piece_sequence);
+    }
+
+    if cli.has_flag("--seed-scan") {
+        return run_seed_scan(&cli, sim_length, n_weights);
+    }
+
+    if cli.has_flag("--compare") {
+        return run_compare(&cli, sim_length, n_weights);
+    }
+
+    if cli.has_flag("--tournament") {
+        return run_tournament(&cli, sim_length, n_weights);
+    }
+
+    if cli.has_flag("--duel") {
+        return run_duel(&cli, sim_length, n_weights);
+    }
+
+    if let Some(path) = cli.get("--sensitivity") {
+        return run_sensitivity(&cli, path, sim_length, n_weights);
     }
 
+    if cli.has_flag("--landscape") {
+        return run_landscape(&cli, sim_length, n_weights);
+    }
+
+    if cli.has_flag("--profile") {
+        return run_profile(&cli, sim_length, n_weights);
+    }
+
+    let output_dir = Path::new(cli.get("--output-dir").unwrap_or("results"));
+
     if let Some(param) = cli.get("--sweep") {
-        return sweep_parameter(param, sim_length, n_weights, averaged, averaged_runs);
+        return sweep_parameter(
+            param,
+            output_dir,
+            sim_length,
+            n_weights,
+            averaged,
+            averaged_runs,
+            aggregation,
+            verbosity,
+        );
     }
 
     if let Some(count_str) = cli.get("--mass-optimize") {
         let count: usize = cli.parse_value("--mass-optimize", count_str)?;
-        return mass_optimize(count, sim_length, n_weights, averaged, averaged_runs);
+        return run_mass_optimize(
+            &cli,
+            count,
+            output_dir,
+            sim_length,
+            n_weights,
+            averaged,
+            averaged_runs,
+            aggregation,
+            verbosity,
+        );
     }
 
-    run_comparison_table(&cli, sim_length, n_weights)
+    run_comparison_table(&cli, sim_length, n_weights, &piece_sequence)
+}
+
+/// Parses a `--piece-sequence` string such as `IOTSZJL` (case-insensitive).
+fn parse_piece_sequence(letters: &str) -> io::Result<Vec<Tetromino>> {
+    let pieces = letters
+        .chars()
+        .map(|c| {
+            Tetromino::from_char(c).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid piece '{c}' in --piece-sequence"),
+                )
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    if pieces.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--piece-sequence must not be empty",
+        ));
+    }
+    Ok(pieces)
 }
 
 /// Default comparison-table mode.
-fn run_comparison_table(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+fn run_comparison_table(
+    cli: &Cli,
+    sim_length: usize,
+    n_weights: usize,
+    piece_sequence: &[Tetromino],
+) -> io::Result<()> {
     let weight_paths = cli.get_all("--weights");
 
     let mut entries: Vec<(String, [f64; weights::NUM_WEIGHTS])> = Vec::new();
@@ -104,7 +270,9 @@ fn run_comparison_table(cli: &Cli, sim_length: usize, n_weights: usize) -> io::R
     println!("------------------------------+-------------");
 
     for (label, w) in &entries {
-        let sim = Simulator::new(*w, sim_length).with_n_weights(n_weights);
+        let sim = Simulator::new(*w, sim_length)
+            .with_n_weights(n_weights)
+            .with_piece_sequence(piece_sequence.to_vec());
         let rows = sim.simulate_game();
         println!("{label:<30}| {rows}");
     }
@@ -113,7 +281,12 @@ fn run_comparison_table(cli: &Cli, sim_length: usize, n_weights: usize) -> io::R
 }
 
 /// Deterministic evaluation mode for experiment runs.
-fn run_eval(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+fn run_eval(
+    cli: &Cli,
+    sim_length: usize,
+    n_weights: usize,
+    piece_sequence: &[Tetromino],
+) -> io::Result<()> {
     let weight_paths = cli.get_all("--weights");
     if weight_paths.is_empty() {
         return Err(io::Error::new(
@@ -122,111 +295,666 @@ fn run_eval(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
         ));
     }
 
-    let output_csv = cli.get("--output-csv").ok_or_else(|| {
-        io::Error::new(
+    let output_csv = cli.get("--output-csv");
+    let output_json = cli.get("--output-json");
+    if output_csv.is_none() && output_json.is_none() {
+        return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            "--output-csv is required in --eval mode",
-        )
-    })?;
+            "--output-csv or --output-json is required in --eval mode",
+        ));
+    }
 
-    let seeds = if let Some(csv) = cli.get("--seeds") {
-        parse_seeds_csv(csv)?
-    } else if let Some(path) = cli.get("--seeds-file") {
-        parse_seeds_file(Path::new(path))?
-    } else {
+    let Some(seeds) = explicit_seeds(cli)? else {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            "--seeds or --seeds-file is required in --eval mode",
+            "--seeds, --seeds-file or --seeds-range is required in --eval mode",
         ));
     };
 
-    let mut writer = BufWriter::new(File::create(output_csv)?);
-    writeln!(writer, "weight_id,seed,rows_cleared")?;
+    let mut scoring = ScoringMode::Rows;
+    apply_flags!(cli, { "--scoring-mode" => scoring });
 
+    let mut appender = if cli.has_flag("--append") {
+        let (Some(path), None) = (output_csv, output_json) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--append needs --output-csv and does not support --output-json",
+            ));
+        };
+        Some(CsvAppender::open(Path::new(path))?)
+    } else {
+        None
+    };
+
+    let mut records = Vec::with_capacity(weight_paths.len() * seeds.len());
     for weight_path in weight_paths {
         let path = Path::new(weight_path);
-        let w = weights::load(path)?;
+        let (w, file_scoring) = weights::load_with_scoring(path)?;
+        let scoring = if cli.has_flag("--scoring-mode") {
+            scoring
+        } else {
+            file_scoring.unwrap_or(scoring)
+        };
         let weight_id = path
             .file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or(weight_path);
 
         for &seed in &seeds {
+            if appender
+                .as_ref()
+                .is_some_and(|appender| appender.contains(weight_id, seed))
+            {
+                continue;
+            }
+            let sim = Simulator::new(w, sim_length)
+                .with_n_weights(n_weights)
+                .with_piece_sequence(piece_sequence.to_vec());
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let stats = sim.stats_with_rng(&mut rng);
+            let record = EvalRecord {
+                weight_id: weight_id.to_string(),
+                seed,
+                rows_cleared: stats.rows_cleared,
+                clears_by_size: stats.clears_by_size,
+                scoring_mode: scoring,
+                fitness: scoring.fitness(stats.rows_cleared, stats.pieces_placed),
+            };
+            match appender.as_mut() {
+                Some(appender) => appender.append(&record)?,
+                None => records.push(record),
+            }
+        }
+    }
+
+    if appender.is_some() {
+        return Ok(());
+    }
+    if let Some(path) = output_csv {
+        records::write_csv(BufWriter::new(File::create(path)?), &records)?;
+    }
+    if let Some(path) = output_json {
+        records::write_json(BufWriter::new(File::create(path)?), &records)?;
+    }
+
+    Ok(())
+}
+
+/// Runs one weight set over many seeds and ranks them by rows cleared.
+fn run_seed_scan(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let weight_path = cli.get("--weights").ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--weights is required in --seed-scan mode",
+        )
+    })?;
+    let mut count = DEFAULT_SEED_SCAN_COUNT;
+    apply_flags!(cli, { "--count" => count });
+    if count == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--count must be > 0",
+        ));
+    }
+
+    let w = weights::load(Path::new(weight_path))?;
+
+    println!("Scanning {count} seeds for {weight_path}...");
+
+    let mut results: Vec<(u64, u32)> = (0..count)
+        .map(|seed| {
             let sim = Simulator::new(w, sim_length).with_n_weights(n_weights);
             let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let rows = sim.simulate_game_with_rng(&mut rng);
-            writeln!(writer, "{weight_id},{seed},{rows}")?;
+            (seed, sim.simulate_game_with_rng(&mut rng))
+        })
+        .collect();
+
+    // Highest rows first; ties keep seed order
+    results.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let output_csv = cli.get("--output-csv").unwrap_or("results/seed_scan.csv");
+    if let Some(parent) = Path::new(output_csv).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output_csv)?);
+    writeln!(writer, "seed,rows_cleared")?;
+    for (seed, rows) in &results {
+        writeln!(writer, "{seed},{rows}")?;
+    }
+
+    let rows: Vec<u32> = results.iter().map(|&(_, rows)| rows).collect();
+    let n = rows.len();
+    let mean =
+        f64::from(rows.iter().sum::<u32>()) / f64::from(u32::try_from(n).unwrap_or(u32::MAX));
+
+    println!(
+        "Rows cleared: min={} p25={} median={} p75={} max={} mean={mean:.2}",
+        rows[n - 1],
+        rows[(n * 3) / 4],
+        rows[n / 2],
+        rows[n / 4],
+        rows[0],
+    );
+
+    println!("Easiest seeds:");
+    for (seed, rows) in results.iter().take(SEED_SCAN_SHOWN) {
+        println!("  {seed:>8} | {rows}");
+    }
+    println!("Hardest seeds:");
+    for (seed, rows) in results.iter().rev().take(SEED_SCAN_SHOWN) {
+        println!("  {seed:>8} | {rows}");
+    }
+
+    println!("Results written to {output_csv}");
+    Ok(())
+}
+
+/// Plays two weight sets over the same seeds and tests whether they differ.
+fn run_compare(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let paths = cli.get_n("--compare", 2).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--compare requires two weights files",
+        )
+    })?;
+    let (path_a, path_b) = (paths[0], paths[1]);
+    let weights_a = weights::load(Path::new(path_a))?;
+    let weights_b = weights::load(Path::new(path_b))?;
+
+    let seeds = seeds_or_runs(cli, DEFAULT_COMPARE_RUNS)?;
+
+    println!(
+        "Comparing {path_a} vs {path_b} over {} seeds...",
+        seeds.len()
+    );
+
+    let play = |w: [f64; weights::NUM_WEIGHTS], seed: u64| {
+        let sim = Simulator::new(w, sim_length).with_n_weights(n_weights);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        sim.simulate_game_with_rng(&mut rng)
+    };
+    let results: Vec<(u64, u32, u32)> = seeds
+        .iter()
+        .map(|&seed| (seed, play(weights_a, seed), play(weights_b, seed)))
+        .collect();
+
+    if let Some(output_csv) = cli.get("--output-csv") {
+        let mut writer = BufWriter::new(File::create(output_csv)?);
+        writeln!(writer, "seed,rows_a,rows_b")?;
+        for (seed, a, b) in &results {
+            writeln!(writer, "{seed},{a},{b}")?;
         }
+        println!("Per-seed results written to {output_csv}");
+    }
+
+    let mut rows_a: Vec<f64> = results.iter().map(|&(_, a, _)| f64::from(a)).collect();
+    let mut rows_b: Vec<f64> = results.iter().map(|&(_, _, b)| f64::from(b)).collect();
+    let test = mann_whitney_u(&rows_a, &rows_b);
+
+    let wins_a = results.iter().filter(|&&(_, a, b)| a > b).count();
+    let wins_b = results.iter().filter(|&&(_, a, b)| b > a).count();
+    let ties = results.len() - wins_a - wins_b;
+
+    println!("{:<30}| {:>10} | {:>10}", "Weights", "Mean", "Median");
+    println!("------------------------------+------------+-----------");
+    for (label, rows) in [(path_a, &mut rows_a), (path_b, &mut rows_b)] {
+        let mean = Aggregation::Mean.apply(rows);
+        let median = Aggregation::Median.apply(rows);
+        println!("{label:<30}| {mean:>10.2} | {median:>10.1}");
+    }
+    println!("Per-seed wins: A={wins_a} B={wins_b} ties={ties}");
+    println!(
+        "Mann-Whitney U={:.1} z={:.3} p={:.4}",
+        test.u, test.z, test.p_value
+    );
+    if test.p_value < COMPARE_ALPHA {
+        println!("Difference is significant at p < {COMPARE_ALPHA}");
+    } else {
+        println!("Difference is not significant at p < {COMPARE_ALPHA}");
     }
 
     Ok(())
 }
 
-fn prompt_and_generate() -> io::Result<Vec<(String, [f64; weights::NUM_WEIGHTS])>> {
-    eprintln!("No weights files found (tried weights.txt).");
-    eprint!("Run optimization to generate weights? [y/n] ");
-    io::stderr().flush()?;
+/// Plays two weight sets head-to-head, exchanging garbage, over the same seeds.
+fn run_duel(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let paths = cli.get_n("--duel", 2).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--duel requires two weights files",
+        )
+    })?;
+    let (path_a, path_b) = (paths[0], paths[1]);
+    let sim = VersusSimulator::new(
+        weights::load(Path::new(path_a))?,
+        weights::load(Path::new(path_b))?,
+        sim_length,
+    )
+    .with_n_weights(n_weights);
 
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+    let seeds = seeds_or_runs(cli, DEFAULT_COMPARE_RUNS)?;
 
-    if !input.trim().eq_ignore_ascii_case("y") {
-        return Err(io::Error::new(
-            io::ErrorKind::NotFound,
-            "weights file is required to run benchmark",
-        ));
+    println!("Dueling {path_a} vs {path_b} over {} seeds...", seeds.len());
+
+    let results: Vec<_> = seeds
+        .iter()
+        .map(|&seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            (seed, sim.play_with_rng(&mut rng))
+        })
+        .collect();
+
+    let output_csv = cli.get("--output-csv").unwrap_or("results/duel.csv");
+    if let Some(parent) = Path::new(output_csv).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output_csv)?);
+    writeln!(
+        writer,
+        "seed,winner,pieces_a,pieces_b,rows_a,rows_b,garbage_a,garbage_b"
+    )?;
+    for (seed, r) in &results {
+        let winner = match r.winner {
+            Some(0) => "a",
+            Some(_) => "b",
+            None => "draw",
+        };
+        writeln!(
+            writer,
+            "{seed},{winner},{},{},{},{},{},{}",
+            r.pieces_placed[0],
+            r.pieces_placed[1],
+            r.rows_cleared[0],
+            r.rows_cleared[1],
+            r.garbage_sent[0],
+            r.garbage_sent[1]
+        )?;
     }
 
-    let path = Path::new("weights.txt");
-    let result = optimize_weights(&OptimizeConfig::default(), path)?;
-    Ok(vec![("weights.txt".to_string(), result.weights)])
+    let games = f64::from(u32::try_from(results.len()).unwrap_or(u32::MAX));
+    let wins = |side: usize| {
+        results
+            .iter()
+            .filter(|(_, r)| r.winner == Some(side))
+            .count()
+    };
+    let (wins_a, wins_b) = (wins(0), wins(1));
+    let draws = results.len() - wins_a - wins_b;
+
+    println!(
+        "{:<30}| {:>6} | {:>8} | {:>13} | {:>13}",
+        "Weights", "Wins", "Win rate", "Mean survival", "Mean garbage"
+    );
+    println!("------------------------------+--------+----------+---------------+--------------");
+    for (side, label, side_wins) in [(0, path_a, wins_a), (1, path_b, wins_b)] {
+        let survival = results
+            .iter()
+            .map(|(_, r)| f64::from(u32::try_from(r.pieces_placed[side]).unwrap_or(u32::MAX)))
+            .sum::<f64>()
+            / games;
+        let garbage = results
+            .iter()
+            .map(|(_, r)| f64::from(r.garbage_sent[side]))
+            .sum::<f64>()
+            / games;
+        let win_rate = f64::from(u32::try_from(side_wins).unwrap_or(u32::MAX)) / games;
+        println!(
+            "{label:<30}| {side_wins:>6} | {:>7.1}% | {survival:>13.1} | {garbage:>13.1}",
+            100.0 * win_rate
+        );
+    }
+    println!("Draws: {draws}");
+    println!("Results written to {output_csv}");
+    Ok(())
+}
+
+/// Aggregate results of one weights file in `--tournament` mode.
+struct TournamentEntry<'a> {
+    path: &'a str,
+    rows: Vec<u32>,
+    wins: usize,
+    losses: usize,
+    draws: usize,
 }
 
-fn parse_seeds_csv(value: &str) -> io::Result<Vec<u64>> {
-    if value.trim().is_empty() {
+/// Plays every weights file over the same seeds and ranks them by mean rows.
+fn run_tournament(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let paths = cli.get_list("--tournament");
+    if paths.len() < 2 {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            "seeds CSV must not be empty",
+            "--tournament requires at least two weights files",
         ));
     }
-    value
-        .split(',')
-        .map(|s| {
-            s.trim().parse::<u64>().map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("invalid seed '{s}': {e}"),
-                )
+    let seeds = seeds_or_runs(cli, DEFAULT_COMPARE_RUNS)?;
+
+    println!(
+        "Tournament of {} weights files over {} seeds...",
+        paths.len(),
+        seeds.len()
+    );
+
+    let mut entries = Vec::with_capacity(paths.len());
+    for path in paths {
+        let w = weights::load(Path::new(path))?;
+        let rows = seeds
+            .iter()
+            .map(|&seed| {
+                let sim = Simulator::new(w, sim_length).with_n_weights(n_weights);
+                let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+                sim.simulate_game_with_rng(&mut rng)
             })
+            .collect();
+        entries.push(TournamentEntry {
+            path,
+            rows,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+        });
+    }
+
+    score_matches(&mut entries);
+
+    let stats = |rows: &[u32]| {
+        let mut rows: Vec<f64> = rows.iter().map(|&r| f64::from(r)).collect();
+        (
+            Aggregation::Mean.apply(&mut rows),
+            Aggregation::Median.apply(&mut rows),
+        )
+    };
+    let mut ranked: Vec<(&TournamentEntry, f64, f64)> = entries
+        .iter()
+        .map(|e| {
+            let (mean, median) = stats(&e.rows);
+            (e, mean, median)
         })
-        .collect()
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let output_csv = cli.get("--output-csv").unwrap_or("results/tournament.csv");
+    if let Some(parent) = Path::new(output_csv).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output_csv)?);
+    writeln!(writer, "rank,weights,mean,median,min,max,wins,losses,draws")?;
+    for (rank, (e, mean, median)) in ranked.iter().enumerate() {
+        let min = e.rows.iter().min().copied().unwrap_or(0);
+        let max = e.rows.iter().max().copied().unwrap_or(0);
+        writeln!(
+            writer,
+            "{},{},{mean:.2},{median:.1},{min},{max},{},{},{}",
+            rank + 1,
+            e.path,
+            e.wins,
+            e.losses,
+            e.draws
+        )?;
+    }
+
+    println!(
+        "{:>4} | {:<30}| {:>10} | {:>10} | {:>3} | {:>3} | {:>3}",
+        "Rank", "Weights", "Mean", "Median", "W", "L", "D"
+    );
+    println!("-----+-------------------------------+------------+------------+-----+-----+----");
+    for (rank, (e, mean, median)) in ranked.iter().enumerate() {
+        println!(
+            "{:>4} | {:<30}| {mean:>10.2} | {median:>10.1} | {:>3} | {:>3} | {:>3}",
+            rank + 1,
+            e.path,
+            e.wins,
+            e.losses,
+            e.draws
+        );
+    }
+    println!("Results written to {output_csv}");
+    Ok(())
 }
 
-fn parse_seeds_file(path: &Path) -> io::Result<Vec<u64>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut seeds = Vec::new();
-    for line in reader.lines() {
-        let line = line?;
-        let trimmed = line.trim();
-        if trimmed.is_empty() || trimmed.starts_with('#') {
-            continue;
+/// Scores each pair of entries as a match won by the file that beats the
+/// other on more of the shared seeds, so every comparison is paired.
+fn score_matches(entries: &mut [TournamentEntry]) {
+    for i in 0..entries.len() {
+        for j in i + 1..entries.len() {
+            let pairs = entries[i].rows.iter().zip(&entries[j].rows);
+            let seed_wins_i = pairs.clone().filter(|(a, b)| a > b).count();
+            let seed_wins_j = pairs.filter(|(a, b)| a < b).count();
+            match seed_wins_i.cmp(&seed_wins_j) {
+                std::cmp::Ordering::Greater => {
+                    entries[i].wins += 1;
+                    entries[j].losses += 1;
+                }
+                std::cmp::Ordering::Less => {
+                    entries[j].wins += 1;
+                    entries[i].losses += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    entries[i].draws += 1;
+                    entries[j].draws += 1;
+                }
+            }
         }
-        let seed: u64 = trimmed.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("invalid seed '{trimmed}': {e}"),
-            )
-        })?;
-        seeds.push(seed);
     }
-    if seeds.is_empty() {
+}
+
+/// Perturbs each weight of one file by ±delta and ranks weights by fitness gradient.
+fn run_sensitivity(cli: &Cli, path: &str, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let w = weights::load(Path::new(path))?;
+    let seeds = seeds_or_runs(cli, DEFAULT_SENSITIVITY_RUNS)?;
+    let mut delta = DEFAULT_SENSITIVITY_DELTA;
+    apply_flags!(cli, { "--delta" => delta });
+    if delta <= 0.0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--delta must be > 0",
+        ));
+    }
+
+    println!(
+        "Perturbing {n_weights} weights of {path} by ±{delta} over {} seeds...",
+        seeds.len()
+    );
+    let results = perturbation_sensitivity(&w, sim_length, n_weights, &seeds, delta);
+
+    let output_csv = cli.get("--output-csv").unwrap_or("results/sensitivity.csv");
+    if let Some(parent) = Path::new(output_csv).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output_csv)?);
+    writeln!(
+        writer,
+        "weight_index,name,minus_delta_fitness,baseline,plus_delta_fitness,sensitivity"
+    )?;
+    for p in &results {
+        writeln!(
+            writer,
+            "{},{},{:.5},{:.5},{:.5},{:.5}",
+            p.index, p.name, p.minus_delta_fitness, p.baseline, p.plus_delta_fitness, p.sensitivity
+        )?;
+    }
+
+    println!(
+        "{:<4} {:<20} {:>10} {:>12}",
+        "w", "Evaluator", "Weight", "Sensitivity"
+    );
+    for p in &results {
+        println!(
+            "{:<5} {:<20} {:>10.4} {:>12.2}",
+            p.index, p.name, w[p.index], p.sensitivity
+        );
+    }
+    println!("Results written to {output_csv}");
+    Ok(())
+}
+
+/// Writes mean fitness over a grid of two weights, holding the rest fixed.
+fn run_landscape(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidInput, msg.to_string());
+    let weight_path = cli
+        .get("--weights")
+        .ok_or_else(|| invalid("--weights is required in --landscape mode"))?;
+    let axes: Vec<usize> = cli
+        .get("--axes")
+        .ok_or_else(|| invalid("--axes is required in --landscape mode"))?
+        .split(',')
+        .map(|axis| cli.parse_value("--axes", axis.trim()))
+        .collect::<Result<_, _>>()?;
+    let [x_axis, y_axis] = axes[..] else {
+        return Err(invalid("--axes takes two weight indices, e.g. 0,1"));
+    };
+    if x_axis == y_axis {
+        return Err(invalid("--axes must name two different weights"));
+    }
+    if x_axis.max(y_axis) >= weights::NUM_WEIGHTS {
+        return Err(invalid(&format!(
+            "--axes indices must be below {}",
+            weights::NUM_WEIGHTS
+        )));
+    }
+    let range_arg = cli.get("--range").unwrap_or("-1:1");
+    let (min, max) = range_arg
+        .split_once(':')
+        .and_then(|(min, max)| Some((min.parse::<f64>().ok()?, max.parse::<f64>().ok()?)))
+        .ok_or_else(|| invalid("--range must be MIN:MAX, e.g. -1:1"))?;
+    if min >= max {
+        return Err(invalid("--range MIN must be < MAX"));
+    }
+    let mut steps = DEFAULT_LANDSCAPE_STEPS;
+    apply_flags!(cli, { "--steps" => steps });
+    if steps < 2 {
+        return Err(invalid("--steps must be >= 2"));
+    }
+
+    let w = weights::load(Path::new(weight_path))?;
+    let seeds = seeds_or_runs(cli, DEFAULT_LANDSCAPE_RUNS)?;
+
+    println!(
+        "Sweeping weights {x_axis} and {y_axis} over {steps}x{steps} points in [{min}, {max}], {} seeds each...",
+        seeds.len()
+    );
+    let points = fitness_landscape(
+        &w,
+        (x_axis, y_axis),
+        (min, max),
+        steps,
+        sim_length,
+        n_weights,
+        &seeds,
+    );
+
+    let output_csv = cli.get("--output-csv").unwrap_or("results/landscape.csv");
+    if let Some(parent) = Path::new(output_csv).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output_csv)?);
+    writeln!(writer, "w{x_axis},w{y_axis},fitness")?;
+    for p in &points {
+        writeln!(writer, "{},{},{:.5}", p.x, p.y, p.fitness)?;
+    }
+
+    if let Some(best) = points.iter().max_by(|a, b| a.fitness.total_cmp(&b.fitness)) {
+        println!(
+            "Best point: w{x_axis}={:.4} w{y_axis}={:.4} fitness={:.2}",
+            best.x, best.y, best.fitness
+        );
+    }
+    println!("Results written to {output_csv}");
+    Ok(())
+}
+
+/// Plays one game and reports where the move search spent its time.
+fn run_profile(cli: &Cli, sim_length: usize, n_weights: usize) -> io::Result<()> {
+    if !profile::ENABLED {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--profile needs a build with --features profile",
+        ));
+    }
+    let weight_path = cli.get("--weights").ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--weights is required in --profile mode",
+        )
+    })?;
+    let mut seed = 0_u64;
+    apply_flags!(cli, { "--seed" => seed });
+
+    let w = weights::load(Path::new(weight_path))?;
+    let sim = Simulator::new(w, sim_length).with_n_weights(n_weights);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+
+    profile::reset();
+    let start = Instant::now();
+    let stats = sim.stats_with_rng(&mut rng);
+    let game_time = start.elapsed();
+    let report = profile::snapshot();
+
+    println!(
+        "Game: {} pieces, {} rows cleared in {game_time:.2?} (seed {seed})",
+        stats.pieces_placed, stats.rows_cleared
+    );
+    println!("find_best_move calls:  {}", report.searches);
+    println!(
+        "  wall-clock:          {:.2?} ({:.1}% of game)",
+        report.search_time,
+        100.0 * report.search_time.as_secs_f64() / game_time.as_secs_f64().max(f64::EPSILON)
+    );
+    println!("Placements evaluated:  {}", report.placements);
+    println!(
+        "  evaluation time:     {:.2?} (summed over threads)",
+        report.eval_time
+    );
+    println!("  time per placement:  {:.2?}", report.time_per_placement());
+    Ok(())
+}
+
+/// Seeds from `--seeds`, `--seeds-file` or `--seeds-range`, if any is given.
+fn explicit_seeds(cli: &Cli) -> io::Result<Option<Vec<u64>>> {
+    if let Some(csv) = cli.get("--seeds") {
+        return records::parse_seeds_csv(csv).map(Some);
+    }
+    if let Some(path) = cli.get("--seeds-file") {
+        return records::parse_seeds_file(Path::new(path)).map(Some);
+    }
+    cli.get("--seeds-range")
+        .map(records::parse_seed_range)
+        .transpose()
+}
+
+/// Seeds from [`explicit_seeds`], falling back to `0..--runs`.
+fn seeds_or_runs(cli: &Cli, default_runs: u64) -> io::Result<Vec<u64>> {
+    if let Some(seeds) = explicit_seeds(cli)? {
+        return Ok(seeds);
+    }
+    let mut runs = default_runs;
+    apply_flags!(cli, { "--runs" => runs });
+    if runs == 0 {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            "seeds file did not contain any seeds",
+            "--runs must be > 0",
+        ));
+    }
+    Ok((0..runs).collect())
+}
+
+fn prompt_and_generate() -> io::Result<Vec<(String, [f64; weights::NUM_WEIGHTS])>> {
+    eprintln!("No weights files found (tried weights.txt).");
+    eprint!("Run optimization to generate weights? [y/n] ");
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    if !input.trim().eq_ignore_ascii_case("y") {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "weights file is required to run benchmark",
         ));
     }
-    Ok(seeds)
+
+    let path = Path::new("weights.txt");
+    let result = optimize_weights(&OptimizeConfig::default(), path)?;
+    Ok(vec![("weights.txt".to_string(), result.weights)])
 }
 
 /// Builds a base config with shared sweep settings.
@@ -235,28 +963,24 @@ fn sweep_base_config(
     n_weights: usize,
     averaged: bool,
     averaged_runs: usize,
+    aggregation: Aggregation,
+    verbosity: Verbosity,
 ) -> OptimizeConfig {
     OptimizeConfig {
         sim_length,
         n_weights,
         averaged,
         averaged_runs,
+        aggregation,
+        verbosity,
         ..OptimizeConfig::default()
     }
 }
 
-/// Sweeps a single HSA parameter over a range and writes results to CSV.
-fn sweep_parameter(
-    param: &str,
-    sim_length: usize,
-    n_weights: usize,
-    averaged: bool,
-    averaged_runs: usize,
-) -> io::Result<()> {
-    let base = || sweep_base_config(sim_length, n_weights, averaged, averaged_runs);
-
-    let configs: Vec<(String, OptimizeConfig)> = match param {
-        "pitch-adj-rate" => (49..=99)
+/// Builds the labeled configurations visited by `--sweep <param>`.
+fn sweep_configs(param: &str, base: &OptimizeConfig) -> io::Result<Vec<(String, OptimizeConfig)>> {
+    match param {
+        "pitch-adj-rate" => Ok((49..=99)
             .step_by(10)
             .map(|x| {
                 let v = f64::from(x) / 100.0;
@@ -264,61 +988,104 @@ fn sweep_parameter(
                     format!("{v}"),
                     OptimizeConfig {
                         pitch_adj_rate: v,
-                        ..base()
+                        ..base.clone()
                     },
                 )
             })
-            .collect(),
-        "iterations" => (100..=1000)
+            .collect()),
+        "iterations" => Ok((100..=1000)
             .step_by(100)
             .map(|v| {
                 (
                     format!("{v}"),
                     OptimizeConfig {
                         iterations: v,
-                        ..base()
+                        ..base.clone()
                     },
                 )
             })
-            .collect(),
-        "bandwidth" => [0.05, 0.1, 0.5, 1.0]
+            .collect()),
+        "bandwidth" => Ok([0.05, 0.1, 0.5, 1.0]
             .into_iter()
             .map(|v| {
                 (
                     format!("{v}"),
                     OptimizeConfig {
                         bandwidth: v,
-                        ..base()
+                        ..base.clone()
                     },
                 )
             })
-            .collect(),
-        "sim-length" => (100..=2000)
+            .collect()),
+        "sim-length" => Ok((100..=2000)
             .step_by(100)
             .map(|v| {
                 (
                     format!("{v}"),
                     OptimizeConfig {
                         sim_length: v,
-                        ..base()
+                        ..base.clone()
                     },
                 )
             })
-            .collect(),
-        other => {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "unknown sweep parameter '{other}': \
-                     expected pitch-adj-rate, iterations, bandwidth, or sim-length"
-                ),
-            ));
-        }
+            .collect()),
+        "n-weights" => Ok((1..=weights::NUM_WEIGHTS)
+            .map(|v| {
+                (
+                    format!("{v}"),
+                    OptimizeConfig {
+                        n_weights: v,
+                        ..base.clone()
+                    },
+                )
+            })
+            .collect()),
+        other => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "unknown sweep parameter '{other}': \
+                 expected pitch-adj-rate, iterations, bandwidth, sim-length, n-weights, {}",
+                CeConfig::SWEEP_PARAMS.join(", ")
+            ),
+        )),
+    }
+}
+
+/// Sweeps a single HSA or CES parameter over a range and writes results to a
+/// timestamped CSV in `output_dir`.
+fn sweep_parameter(
+    param: &str,
+    output_dir: &Path,
+    sim_length: usize,
+    n_weights: usize,
+    averaged: bool,
+    averaged_runs: usize,
+    aggregation: Aggregation,
+    verbosity: Verbosity,
+) -> io::Result<()> {
+    let base = sweep_base_config(
+        sim_length,
+        n_weights,
+        averaged,
+        averaged_runs,
+        aggregation,
+        verbosity,
+    );
+    let ce_base = CeConfig {
+        sim_length,
+        n_weights,
+        averaged,
+        averaged_runs,
+        aggregation,
+        verbosity,
+        ..CeConfig::default()
     };
+    if let Some(configs) = ce_base.sweep_configs(param) {
+        return sweep_ce_parameter(param, output_dir, &configs);
+    }
+    let configs = sweep_configs(param, &base)?;
 
-    fs::create_dir_all("results")?;
-    let csv_path = format!("results/benchmark_{}.csv", param.replace('-', "_"));
-    let mut file = BufWriter::new(File::create(&csv_path)?);
+    let (csv_path, mut file) = create_sweep_csv(param, output_dir)?;
 
     println!("Sweeping {param} ({} values)...", configs.len());
 
@@ -329,7 +1096,8 @@ fn sweep_parameter(
             config.accept_rate,
             config.pitch_adj_rate,
             config.bandwidth,
-        );
+        )
+        .with_verbosity(config.verbosity);
 
         println!("  {param} = {label}");
 
@@ -340,77 +1108,114 @@ fn sweep_parameter(
             config.n_weights,
             config.averaged,
             config.averaged_runs,
+            config.aggregation,
+            config.scoring,
+            config.early_stop_patience,
+            config.early_stop_target,
+            &mut rng,
+            None,
+            &mut io::stdout(),
+        );
+        writeln!(file, "{label},{:.5}", result.best_score)?;
+    }
+
+    println!("Results written to {}", csv_path.display());
+    Ok(())
+}
+
+/// Runs each CES config of a `--sweep` and writes its best fitness per value.
+fn sweep_ce_parameter(
+    param: &str,
+    output_dir: &Path,
+    configs: &[(String, CeConfig)],
+) -> io::Result<()> {
+    let (csv_path, mut file) = create_sweep_csv(param, output_dir)?;
+
+    println!("Sweeping {param} with CES ({} values)...", configs.len());
+
+    for (label, config) in configs {
+        println!("  {param} = {label}");
+
+        let mut rng = rand::rng();
+        let result = config.solver().optimize_with_rng(
+            config.sim_length,
+            config.n_weights,
+            config.averaged,
+            config.averaged_runs,
+            config.aggregation,
+            config.scoring,
+            config.std_dev_floor,
             config.early_stop_patience,
             config.early_stop_target,
             &mut rng,
             None,
+            &mut io::stdout(),
         );
         writeln!(file, "{label},{:.5}", result.best_score)?;
     }
 
-    println!("Results written to {csv_path}");
+    println!("Results written to {}", csv_path.display());
     Ok(())
 }
 
-/// Runs N independent optimizations and writes all weights + scores to CSV.
-fn mass_optimize(
+/// Creates the timestamped `benchmark_<param>` CSV of a sweep.
+fn create_sweep_csv(param: &str, output_dir: &Path) -> io::Result<(PathBuf, BufWriter<File>)> {
+    let stem = format!("benchmark_{}", param.replace('-', "_"));
+    let (csv_path, file) = records::create_timestamped(output_dir, &stem, "csv")?;
+    Ok((csv_path, BufWriter::new(file)))
+}
+
+/// Runs N independent optimizations in parallel and writes all weights + scores
+/// to a timestamped CSV in `output_dir`.
+fn run_mass_optimize(
+    cli: &Cli,
     count: usize,
+    output_dir: &Path,
     sim_length: usize,
     n_weights: usize,
     averaged: bool,
     averaged_runs: usize,
+    aggregation: Aggregation,
+    verbosity: Verbosity,
 ) -> io::Result<()> {
-    fs::create_dir_all("results")?;
-    let mut file = BufWriter::new(File::create("results/optimized_weights.csv")?);
-
-    writeln!(
-        file,
-        "Run,Score,{}",
-        (1..=weights::NUM_WEIGHTS)
-            .map(|i| format!("w{i}"))
-            .collect::<Vec<_>>()
-            .join(",")
-    )?;
+    let mut base_seed: u64 = rand::random();
+    let mut threads: usize = 0;
+    apply_flags!(cli, {
+        "--seed"    => base_seed,
+        "--threads" => threads,
+    });
 
     let config = OptimizeConfig {
         sim_length,
         n_weights,
         averaged,
         averaged_runs,
+        aggregation,
+        verbosity,
         ..OptimizeConfig::default()
     };
 
-    println!("Running {count} optimizations...");
-
-    for i in 1..=count {
-        let mut solver = HarmonySearch::new(
-            config.memory_size,
-            config.iterations,
-            config.accept_rate,
-            config.pitch_adj_rate,
-            config.bandwidth,
-        );
-
-        println!("  Run {i}/{count}");
-
-        let mut rng = rand::rng();
-        let result = solver.optimize_with_rng(
-            config.sim_length,
-            config.bounds,
-            config.n_weights,
-            config.averaged,
-            config.averaged_runs,
-            config.early_stop_patience,
-            config.early_stop_target,
-            &mut rng,
-            None,
-        );
+    println!("Running {count} optimizations (base seed {base_seed})...");
+    let runs = mass_optimize(&config, count, base_seed, threads)?;
 
+    let (weights_path, file) = records::create_timestamped(output_dir, "optimized_weights", "csv")?;
+    let mut file = BufWriter::new(file);
+    writeln!(
+        file,
+        "Run,Seed,Score,{}",
+        (1..=weights::NUM_WEIGHTS)
+            .map(|i| format!("w{i}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    )?;
+    for run in &runs {
         writeln!(
             file,
-            "{i},{:.5},{}",
-            result.best_score,
-            result
+            "{},{},{:.5},{}",
+            run.run,
+            run.seed,
+            run.result.best_score,
+            run.result
                 .weights
                 .iter()
                 .map(|w| format!("{w:.5}"))
@@ -419,6 +1224,26 @@ fn mass_optimize(
         )?;
     }
 
-    println!("Results written to results/optimized_weights.csv");
+    println!("Results written to {}", weights_path.display());
+
+    let solutions: Vec<_> = runs.iter().map(|run| run.result.weights).collect();
+    let spread = diversity(&solutions);
+    let (diversity_path, file) = records::create_timestamped(output_dir, "diversity", "csv")?;
+    let mut file = BufWriter::new(file);
+    writeln!(file, "Metric,Value")?;
+    writeln!(
+        file,
+        "mean_pairwise_distance,{:.5}",
+        spread.mean_pairwise_distance
+    )?;
+    for (i, variance) in spread.variances.iter().enumerate() {
+        writeln!(file, "variance_w{},{variance:.5}", i + 1)?;
+    }
+
+    println!(
+        "Mean pairwise distance of normalized solutions: {:.4}",
+        spread.mean_pairwise_distance
+    );
+    println!("Diversity written to {}", diversity_path.display());
     Ok(())
 }
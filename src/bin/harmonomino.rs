@@ -1,11 +1,19 @@
+use std::fmt::Debug;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use harmonomino::apply_flags;
 use harmonomino::cli::Cli;
+use harmonomino::eval_fns::list_evaluators;
 use harmonomino::harmony::{
-    CeConfig, OptimizeConfig, optimize_weights_ce_with_seed, optimize_weights_with_seed,
+    CeConfig, CmaConfig, GaConfig, OptimizeConfig, Verbosity, optimize_weights_ce_with_seed,
+    optimize_weights_cmaes_with_seed, optimize_weights_ga_with_seed, optimize_weights_with_seed,
+    sensitivity_ranking,
 };
+use harmonomino::weights;
+
+/// Default number of seeded games per variant in the sensitivity pass.
+const DEFAULT_SENSITIVITY_RUNS: u64 = 5;
 
 fn main() -> io::Result<()> {
     let cli = Cli::parse();
@@ -15,14 +23,21 @@ fn main() -> io::Result<()> {
         return Ok(());
     }
 
+    if cli.has_flag("--list-evals") {
+        print!("{}", list_evaluators());
+        return Ok(());
+    }
+
     let algorithm = cli.get("--algorithm").unwrap_or("hsa");
 
     match algorithm {
         "hsa" => run_hsa(&cli),
         "ce" => run_ce(&cli),
+        "ga" => run_ga(&cli),
+        "cmaes" => run_cmaes(&cli),
         other => Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            format!("unknown algorithm '{other}': expected hsa or ce"),
+            format!("unknown algorithm '{other}': expected hsa, ce, ga, or cmaes"),
         )),
     }
 }
@@ -38,10 +53,20 @@ fn run_hsa(cli: &Cli) -> io::Result<()> {
         "--sim-length"     => config.sim_length,
         "--n-weights"      => config.n_weights,
         "--averaged-runs"  => config.averaged_runs,
+        "--aggregation"    => config.aggregation,
+        "--scoring"        => config.scoring,
         "--early-stop-patience" => config.early_stop_patience,
         "--early-stop-target"   => config.early_stop_target,
+        "--restart-patience"    => config.restart_patience,
+        "--max-restarts"        => config.max_restarts,
+        "--rel-improve-window"    => config.rel_improve_window,
+        "--rel-improve-threshold" => config.rel_improve_threshold,
     });
     config.averaged = cli.has_flag("--averaged");
+    config.cache_moves = cli.has_flag("--cache-moves");
+    config.verbosity = verbosity(cli);
+    config.normalize = cli.has_flag("--normalize");
+    config.elitism = !cli.has_flag("--no-elitism");
 
     let seed: Option<u64> = cli
         .get("--seed")
@@ -53,8 +78,13 @@ fn run_hsa(cli: &Cli) -> io::Result<()> {
         .get("--output")
         .map_or_else(|| PathBuf::from("weights.txt"), PathBuf::from);
 
-    let _ = optimize_weights_with_seed(&config, &output, seed, log_csv.as_deref())?;
-    Ok(())
+    if cli.has_flag("--dry-run") {
+        print_dry_run(&config, config.estimated_games(), &output, seed);
+        return Ok(());
+    }
+
+    let result = optimize_weights_with_seed(&config, &output, seed, log_csv.as_deref())?;
+    report_sensitivity(cli, &result.weights, config.sim_length, config.n_weights)
 }
 
 fn run_ce(cli: &Cli) -> io::Result<()> {
@@ -62,16 +92,26 @@ fn run_ce(cli: &Cli) -> io::Result<()> {
     apply_flags!(cli, {
         "--n-samples"      => config.n_samples,
         "--n-elite"        => config.n_elite,
+        "--elite-weighting" => config.elite_weighting,
         "--iterations"     => config.iterations,
         "--sim-length"     => config.sim_length,
         "--n-weights"      => config.n_weights,
         "--averaged-runs"  => config.averaged_runs,
+        "--aggregation"    => config.aggregation,
+        "--scoring"        => config.scoring,
         "--initial-std-dev" => config.initial_std_dev,
         "--std-dev-floor"  => config.std_dev_floor,
         "--early-stop-patience" => config.early_stop_patience,
         "--early-stop-target"   => config.early_stop_target,
+        "--restart-patience"    => config.restart_patience,
+        "--max-restarts"        => config.max_restarts,
+        "--rel-improve-window"    => config.rel_improve_window,
+        "--rel-improve-threshold" => config.rel_improve_threshold,
     });
     config.averaged = cli.has_flag("--averaged");
+    config.cache_moves = cli.has_flag("--cache-moves");
+    config.verbosity = verbosity(cli);
+    config.normalize = cli.has_flag("--normalize");
 
     let seed: Option<u64> = cli
         .get("--seed")
@@ -83,6 +123,145 @@ fn run_ce(cli: &Cli) -> io::Result<()> {
         .get("--output")
         .map_or_else(|| PathBuf::from("weights.txt"), PathBuf::from);
 
-    let _ = optimize_weights_ce_with_seed(&config, &output, seed, log_csv.as_deref())?;
+    if cli.has_flag("--dry-run") {
+        print_dry_run(&config, config.estimated_games(), &output, seed);
+        return Ok(());
+    }
+
+    let result = optimize_weights_ce_with_seed(&config, &output, seed, log_csv.as_deref())?;
+    report_sensitivity(cli, &result.weights, config.sim_length, config.n_weights)
+}
+
+fn run_ga(cli: &Cli) -> io::Result<()> {
+    let mut config = GaConfig::default();
+    apply_flags!(cli, {
+        "--population"       => config.population,
+        "--iterations"       => config.iterations,
+        "--mutation-rate"    => config.mutation_rate,
+        "--mutation-std-dev" => config.mutation_std_dev,
+        "--crossover-rate"   => config.crossover_rate,
+        "--elitism"          => config.elitism,
+        "--tournament-size"  => config.tournament_size,
+        "--sim-length"       => config.sim_length,
+        "--n-weights"        => config.n_weights,
+        "--averaged-runs"    => config.averaged_runs,
+        "--aggregation"      => config.aggregation,
+        "--scoring"          => config.scoring,
+        "--early-stop-patience" => config.early_stop_patience,
+        "--early-stop-target"   => config.early_stop_target,
+    });
+    config.averaged = cli.has_flag("--averaged");
+    config.cache_moves = cli.has_flag("--cache-moves");
+    config.verbosity = verbosity(cli);
+    config.normalize = cli.has_flag("--normalize");
+
+    let seed: Option<u64> = cli
+        .get("--seed")
+        .map(|v| cli.parse_value("--seed", v))
+        .transpose()?;
+    let log_csv = cli.get("--log-csv").map(PathBuf::from);
+
+    let output: PathBuf = cli
+        .get("--output")
+        .map_or_else(|| PathBuf::from("weights.txt"), PathBuf::from);
+
+    if cli.has_flag("--dry-run") {
+        print_dry_run(&config, config.estimated_games(), &output, seed);
+        return Ok(());
+    }
+
+    let result = optimize_weights_ga_with_seed(&config, &output, seed, log_csv.as_deref())?;
+    report_sensitivity(cli, &result.weights, config.sim_length, config.n_weights)
+}
+
+fn run_cmaes(cli: &Cli) -> io::Result<()> {
+    let mut config = CmaConfig::default();
+    apply_flags!(cli, {
+        "--population"     => config.population,
+        "--iterations"     => config.iterations,
+        "--initial-sigma"  => config.initial_sigma,
+        "--sim-length"     => config.sim_length,
+        "--n-weights"      => config.n_weights,
+        "--averaged-runs"  => config.averaged_runs,
+        "--aggregation"    => config.aggregation,
+        "--scoring"        => config.scoring,
+        "--early-stop-patience" => config.early_stop_patience,
+        "--early-stop-target"   => config.early_stop_target,
+    });
+    config.averaged = cli.has_flag("--averaged");
+    config.cache_moves = cli.has_flag("--cache-moves");
+    config.verbosity = verbosity(cli);
+    config.normalize = cli.has_flag("--normalize");
+
+    let seed: Option<u64> = cli
+        .get("--seed")
+        .map(|v| cli.parse_value("--seed", v))
+        .transpose()?;
+    let log_csv = cli.get("--log-csv").map(PathBuf::from);
+
+    let output: PathBuf = cli
+        .get("--output")
+        .map_or_else(|| PathBuf::from("weights.txt"), PathBuf::from);
+
+    if cli.has_flag("--dry-run") {
+        print_dry_run(&config, config.estimated_games(), &output, seed);
+        return Ok(());
+    }
+
+    let result = optimize_weights_cmaes_with_seed(&config, &output, seed, log_csv.as_deref())?;
+    report_sensitivity(cli, &result.weights, config.sim_length, config.n_weights)
+}
+
+/// Prints the resolved configuration for `--dry-run` instead of running it.
+fn print_dry_run(config: &impl Debug, games: usize, output: &Path, seed: Option<u64>) {
+    println!("{config:#?}");
+    println!("Output: {}", output.display());
+    println!(
+        "Seed: {}",
+        seed.map_or_else(|| "random".to_string(), |seed| seed.to_string())
+    );
+    println!("Estimated games: {games} (before early stopping)");
+}
+
+/// Quiet progress if `--quiet` is set.
+fn verbosity(cli: &Cli) -> Verbosity {
+    if cli.has_flag("--quiet") {
+        Verbosity::Quiet
+    } else {
+        Verbosity::Normal
+    }
+}
+
+/// Prints a one-at-a-time sensitivity ranking of the best weights if `--sensitivity` is set.
+fn report_sensitivity(
+    cli: &Cli,
+    best: &[f64; weights::NUM_WEIGHTS],
+    sim_length: usize,
+    n_weights: usize,
+) -> io::Result<()> {
+    if !cli.has_flag("--sensitivity") {
+        return Ok(());
+    }
+    let mut runs = DEFAULT_SENSITIVITY_RUNS;
+    apply_flags!(cli, { "--sensitivity-runs" => runs });
+
+    println!("Running sensitivity analysis ({runs} seeded games per weight)...");
+    let seeds: Vec<u64> = (0..runs).collect();
+    let report = sensitivity_ranking(best, sim_length, n_weights, &seeds);
+
+    println!("Baseline fitness: {:.2}", report.baseline);
+    println!(
+        "{:<4} {:<20} {:>10} {:>12}",
+        "Rank", "Evaluator", "Weight", "Fitness drop"
+    );
+    for (rank, entry) in report.ranking.iter().enumerate() {
+        println!(
+            "{:<4} {:<20} {:>10.4} {:>12.2}",
+            rank + 1,
+            entry.name,
+            best[entry.index],
+            entry.fitness_drop
+        );
+    }
     Ok(())
 }
@@ -1,10 +1,64 @@
 use std::io;
+use std::path::Path;
 
-use harmonomino::tui::{App, run_event_loop};
+use harmonomino::cli::Cli;
+use harmonomino::game::seed_default_rng;
+use harmonomino::tui::{App, ColorScheme, DropMode, Theme, run_event_loop};
+use harmonomino::weights;
+
+const WEIGHTS_PATH: &str = "weights.txt";
+
+const USAGE: &str = "\
+Usage: tetris [OPTIONS]
+
+Plays Tetris in the terminal.
+
+Options:
+  --drop-mode <MODE>    Down key: soft, sonic, hard-only [default: soft]
+  --palette <NAME>      Piece colors: classic, colorblind [default: classic]
+  --theme <PATH>        Load board colors from a key = value file (C returns to --palette)
+  --seed <N>            Seed the piece sequence for a reproducible session
+
+Press T in game to let the agent play while editing its weights live.
+  --help                Print this help message";
 
 fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+
+    if cli.help_requested() {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    let drop_mode: DropMode = cli
+        .get("--drop-mode")
+        .map(|v| cli.parse_value("--drop-mode", v))
+        .transpose()?
+        .unwrap_or_default();
+    let palette: ColorScheme = cli
+        .get("--palette")
+        .map(|v| cli.parse_value("--palette", v))
+        .transpose()?
+        .unwrap_or_default();
+    if let Some(seed) = cli.get("--seed") {
+        seed_default_rng(cli.parse_value("--seed", seed)?);
+    }
+
+    // Weights are optional here; they feed the eval-debug panel and tuning mode.
+    let path = Path::new(WEIGHTS_PATH);
+    let mut app = App::new()
+        .with_drop_mode(drop_mode)
+        .with_color_scheme(palette)
+        .with_weights_path(path);
+    if path.exists() {
+        app = app.with_weights(weights::load(path)?);
+    }
+    if let Some(theme_path) = cli.get("--theme") {
+        app = app.with_theme(Theme::load(Path::new(theme_path))?);
+    }
+
     let mut terminal = ratatui::init();
-    let result = run_event_loop(&mut terminal, &mut App::new());
+    let result = run_event_loop(&mut terminal, &mut app);
     ratatui::restore();
     result
 }
@@ -3,13 +3,28 @@ use std::path::Path;
 
 use harmonomino::cli::Cli;
 use harmonomino::harmony::{OptimizeConfig, optimize_weights};
-use harmonomino::tui::{VersusApp, run_event_loop};
+use harmonomino::tui::{ColorScheme, Difficulty, Theme, VersusApp, run_event_loop};
 use harmonomino::weights;
 
 const WEIGHTS_PATH: &str = "weights.txt";
 
 fn main() -> io::Result<()> {
-    let _cli = Cli::parse();
+    let cli = Cli::parse();
+    let palette: ColorScheme = cli
+        .get("--palette")
+        .map(|v| cli.parse_value("--palette", v))
+        .transpose()?
+        .unwrap_or_default();
+    let difficulty: Difficulty = cli
+        .get("--difficulty")
+        .map(|v| cli.parse_value("--difficulty", v))
+        .transpose()?
+        .unwrap_or_default();
+
+    let theme = cli
+        .get("--theme")
+        .map(|p| Theme::load(Path::new(p)))
+        .transpose()?;
 
     let path = Path::new(WEIGHTS_PATH);
     let w = if path.exists() {
@@ -18,8 +33,15 @@ fn main() -> io::Result<()> {
         prompt_and_generate(path)?
     };
 
+    let mut app = VersusApp::new(w)
+        .with_color_scheme(palette)
+        .with_difficulty(difficulty);
+    if let Some(theme) = theme {
+        app = app.with_theme(theme);
+    }
+
     let mut terminal = ratatui::init();
-    let result = run_event_loop(&mut terminal, &mut VersusApp::new(w));
+    let result = run_event_loop(&mut terminal, &mut app);
     ratatui::restore();
     result
 }
@@ -48,24 +48,74 @@ impl Cli {
             .collect()
     }
 
+    /// Returns every value following `flag` up to the next `--` argument.
+    #[must_use]
+    pub fn get_list(&self, flag: &str) -> Vec<&str> {
+        self.args
+            .iter()
+            .position(|a| a == flag)
+            .map(|i| {
+                self.args[i + 1..]
+                    .iter()
+                    .take_while(|a| !a.starts_with("--"))
+                    .map(String::as_str)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the `count` values following `flag`, if present and all supplied.
+    #[must_use]
+    pub fn get_n(&self, flag: &str, count: usize) -> Option<Vec<&str>> {
+        let start = self.args.iter().position(|a| a == flag)? + 1;
+        let values = self.args.get(start..start + count)?;
+        if values.iter().any(|v| v.starts_with("--")) {
+            return None;
+        }
+        Some(values.iter().map(String::as_str).collect())
+    }
+
     /// Parses a string value into `T`, producing a user-friendly error on failure.
     ///
     /// # Errors
     ///
-    /// Returns `InvalidInput` if the value cannot be parsed.
-    pub fn parse_value<T: std::str::FromStr>(&self, flag: &str, value: &str) -> io::Result<T>
+    /// Returns [`CliError::InvalidValue`] if the value cannot be parsed.
+    pub fn parse_value<T: std::str::FromStr>(&self, flag: &str, value: &str) -> Result<T, CliError>
     where
         T::Err: std::fmt::Display,
     {
-        value.parse().map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("invalid value for {flag}: {e}"),
-            )
+        value.parse().map_err(|e: T::Err| CliError::InvalidValue {
+            flag: flag.to_string(),
+            message: e.to_string(),
         })
     }
 }
 
+/// Errors produced while interpreting command-line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// A flag's value could not be parsed.
+    InvalidValue { flag: String, message: String },
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidValue { flag, message } => {
+                write!(f, "invalid value for {flag}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<CliError> for io::Error {
+    fn from(e: CliError) -> Self {
+        Self::new(io::ErrorKind::InvalidInput, e)
+    }
+}
+
 /// Applies CLI flags to struct fields in a single declarative block.
 ///
 /// For each `"--flag" => field` pair, if the flag is present on the command line
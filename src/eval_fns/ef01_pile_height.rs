@@ -6,15 +6,21 @@ use crate::game::Board;
 pub struct PileHeight;
 
 impl EvalFn for PileHeight {
+    fn name(&self) -> &'static str {
+        "pile_height"
+    }
+
+    fn label(&self) -> &'static str {
+        "Pile Height"
+    }
+
+    fn description(&self) -> &'static str {
+        "Height of the topmost filled cell"
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn eval(&self, board: &Board) -> u16 {
-        // Find the highest row with any occupied cell
-        for row in (0..Board::HEIGHT).rev() {
-            if board[row].iter().any(|&cell| cell) {
-                return (row + 1) as u16;
-            }
-        }
-        0
+        board.stack_height() as u16
     }
 }
 
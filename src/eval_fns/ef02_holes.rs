@@ -1,22 +1,28 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The number of all gaps with at least one occupied cell above them.
 pub struct Holes;
 
 impl EvalFn for Holes {
+    fn name(&self) -> &'static str {
+        "holes"
+    }
+
+    fn label(&self) -> &'static str {
+        "Holes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Empty cells with a filled cell above them"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        let mut holes = 0;
-        for (row_idx, row) in board.rows_bottom_up() {
-            for (col, &occupied) in row.iter().enumerate() {
-                // A hole is an empty cell with at one filled cell somewhere above it
-                if !occupied && row_idx < Board::HEIGHT - 1 && board.has_filled_above(row_idx, col)
-                {
-                    holes += 1;
-                }
-            }
-        }
-        holes
+        board.holes().total
+    }
+
+    fn eval_with(&self, _board: &Board, summary: &BoardSummary) -> u16 {
+        summary.holes.total
     }
 }
 
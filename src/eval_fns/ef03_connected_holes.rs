@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// Counts vertically connected gaps as one hole.
@@ -7,39 +7,24 @@ use crate::game::Board;
 pub struct ConnectedHoles;
 
 impl EvalFn for ConnectedHoles {
-    fn eval(&self, board: &Board) -> u16 {
-        let mut total = 0;
+    fn name(&self) -> &'static str {
+        "connected_holes"
+    }
 
-        for col in 0..Board::WIDTH {
-            // Find the highest filled cell in this column
-            let mut top_filled = None;
-            for row in (0..Board::HEIGHT).rev() {
-                if board[row][col] {
-                    top_filled = Some(row);
-                    break;
-                }
-            }
+    fn label(&self) -> &'static str {
+        "Connected Holes"
+    }
 
-            // No filled cells means no holes in this column
-            let Some(top) = top_filled else { continue };
+    fn description(&self) -> &'static str {
+        "Holes, counting vertical runs of empty cells once"
+    }
 
-            // Count connected hole groups below the top
-            let mut in_hole = false;
-            for row in (0..top).rev() {
-                if board[row][col] {
-                    // Filled cell ends the hole group
-                    in_hole = false;
-                } else {
-                    // Empty cell with filled cell above = start of hole group
-                    if !in_hole {
-                        total += 1;
-                        in_hole = true;
-                    }
-                }
-            }
-        }
+    fn eval(&self, board: &Board) -> u16 {
+        board.holes().connected_groups
+    }
 
-        total
+    fn eval_with(&self, _board: &Board, summary: &BoardSummary) -> u16 {
+        summary.holes.connected_groups
     }
 }
 
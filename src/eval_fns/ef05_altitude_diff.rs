@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The difference between the highest occupied cell and the lowest gap
@@ -6,19 +6,33 @@ use crate::game::Board;
 pub struct AltitudeDiff;
 
 impl EvalFn for AltitudeDiff {
-    #[allow(clippy::cast_possible_truncation)]
-    fn eval(&self, board: &Board) -> u16 {
-        let mut max_height = 0usize;
-        let mut min_height = Board::HEIGHT;
+    fn name(&self) -> &'static str {
+        "altitude_diff"
+    }
 
-        for col in 0..Board::WIDTH {
-            let height = board.column_height(col);
-            max_height = max_height.max(height);
-            min_height = min_height.min(height);
-        }
+    fn label(&self) -> &'static str {
+        "Altitude Diff"
+    }
 
-        (max_height - min_height) as u16
+    fn description(&self) -> &'static str {
+        "Tallest minus shortest column height"
     }
+
+    fn eval(&self, board: &Board) -> u16 {
+        altitude_diff(&board.column_heights()[..board.width()])
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        altitude_diff(&summary.heights[..board.width()])
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn altitude_diff(heights: &[usize]) -> u16 {
+    let max_height = heights.iter().copied().max().unwrap_or(0);
+    let min_height = heights.iter().copied().min().unwrap_or(0);
+
+    (max_height - min_height) as u16
 }
 
 #[cfg(test)]
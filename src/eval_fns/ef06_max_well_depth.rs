@@ -1,30 +1,69 @@
 use crate::eval_fns::EvalFn;
 use crate::game::Board;
 
-pub struct MaxWellDepth;
+/// Whether a board edge counts as a well wall by default.
+pub const DEFAULT_COUNT_EDGE_WELLS: bool = true;
+
+pub struct MaxWellDepth {
+    /// Treat the board edges as walls, so edge columns can form wells.
+    pub count_edge_wells: bool,
+}
+
+impl Default for MaxWellDepth {
+    fn default() -> Self {
+        Self {
+            count_edge_wells: DEFAULT_COUNT_EDGE_WELLS,
+        }
+    }
+}
 
 impl EvalFn for MaxWellDepth {
+    fn name(&self) -> &'static str {
+        "max_well_depth"
+    }
+
+    fn label(&self) -> &'static str {
+        "Max Well Depth"
+    }
+
+    fn description(&self) -> &'static str {
+        "Depth of the deepest well"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        (0..Board::WIDTH)
-            .map(|col| calculate_well_depth(board, col))
+        (0..board.width())
+            .map(|col| calculate_well_depth_with(board, col, self.count_edge_wells))
             .max()
             .unwrap_or(0)
     }
 }
 
+/// Well depth of `col`, treating the board edges as walls.
 #[must_use]
 pub fn calculate_well_depth(board: &Board, col: usize) -> u16 {
+    calculate_well_depth_with(board, col, DEFAULT_COUNT_EDGE_WELLS)
+}
+
+/// Well depth of `col`, counting open cells bordered on both sides.
+///
+/// Only cells with nothing above them count. With `count_edge_wells`, a board edge counts
+/// as a filled neighbour; otherwise edge columns never form wells.
+#[must_use]
+pub fn calculate_well_depth_with(board: &Board, col: usize, count_edge_wells: bool) -> u16 {
     let mut depth = 0;
-    for row in 0..Board::HEIGHT {
+    for row in 0..board.height() {
         if board[row][col] || board.has_filled_above(row, col) {
             continue;
         }
-        // TODO: check if well is allowed to be at edge of the board (I think so)
-        let left_filled = if col > 0 { board[row][col - 1] } else { true };
-        let right_filled = if col < Board::WIDTH - 1 {
+        let left_filled = if col > 0 {
+            board[row][col - 1]
+        } else {
+            count_edge_wells
+        };
+        let right_filled = if col < board.width() - 1 {
             board[row][col + 1]
         } else {
-            true
+            count_edge_wells
         };
         if left_filled && right_filled {
             depth += 1;
@@ -32,3 +71,35 @@ pub fn calculate_well_depth(board: &Board, col: usize) -> u16 {
     }
     depth
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_fns::test_util::board_with_open_columns;
+    use crate::game::Board;
+
+    #[test]
+    fn test_central_well_ignores_edge_setting() {
+        let board = board_with_open_columns(3, &[4]);
+        for count_edge_wells in [true, false] {
+            let ef = MaxWellDepth { count_edge_wells };
+            assert_eq!(ef.eval(&board), 3);
+        }
+    }
+
+    #[test]
+    fn test_edge_well_counted_by_default() {
+        let board = board_with_open_columns(3, &[0]);
+        assert_eq!(MaxWellDepth::default().eval(&board), 3);
+        assert_eq!(calculate_well_depth(&board, 0), 3);
+    }
+
+    #[test]
+    fn test_edge_well_ignored_without_edge_walls() {
+        let board = board_with_open_columns(3, &[Board::WIDTH - 1]);
+        let ef = MaxWellDepth {
+            count_edge_wells: false,
+        };
+        assert_eq!(ef.eval(&board), 0);
+    }
+}
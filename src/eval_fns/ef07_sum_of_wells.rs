@@ -1,12 +1,59 @@
-use crate::eval_fns::{EvalFn, ef06_max_well_depth::calculate_well_depth};
+use crate::eval_fns::EvalFn;
+use crate::eval_fns::ef06_max_well_depth::{DEFAULT_COUNT_EDGE_WELLS, calculate_well_depth_with};
 use crate::game::Board;
 
-pub struct SumOfWells;
+pub struct SumOfWells {
+    /// Treat the board edges as walls, so edge columns can form wells.
+    pub count_edge_wells: bool,
+}
+
+impl Default for SumOfWells {
+    fn default() -> Self {
+        Self {
+            count_edge_wells: DEFAULT_COUNT_EDGE_WELLS,
+        }
+    }
+}
 
 impl EvalFn for SumOfWells {
+    fn name(&self) -> &'static str {
+        "sum_of_wells"
+    }
+
+    fn label(&self) -> &'static str {
+        "Sum of Wells"
+    }
+
+    fn description(&self) -> &'static str {
+        "Total depth of all wells"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        (0..Board::WIDTH)
-            .map(|col| calculate_well_depth(board, col))
+        (0..board.width())
+            .map(|col| calculate_well_depth_with(board, col, self.count_edge_wells))
             .sum()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    #[test]
+    fn test_edge_well_under_each_setting() {
+        // Two-deep well on the left edge plus a one-deep well in column 5
+        let mut board = Board::new();
+        for row in 0..2 {
+            for col in 1..Board::WIDTH {
+                board[row][col] = row == 0 || col != 5;
+            }
+        }
+
+        assert_eq!(SumOfWells::default().eval(&board), 3);
+        let ef = SumOfWells {
+            count_edge_wells: false,
+        };
+        assert_eq!(ef.eval(&board), 1);
+    }
+}
@@ -4,6 +4,18 @@ use crate::game::Board;
 pub struct Blocks;
 
 impl EvalFn for Blocks {
+    fn name(&self) -> &'static str {
+        "blocks"
+    }
+
+    fn label(&self) -> &'static str {
+        "Blocks"
+    }
+
+    fn description(&self) -> &'static str {
+        "Number of filled cells"
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn eval(&self, board: &Board) -> u16 {
         board.all_cells().filter(|&&cell| cell).count() as u16
@@ -4,6 +4,18 @@ use crate::game::Board;
 pub struct WeightedBlocks;
 
 impl EvalFn for WeightedBlocks {
+    fn name(&self) -> &'static str {
+        "weighted_blocks"
+    }
+
+    fn label(&self) -> &'static str {
+        "Weighted Blocks"
+    }
+
+    fn description(&self) -> &'static str {
+        "Filled cells weighted by their row height"
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn eval(&self, board: &Board) -> u16 {
         board
@@ -6,24 +6,36 @@ use crate::game::Board;
 pub struct RowTransitions;
 
 impl EvalFn for RowTransitions {
+    fn name(&self) -> &'static str {
+        "row_transitions"
+    }
+
+    fn label(&self) -> &'static str {
+        "Row Transitions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Horizontal filled/empty transitions, walls count as filled"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
         let mut transitions = 0;
 
-        for row in 0..Board::HEIGHT {
+        for row in 0..board.height() {
             // Left wall to first cell
             if !board[row][0] {
                 transitions += 1;
             }
 
             // Transitions within the row
-            for col in 0..Board::WIDTH - 1 {
+            for col in 0..board.width() - 1 {
                 if board[row][col] != board[row][col + 1] {
                     transitions += 1;
                 }
             }
 
             // Last cell to right wall
-            if !board[row][Board::WIDTH - 1] {
+            if !board[row][board.width() - 1] {
                 transitions += 1;
             }
         }
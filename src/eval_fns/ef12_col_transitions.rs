@@ -6,17 +6,29 @@ use crate::game::Board;
 pub struct ColTransitions;
 
 impl EvalFn for ColTransitions {
+    fn name(&self) -> &'static str {
+        "col_transitions"
+    }
+
+    fn label(&self) -> &'static str {
+        "Col Transitions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Vertical filled/empty transitions, floor counts as filled"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
         let mut transitions = 0;
 
-        for col in 0..Board::WIDTH {
+        for col in 0..board.width() {
             // Floor to bottom cell (floor counts as occupied)
             if !board[0][col] {
                 transitions += 1;
             }
 
             // Transitions within the column
-            for row in 0..Board::HEIGHT - 1 {
+            for row in 0..board.height() - 1 {
                 if board[row][col] != board[row + 1][col] {
                     transitions += 1;
                 }
@@ -24,7 +36,7 @@ impl EvalFn for ColTransitions {
 
             // Top cell to ceiling (ceiling counts as empty, so transition only if top cell is filled)
             // unless we don't want to count it, unclear based on paper, purposefully untested
-            transitions += u16::from(board[Board::HEIGHT - 1][col]);
+            transitions += u16::from(board[board.height() - 1][col]);
         }
 
         transitions
@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The height (1-indexed row) of the topmost hole on the game board.
@@ -7,12 +7,32 @@ use crate::game::Board;
 pub struct HighestHole;
 
 impl EvalFn for HighestHole {
-    #[allow(clippy::cast_possible_truncation)]
+    fn name(&self) -> &'static str {
+        "highest_hole"
+    }
+
+    fn label(&self) -> &'static str {
+        "Highest Hole"
+    }
+
+    fn description(&self) -> &'static str {
+        "Height of the topmost hole"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        board.highest_hole_row().map_or(0, |row| (row + 1) as u16)
+        height_of(board.highest_hole_row())
+    }
+
+    fn eval_with(&self, _board: &Board, summary: &BoardSummary) -> u16 {
+        height_of(summary.holes.highest_row)
     }
 }
 
+#[allow(clippy::cast_possible_truncation)]
+fn height_of(hole_row: Option<usize>) -> u16 {
+    hole_row.map_or(0, |row| (row + 1) as u16)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The number of filled cells above the highest hole.
@@ -6,21 +6,41 @@ use crate::game::Board;
 pub struct BlocksAboveHighest;
 
 impl EvalFn for BlocksAboveHighest {
+    fn name(&self) -> &'static str {
+        "blocks_above_highest"
+    }
+
+    fn label(&self) -> &'static str {
+        "Blocks Above Hole"
+    }
+
+    fn description(&self) -> &'static str {
+        "Filled cells above the highest hole"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        let Some(hole_row) = board.highest_hole_row() else {
-            return 0;
-        };
+        blocks_above(board, board.highest_hole_row())
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        blocks_above(board, summary.holes.highest_row)
+    }
+}
+
+fn blocks_above(board: &Board, hole_row: Option<usize>) -> u16 {
+    let Some(hole_row) = hole_row else {
+        return 0;
+    };
 
-        let mut count = 0;
-        for row in (hole_row + 1)..Board::HEIGHT {
-            for col in 0..Board::WIDTH {
-                if board[row][col] {
-                    count += 1;
-                }
+    let mut count = 0;
+    for row in (hole_row + 1)..board.height() {
+        for col in 0..board.width() {
+            if board[row][col] {
+                count += 1;
             }
         }
-        count
     }
+    count
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The number of rows located above the Highest Hole that have more than 8 filled cells (I think).
@@ -6,19 +6,40 @@ use crate::game::Board;
 pub struct PotentialRows;
 
 impl EvalFn for PotentialRows {
+    fn name(&self) -> &'static str {
+        "potential_rows"
+    }
+
+    fn label(&self) -> &'static str {
+        "Potential Rows"
+    }
+
+    fn description(&self) -> &'static str {
+        "Nearly full rows above the highest hole"
+    }
+
     fn eval(&self, board: &Board) -> u16 {
-        let Some(hole_row) = board.highest_hole_row() else {
-            return 0;
-        };
+        potential_rows(board, board.highest_hole_row())
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        potential_rows(board, summary.holes.highest_row)
+    }
+}
+
+fn potential_rows(board: &Board, hole_row: Option<usize>) -> u16 {
+    let Some(hole_row) = hole_row else {
+        return 0;
+    };
 
-        let mut count = 0;
-        for row in (hole_row + 1)..Board::HEIGHT {
-            if board[row].iter().filter(|&&c| c).count() > 8 {
-                count += 1;
-            }
+    let mut count = 0;
+    for row in (hole_row + 1)..board.height() {
+        // More than 8 of 10, i.e. at most two cells missing
+        if board.row(row).iter().filter(|&&c| c).count() > board.width().saturating_sub(2) {
+            count += 1;
         }
-        count
     }
+    count
 }
 
 #[cfg(test)]
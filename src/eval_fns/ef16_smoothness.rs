@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The sum of all absolute differences of adjacent column heights,
@@ -6,26 +6,43 @@ use crate::game::Board;
 pub struct Smoothness;
 
 impl EvalFn for Smoothness {
-    fn eval(&self, board: &Board) -> u16 {
-        #[allow(clippy::cast_possible_truncation)]
-        let heights: [u16; Board::WIDTH] =
-            std::array::from_fn(|col| board.column_height(col) as u16);
+    fn name(&self) -> &'static str {
+        "smoothness"
+    }
 
-        let mut sum = 0;
+    fn label(&self) -> &'static str {
+        "Smoothness"
+    }
 
-        // Adjacent column differences
-        for i in 0..Board::WIDTH - 1 {
-            sum += heights[i].abs_diff(heights[i + 1]);
-        }
+    fn description(&self) -> &'static str {
+        "Sum of adjacent column height differences"
+    }
 
-        // First and last column difference
-        // NOTE: Maybe remove dispite paper, I don't see relevance
-        sum += heights[0].abs_diff(heights[Board::WIDTH - 1]);
+    fn eval(&self, board: &Board) -> u16 {
+        smoothness(&board.column_heights()[..board.width()])
+    }
 
-        sum
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        smoothness(&summary.heights[..board.width()])
     }
 }
 
+#[allow(clippy::cast_possible_truncation)]
+fn smoothness(heights: &[usize]) -> u16 {
+    let mut sum = 0;
+
+    // Adjacent column differences
+    for pair in heights.windows(2) {
+        sum += pair[0].abs_diff(pair[1]) as u16;
+    }
+
+    // First and last column difference
+    // NOTE: Maybe remove dispite paper, I don't see relevance
+    sum += heights[0].abs_diff(heights[heights.len() - 1]) as u16;
+
+    sum
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::eval_fns::EvalFn;
+use crate::eval_fns::{BoardSummary, EvalFn};
 use crate::game::Board;
 
 /// The number of rows that contain at least one hole.
@@ -6,19 +6,24 @@ use crate::game::Board;
 pub struct RowHoles;
 
 impl EvalFn for RowHoles {
-    fn eval(&self, board: &Board) -> u16 {
-        let mut count = 0;
+    fn name(&self) -> &'static str {
+        "row_holes"
+    }
+
+    fn label(&self) -> &'static str {
+        "Row Holes"
+    }
 
-        for row in 0..Board::HEIGHT - 1 {
-            for col in 0..Board::WIDTH {
-                if !board[row][col] && board.has_filled_above(row, col) {
-                    count += 1;
-                    break; // Only count each row once
-                }
-            }
-        }
+    fn description(&self) -> &'static str {
+        "Rows containing at least one hole"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        board.holes().rows_with_holes
+    }
 
-        count
+    fn eval_with(&self, _board: &Board, summary: &BoardSummary) -> u16 {
+        summary.holes.rows_with_holes
     }
 }
 
@@ -6,15 +6,27 @@ use crate::game::Board;
 pub struct HoleDepth;
 
 impl EvalFn for HoleDepth {
+    fn name(&self) -> &'static str {
+        "hole_depth"
+    }
+
+    fn label(&self) -> &'static str {
+        "Hole Depth"
+    }
+
+    fn description(&self) -> &'static str {
+        "Filled cells stacked above each hole"
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn eval(&self, board: &Board) -> u16 {
         let mut total: u16 = 0;
 
-        for col in 0..Board::WIDTH {
+        for col in 0..board.width() {
             let mut filled_above: u16 = 0;
 
             // Scan from top to bottom
-            for row in (0..Board::HEIGHT).rev() {
+            for row in (0..board.height()).rev() {
                 if board[row][col] {
                     filled_above += 1;
                 } else if filled_above > 0 {
@@ -0,0 +1,81 @@
+use crate::eval_fns::EvalFn;
+use crate::game::Board;
+
+/// The number of filled cells in the contiguous stack directly covering each hole.
+/// Unlike hole depth, counting stops at the first empty cell above the hole.
+pub struct CoveredCells;
+
+impl EvalFn for CoveredCells {
+    fn name(&self) -> &'static str {
+        "covered_cells"
+    }
+
+    fn label(&self) -> &'static str {
+        "Covered Cells"
+    }
+
+    fn description(&self) -> &'static str {
+        "Filled cells stacked directly on each hole"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        let mut total: u16 = 0;
+
+        for col in 0..board.width() {
+            for row in 0..board.height() - 1 {
+                if board[row][col] || !board[row + 1][col] {
+                    continue;
+                }
+                // Empty cell with a block immediately above: count the covering stack
+                let mut above = row + 1;
+                while above < board.height() && board[above][col] {
+                    total += 1;
+                    above += 1;
+                }
+            }
+        }
+
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &CoveredCells;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_single_covered_hole() {
+        let mut board = Board::new();
+        board[1][0] = true;
+        assert_eq!(EF.eval(&board), 1);
+    }
+
+    #[test]
+    fn test_stacked_cover() {
+        let mut board = Board::new();
+        // Hole at row 0 covered by rows 1-3, then a gap, then row 5
+        for row in 1..=3 {
+            board[row][0] = true;
+        }
+        board[5][0] = true;
+        // Hole at row 0 is covered by 3 cells, hole at row 4 by 1
+        assert_eq!(EF.eval(&board), 4);
+    }
+
+    #[test]
+    fn test_filled_column_has_no_cover() {
+        let mut board = Board::new();
+        for row in 0..4 {
+            board[row][0] = true;
+        }
+        assert_eq!(EF.eval(&board), 0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::eval_fns::{EvalFn, ef06_max_well_depth::calculate_well_depth};
+use crate::game::Board;
+
+/// The number of distinct wells: columns with an open cell bordered on both
+/// sides by filled cells or a wall (see `calculate_well_depth`).
+pub struct WellCount;
+
+impl EvalFn for WellCount {
+    fn name(&self) -> &'static str {
+        "well_count"
+    }
+
+    fn label(&self) -> &'static str {
+        "Well Count"
+    }
+
+    fn description(&self) -> &'static str {
+        "Number of columns that form a well"
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn eval(&self, board: &Board) -> u16 {
+        (0..board.width())
+            .filter(|&col| calculate_well_depth(board, col) > 0)
+            .count() as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_fns::test_util::board_with_open_columns;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &WellCount;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_single_central_well() {
+        let board = board_with_open_columns(4, &[4]);
+        assert_eq!(EF.eval(&board), 1);
+    }
+
+    #[test]
+    fn test_two_wells_one_column_apart() {
+        let board = board_with_open_columns(4, &[3, 5]);
+        assert_eq!(EF.eval(&board), 2);
+    }
+
+    #[test]
+    fn test_adjacent_open_columns_are_not_wells() {
+        // A two-wide trench has no cell bordered on both sides
+        let board = board_with_open_columns(4, &[4, 5]);
+        assert_eq!(EF.eval(&board), 0);
+    }
+
+    #[test]
+    fn test_edge_well() {
+        let board = board_with_open_columns(3, &[0]);
+        assert_eq!(EF.eval(&board), 1);
+    }
+}
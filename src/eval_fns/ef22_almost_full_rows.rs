@@ -0,0 +1,56 @@
+use crate::eval_fns::EvalFn;
+use crate::game::Board;
+
+/// The number of rows with exactly one empty cell.
+/// Unlike `PotentialRows`, these rows count whether or not a hole lies below.
+pub struct AlmostFullRows;
+
+impl EvalFn for AlmostFullRows {
+    fn name(&self) -> &'static str {
+        "almost_full_rows"
+    }
+
+    fn label(&self) -> &'static str {
+        "Almost Full Rows"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rows one cell away from being full"
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn eval(&self, board: &Board) -> u16 {
+        board
+            .rows_bottom_up()
+            .filter(|(_, row)| row.iter().filter(|&&c| c).count() == board.width() - 1)
+            .count() as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &AlmostFullRows;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_one_almost_full_row() {
+        let mut board = Board::new();
+        board[0].fill(true);
+        board[0][3] = false;
+        assert_eq!(EF.eval(&board), 1);
+    }
+
+    #[test]
+    fn test_full_row_not_counted() {
+        let mut board = Board::new();
+        board[0].fill(true);
+        assert_eq!(EF.eval(&board), 0);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::eval_fns::{EvalFn, ef06_max_well_depth::calculate_well_depth};
+use crate::game::Board;
+
+/// The column index (0-9) of the deepest well (see `calculate_well_depth`).
+///
+/// Ties go to the rightmost column. A board without wells scores the board
+/// width, one past the last column, so it never reads as a column 0 well.
+///
+/// A positive weight rewards keeping the well on the right, a negative one on the left.
+pub struct DeepestWellColumn;
+
+impl EvalFn for DeepestWellColumn {
+    fn name(&self) -> &'static str {
+        "deepest_well_column"
+    }
+
+    fn label(&self) -> &'static str {
+        "Deepest Well Col"
+    }
+
+    fn description(&self) -> &'static str {
+        "Column index of the deepest well"
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn eval(&self, board: &Board) -> u16 {
+        (0..board.width())
+            .map(|col| (calculate_well_depth(board, col), col))
+            .filter(|&(depth, _)| depth > 0)
+            .max()
+            .map_or_else(|| board.width(), |(_, col)| col) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_fns::test_util::board_with_open_columns;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &DeepestWellColumn;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 10);
+    }
+
+    #[test]
+    fn test_left_edge_well_is_not_the_empty_score() {
+        let board = board_with_open_columns(4, &[0]);
+        assert_eq!(EF.eval(&board), 0);
+        assert_ne!(EF.eval(&board), EF.eval(&Board::new()));
+    }
+
+    #[test]
+    fn test_central_well() {
+        let board = board_with_open_columns(4, &[4]);
+        assert_eq!(EF.eval(&board), 4);
+    }
+
+    #[test]
+    fn test_edge_well() {
+        let board = board_with_open_columns(4, &[Board::WIDTH - 1]);
+        assert_eq!(EF.eval(&board), 9);
+    }
+
+    #[test]
+    fn test_deeper_well_wins() {
+        let mut board = board_with_open_columns(4, &[2]);
+        // A shallower well in column 7
+        for row in 0..4 {
+            board[row][7] = row < 2;
+        }
+        assert_eq!(EF.eval(&board), 2);
+    }
+
+    #[test]
+    fn test_ties_prefer_rightmost() {
+        let mut board = board_with_open_columns(3, &[2]);
+        for row in 0..3 {
+            board[row][7] = false;
+        }
+        assert_eq!(EF.eval(&board), 7);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::eval_fns::EvalFn;
+use crate::game::Board;
+
+/// Percentage (0-100) of the cells below the stack height that are occupied.
+/// An empty board scores 0.
+///
+/// Unlike `Blocks`, which counts cells, this measures how solid the pile is.
+pub struct PackingDensity;
+
+impl EvalFn for PackingDensity {
+    fn name(&self) -> &'static str {
+        "packing_density"
+    }
+
+    fn label(&self) -> &'static str {
+        "Packing Density"
+    }
+
+    fn description(&self) -> &'static str {
+        "Percentage of filled cells below the stack height"
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn eval(&self, board: &Board) -> u16 {
+        let area = board.stack_height() * board.width();
+        if area == 0 {
+            return 0;
+        }
+        (board.cell_count() as usize * 100 / area) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &PackingDensity;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_packed_rectangle() {
+        let mut board = Board::new();
+        for row in 0..3 {
+            board[row].fill(true);
+        }
+        assert_eq!(EF.eval(&board), 100);
+    }
+
+    #[test]
+    fn test_sparse_stack() {
+        let mut board = Board::new();
+        // One column of height 4: 4 cells out of 40
+        for row in 0..4 {
+            board[row][0] = true;
+        }
+        assert_eq!(EF.eval(&board), 10);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::eval_fns::{BoardSummary, EvalFn};
+use crate::game::Board;
+
+/// The average column height, rounded to the nearest row.
+///
+/// Complements `PileHeight` (the maximum) and `AltitudeDiff` (the range) by
+/// measuring the overall elevation of the pile regardless of its shape.
+pub struct MeanHeight;
+
+impl EvalFn for MeanHeight {
+    fn name(&self) -> &'static str {
+        "mean_height"
+    }
+
+    fn label(&self) -> &'static str {
+        "Mean Height"
+    }
+
+    fn description(&self) -> &'static str {
+        "Average column height, rounded"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        mean_height(&board.column_heights()[..board.width()])
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        mean_height(&summary.heights[..board.width()])
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn mean_height(heights: &[usize]) -> u16 {
+    let total: usize = heights.iter().sum();
+    ((total + heights.len() / 2) / heights.len()) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &MeanHeight;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_flat_board() {
+        let mut board = Board::new();
+        for row in 0..3 {
+            board[row].fill(true);
+        }
+        assert_eq!(EF.eval(&board), 3);
+    }
+
+    #[test]
+    fn test_jagged_board() {
+        let mut board = Board::new();
+        // Heights 0, 1, ..., 9 sum to 45: a mean of 4.5 rounds up to 5
+        for col in 0..Board::WIDTH {
+            for row in 0..col {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 5);
+
+        // A single column of height 4 averages 0.4, which rounds down
+        let mut board = Board::new();
+        for row in 0..4 {
+            board[row][0] = true;
+        }
+        assert_eq!(EF.eval(&board), 0);
+    }
+}
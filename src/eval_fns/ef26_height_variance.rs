@@ -0,0 +1,86 @@
+use crate::eval_fns::{BoardSummary, EvalFn};
+use crate::game::Board;
+
+/// The population variance of the column heights, rounded.
+///
+/// `Smoothness` only sees differences between neighbours, so a gentle slope
+/// across the whole board scores as smooth. The variance compares every
+/// column with the mean height and grows with any large-scale unevenness.
+pub struct HeightVariance;
+
+impl EvalFn for HeightVariance {
+    fn name(&self) -> &'static str {
+        "height_variance"
+    }
+
+    fn label(&self) -> &'static str {
+        "Height Variance"
+    }
+
+    fn description(&self) -> &'static str {
+        "Variance of the column heights, rounded"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        height_variance(&board.surface_profile()[..board.width()])
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        height_variance(&summary.heights[..board.width()])
+    }
+}
+
+/// Computed as `(n Σh² - (Σh)²) / n²` in integers, rounded to nearest.
+#[allow(clippy::cast_possible_truncation)]
+fn height_variance(heights: &[usize]) -> u16 {
+    let n = heights.len();
+    let sum: usize = heights.iter().sum();
+    let sum_sq: usize = heights.iter().map(|h| h * h).sum();
+    let square_of_sum = sum.pow(2);
+    let denom = n * n;
+    ((n * sum_sq - square_of_sum + denom / 2) / denom) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &HeightVariance;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_flat_board() {
+        let mut board = Board::new();
+        for row in 0..5 {
+            board[row].fill(true);
+        }
+        assert_eq!(EF.eval(&board), 0);
+    }
+
+    #[test]
+    fn test_single_tall_column() {
+        // Heights 20, 0, ..., 0: mean 2, mean square 40, variance 36
+        let mut board = Board::new();
+        for row in 0..Board::HEIGHT {
+            board[row][0] = true;
+        }
+        assert_eq!(EF.eval(&board), 36);
+    }
+
+    #[test]
+    fn test_slope_is_uneven_but_smooth() {
+        // Heights 0..=9 differ by one between neighbours, variance 8.25
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..col {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 8);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::eval_fns::EvalFn;
+use crate::game::Board;
+
+/// The number of filled cells with an empty cell directly below them.
+///
+/// `Holes` counts the covered empty cells; this counts the cells covering
+/// them, so a wide overhang over a single-row gap scores once per covering
+/// cell rather than once per hole.
+pub struct Overhangs;
+
+impl EvalFn for Overhangs {
+    fn name(&self) -> &'static str {
+        "overhangs"
+    }
+
+    fn label(&self) -> &'static str {
+        "Overhangs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Filled cells with an empty cell directly below"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        board.overhang_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &Overhangs;
+
+    #[test]
+    fn test_flush_stack() {
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..=(col % 3) {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 0);
+    }
+
+    #[test]
+    fn test_simple_overhang() {
+        let mut board = Board::new();
+        // A roof over columns 0-2 at row 1, resting on column 3
+        board[0][3] = true;
+        for col in 0..4 {
+            board[1][col] = true;
+        }
+        assert_eq!(EF.eval(&board), 3);
+        assert_eq!(board.holes().total, 3);
+    }
+
+    #[test]
+    fn test_covering_cells_differ_from_holes() {
+        let mut board = Board::new();
+        // One cell floating over a three-row gap: 1 overhang, 3 holes
+        board[3][5] = true;
+        assert_eq!(EF.eval(&board), 1);
+        assert_eq!(board.holes().total, 3);
+    }
+}
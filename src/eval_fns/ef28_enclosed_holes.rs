@@ -0,0 +1,74 @@
+use crate::eval_fns::EvalFn;
+use crate::game::Board;
+
+/// The number of empty cells sealed off from the top of the board.
+///
+/// `Holes` counts every empty cell with a filled cell above it, including
+/// cells under an overhang that a piece could still slide into from an open
+/// well. This only counts cells a flood fill from the top row cannot reach.
+pub struct EnclosedHoles;
+
+impl EvalFn for EnclosedHoles {
+    fn name(&self) -> &'static str {
+        "enclosed_holes"
+    }
+
+    fn label(&self) -> &'static str {
+        "Enclosed Holes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Empty cells not reachable from the top through empty cells"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        board.enclosed_empties()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &EnclosedHoles;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_open_well_under_overhang() {
+        let mut board = Board::new();
+        // A roof over columns 0-3 at row 2, resting on column 0; the cells
+        // below it open into the empty column 4
+        board[0][0] = true;
+        board[1][0] = true;
+        for col in 0..4 {
+            board[2][col] = true;
+        }
+        assert_eq!(EF.eval(&board), 0);
+        assert_eq!(board.holes().total, 6);
+    }
+
+    #[test]
+    fn test_sealed_cells() {
+        let mut board = Board::new();
+        // Row 0 full except columns 2 and 7, both covered by a full row 1
+        board[0].fill(true);
+        board[0][2] = false;
+        board[0][7] = false;
+        board[1].fill(true);
+        board[1][9] = false;
+        assert_eq!(EF.eval(&board), 2);
+    }
+
+    #[test]
+    fn test_narrow_board() {
+        let mut board = Board::with_dimensions(4, 4);
+        board[3][..4].fill(true);
+        // The top row is full, so everything below it is sealed
+        assert_eq!(EF.eval(&board), 12);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::eval_fns::{BoardSummary, EvalFn};
+use crate::game::Board;
+
+/// The number of rows from the bottom before the first row containing a hole.
+/// A hole is an empty cell with at least one filled cell above it.
+///
+/// Returns the stack height when there are no holes. Unlike `HighestHole`,
+/// this measures the clean foundation from the bottom up.
+pub struct SolidHeight;
+
+impl EvalFn for SolidHeight {
+    fn name(&self) -> &'static str {
+        "solid_height"
+    }
+
+    fn label(&self) -> &'static str {
+        "Solid Height"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rows from the bottom before the lowest hole"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        solid_height(board, &board.column_heights())
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        solid_height(board, &summary.heights)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn solid_height(board: &Board, heights: &[usize]) -> u16 {
+    let stack = heights.iter().copied().max().unwrap_or(0);
+    let lowest_hole = heights
+        .iter()
+        .enumerate()
+        .filter_map(|(col, &height)| (0..height).find(|&row| !board[row][col]))
+        .min();
+    lowest_hole.unwrap_or(stack) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &SolidHeight;
+
+    #[test]
+    fn test_empty_board() {
+        assert_eq!(EF.eval(&Board::new()), 0);
+    }
+
+    #[test]
+    fn test_all_solid_stack() {
+        let mut board = Board::new();
+        // Uneven but hole-free columns, the tallest four high
+        for col in 0..Board::WIDTH {
+            for row in 0..=(col % 4) {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 4);
+    }
+
+    #[test]
+    fn test_hole_near_the_bottom() {
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..5 {
+                board[row][col] = true;
+            }
+        }
+        // A hole in row 1 of column 6, and a higher one in column 2
+        board[1][6] = false;
+        board[3][2] = false;
+        assert_eq!(EF.eval(&board), 1);
+        assert_eq!(EF.eval_with(&board, &BoardSummary::new(&board)), 1);
+    }
+}
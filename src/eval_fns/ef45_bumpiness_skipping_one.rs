@@ -0,0 +1,115 @@
+use crate::eval_fns::{BoardSummary, EvalFn};
+use crate::game::Board;
+
+/// The bumpiness (sum of adjacent column height differences) of the surface
+/// with one column left out, choosing the column that leaves it flattest.
+///
+/// The skipped column may sit anywhere, so a single well is ignored whether it
+/// is on an edge or in the middle. Unlike global smoothness, a flat stack with
+/// one well scores 0.
+pub struct BumpinessSkippingOne;
+
+impl EvalFn for BumpinessSkippingOne {
+    fn name(&self) -> &'static str {
+        "bumpiness_skipping_one"
+    }
+
+    fn label(&self) -> &'static str {
+        "Skip-One Bumpiness"
+    }
+
+    fn description(&self) -> &'static str {
+        "Bumpiness of the surface with its most uneven column left out"
+    }
+
+    fn eval(&self, board: &Board) -> u16 {
+        flattest_skipping_one(&board.column_heights()[..board.width()])
+    }
+
+    fn eval_with(&self, board: &Board, summary: &BoardSummary) -> u16 {
+        flattest_skipping_one(&summary.heights[..board.width()])
+    }
+}
+
+/// Leaving out a column removes the differences to its neighbours and, for an
+/// interior column, adds back the one that bridges the gap.
+#[allow(clippy::cast_possible_truncation)]
+fn flattest_skipping_one(heights: &[usize]) -> u16 {
+    if heights.len() < 2 {
+        return 0;
+    }
+    let last = heights.len() - 1;
+    let diff = |left: usize, right: usize| heights[left].abs_diff(heights[right]);
+    let total: usize = (0..last).map(|col| diff(col, col + 1)).sum();
+    let skipping = |col: usize| match col {
+        0 => total - diff(0, 1),
+        col if col == last => total - diff(col - 1, col),
+        col => total - diff(col - 1, col) - diff(col, col + 1) + diff(col - 1, col + 1),
+    };
+    (0..=last).map(skipping).min().unwrap_or(0) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_fns::ef16_smoothness::Smoothness;
+    use crate::game::Board;
+
+    const EF: &dyn EvalFn = &BumpinessSkippingOne;
+
+    #[test]
+    fn test_empty_board() {
+        let board = Board::new();
+        assert_eq!(EF.eval(&board), 0);
+    }
+
+    #[test]
+    fn test_flat_with_edge_well() {
+        let mut board = Board::new();
+        // Heights 4 everywhere except an empty well in the last column
+        for row in 0..4 {
+            for col in 0..Board::WIDTH - 1 {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 0);
+        assert!(Smoothness.eval(&board) > 0);
+    }
+
+    #[test]
+    fn test_flat_with_interior_well() {
+        let mut board = Board::new();
+        // Heights 4 everywhere except an empty well in column 4
+        for row in 0..4 {
+            for col in (0..Board::WIDTH).filter(|&col| col != 4) {
+                board[row][col] = true;
+            }
+        }
+        assert_eq!(EF.eval(&board), 0);
+        assert_eq!(Smoothness.eval(&board), 8);
+    }
+
+    #[test]
+    fn test_bumpy_surface() {
+        let mut board = Board::new();
+        // Heights alternate 0, 2, 0, 2, ...
+        for col in (1..Board::WIDTH).step_by(2) {
+            board[0][col] = true;
+            board[1][col] = true;
+        }
+        // Any 9 remaining columns have 8 adjacent differences of 2, except that
+        // dropping an interior column joins two equal neighbours: 7 of 2
+        assert_eq!(EF.eval(&board), 14);
+    }
+
+    #[test]
+    fn test_picks_flattest_skipping_one() {
+        let mut board = Board::new();
+        // Column 0 has height 3, the rest are empty
+        for row in 0..3 {
+            board[row][0] = true;
+        }
+        // Leaving out the tall column leaves a flat surface
+        assert_eq!(EF.eval(&board), 0);
+    }
+}
@@ -1,10 +1,126 @@
 use crate::game::Board;
 
+/// Hole measurements of a board, from [`Board::holes`].
+///
+/// A hole is an empty cell with at least one filled cell above it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HoleSummary {
+    /// Number of holes.
+    pub total: u16,
+    /// Number of vertical runs of holes, counting each run once.
+    pub connected_groups: u16,
+    /// Number of rows containing at least one hole.
+    pub rows_with_holes: u16,
+    /// Row of the highest hole, if any.
+    pub highest_row: Option<usize>,
+}
+
 impl Board {
+    /// Measures every hole on the board in one pass over each column.
+    #[must_use]
+    pub fn holes(&self) -> HoleSummary {
+        self.holes_below(&self.column_heights())
+    }
+
+    /// Like [`Board::holes`], reusing already computed column heights.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn holes_below(&self, heights: &[usize]) -> HoleSummary {
+        let mut summary = HoleSummary::default();
+        // Rows fit in the mask: boards are at most `MAX_DIMENSION` (127) tall
+        let mut hole_rows: u128 = 0;
+        for (col, &height) in heights.iter().enumerate() {
+            let mut in_hole = false;
+            for row in (0..height).rev() {
+                if self[row][col] {
+                    in_hole = false;
+                    continue;
+                }
+                summary.total += 1;
+                if !in_hole {
+                    summary.connected_groups += 1;
+                    in_hole = true;
+                }
+                hole_rows |= 1 << row;
+                summary.highest_row = summary.highest_row.max(Some(row));
+            }
+        }
+        summary.rows_with_holes = hole_rows.count_ones() as u16;
+        summary
+    }
+
+    /// Iterates the `(col, row)` of every hole, column by column from the
+    /// left and bottom-up within each column.
+    pub fn iter_holes(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let heights = self.column_heights();
+        (0..self.width()).flat_map(move |col| {
+            (0..heights[col])
+                .filter(move |&row| !self[row][col])
+                .map(move |row| (col, row))
+        })
+    }
+
+    /// Counts filled cells with an empty cell directly below them. The floor
+    /// counts as filled, so the bottom row never overhangs.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn overhang_count(&self) -> u16 {
+        (1..self.height())
+            .map(|row| {
+                self.row(row)
+                    .iter()
+                    .zip(self.row(row - 1))
+                    .filter(|&(&above, &below)| above && !below)
+                    .count()
+            })
+            .sum::<usize>() as u16
+    }
+
+    /// Counts empty cells that cannot be reached from the top row by moving
+    /// left, right, up or down through empty cells.
+    ///
+    /// Unlike [`Board::holes`], an empty cell under an overhang is only
+    /// enclosed if no gap leads to it from above.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn enclosed_empties(&self) -> u16 {
+        let (width, height) = (self.width(), self.height());
+        let mut reached = vec![vec![false; width]; height];
+        let mut stack: Vec<(usize, usize)> = (0..width)
+            .filter(|&col| !self[height - 1][col])
+            .map(|col| (col, height - 1))
+            .collect();
+        while let Some((col, row)) = stack.pop() {
+            if reached[row][col] {
+                continue;
+            }
+            reached[row][col] = true;
+            let neighbours = [
+                col.checked_sub(1).map(|c| (c, row)),
+                (col + 1 < width).then_some((col + 1, row)),
+                row.checked_sub(1).map(|r| (col, r)),
+                (row + 1 < height).then_some((col, row + 1)),
+            ];
+            stack.extend(
+                neighbours
+                    .into_iter()
+                    .flatten()
+                    .filter(|&(c, r)| !self[r][c] && !reached[r][c]),
+            );
+        }
+
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .filter(|&col| !self[row][col] && !reached[row][col])
+                    .count()
+            })
+            .sum::<usize>() as u16
+    }
+
     /// Checks if there is at least one filled cell above the given position.
     #[must_use]
     pub fn has_filled_above(&self, row: usize, col: usize) -> bool {
-        for r in (row + 1)..Self::HEIGHT {
+        for r in (row + 1)..self.height() {
             if self[r][col] {
                 return true;
             }
@@ -16,13 +132,78 @@ impl Board {
     /// A hole is an empty cell with at least one filled cell above it.
     #[must_use]
     pub fn highest_hole_row(&self) -> Option<usize> {
-        for row in (0..Self::HEIGHT - 1).rev() {
-            for col in 0..Self::WIDTH {
-                if !self[row][col] && self.has_filled_above(row, col) {
-                    return Some(row);
-                }
-            }
+        self.holes().highest_row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_with(cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new();
+        for &(row, col) in cells {
+            board[row][col] = true;
         }
-        None
+        board
+    }
+
+    #[test]
+    fn empty_board_has_no_holes() {
+        assert_eq!(Board::new().holes(), HoleSummary::default());
+    }
+
+    #[test]
+    fn separated_groups_in_one_column() {
+        // Rows 0-1 and 3-4 of column 0 are covered
+        let holes = board_with(&[(2, 0), (5, 0)]).holes();
+        assert_eq!(
+            holes,
+            HoleSummary {
+                total: 4,
+                connected_groups: 2,
+                rows_with_holes: 4,
+                highest_row: Some(4),
+            }
+        );
+    }
+
+    #[test]
+    fn holes_sharing_a_row_count_it_once() {
+        let holes = board_with(&[(1, 0), (1, 5)]).holes();
+        assert_eq!(
+            holes,
+            HoleSummary {
+                total: 2,
+                connected_groups: 2,
+                rows_with_holes: 1,
+                highest_row: Some(0),
+            }
+        );
+    }
+
+    #[test]
+    fn iter_holes_lists_each_hole_in_order() {
+        // Column 1 has an overhang at row 3 over a filled row 1
+        let board = board_with(&[(0, 0), (3, 1), (1, 1), (2, 4)]);
+        let holes: Vec<_> = board.iter_holes().collect();
+        assert_eq!(holes, [(1, 0), (1, 2), (4, 0), (4, 1)]);
+        assert_eq!(holes.len(), usize::from(board.holes().total));
+        assert_eq!(Board::new().iter_holes().count(), 0);
+    }
+
+    #[test]
+    fn scattered_holes_across_columns() {
+        // Column 0 covers rows 0-1, column 5 covers rows 0-3
+        let holes = board_with(&[(2, 0), (4, 5)]).holes();
+        assert_eq!(
+            holes,
+            HoleSummary {
+                total: 6,
+                connected_groups: 2,
+                rows_with_holes: 4,
+                highest_row: Some(3),
+            }
+        );
     }
 }
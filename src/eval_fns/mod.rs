@@ -1,6 +1,8 @@
 //! Board evaluation functions used by the agent.
 
 mod helpers;
+#[cfg(test)]
+mod test_util;
 
 pub mod ef01_pile_height;
 pub mod ef02_holes;
@@ -18,19 +20,89 @@ pub mod ef15_potential_rows;
 pub mod ef16_smoothness;
 pub mod ef18_row_holes;
 pub mod ef19_hole_depth;
+pub mod ef20_covered_cells;
+pub mod ef21_well_count;
+pub mod ef22_almost_full_rows;
+pub mod ef23_deepest_well_column;
+pub mod ef24_packing_density;
+pub mod ef25_mean_height;
+pub mod ef26_height_variance;
+pub mod ef27_overhangs;
+pub mod ef28_enclosed_holes;
+pub mod ef29_solid_height;
+pub mod ef45_bumpiness_skipping_one;
 
 // Removed: ef04_removed_rows, ef08_landing_height, ef17_eroded_pieces
-// (these require game context beyond the board state)
+// (these require game context beyond the board state, see `EvalFn::eval_ctx`)
 
-use crate::game::Board;
+use std::fmt::Write as _;
+
+use crate::game::{Board, FallingPiece};
 use crate::weights;
 
+pub use helpers::HoleSummary;
+
+/// Board measurements shared by several evaluators, computed once per board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardSummary {
+    /// Height of each column (see [`Board::column_heights`]).
+    pub heights: Vec<usize>,
+    /// Hole measurements (see [`Board::holes`]).
+    pub holes: HoleSummary,
+}
+
+impl BoardSummary {
+    /// Measures `board` in a single pass over each column.
+    #[must_use]
+    pub fn new(board: &Board) -> Self {
+        let heights = board.column_heights();
+        let holes = board.holes_below(&heights);
+        Self { heights, holes }
+    }
+}
+
+/// The boards around a single placement, for evaluators that need landing context.
+#[derive(Debug, Clone, Copy)]
+pub struct EvalContext<'a> {
+    /// Board with the piece placed, before full rows are cleared.
+    pub pre_clear: &'a Board,
+    /// Board after full rows are cleared.
+    pub post_clear: &'a Board,
+    /// Summary of `post_clear`.
+    pub summary: &'a BoardSummary,
+    /// The placed piece at its landing position.
+    pub piece: FallingPiece,
+    pub rows_cleared: u32,
+}
+
 pub trait EvalFn {
+    /// Stable `snake_case` identifier, the module name without its `efNN_`
+    /// prefix (e.g. `sum_of_wells`).
+    fn name(&self) -> &'static str;
+
+    /// Short display label for tables and the TUI (e.g. `Sum of Wells`).
+    fn label(&self) -> &'static str;
+
+    /// One-line description of what the evaluator measures.
+    fn description(&self) -> &'static str;
+
     /// Evaluates the board and returns a score (0-255).
     fn eval(&self, board: &Board) -> u16;
+
+    /// Evaluates the board, reusing measurements from its `summary`.
+    /// Must agree with [`EvalFn::eval`]; defaults to calling it.
+    fn eval_with(&self, board: &Board, _summary: &BoardSummary) -> u16 {
+        self.eval(board)
+    }
+
+    /// Evaluates a placement with its landing context.
+    /// Defaults to [`EvalFn::eval_with`] on the post-clear board.
+    fn eval_ctx(&self, ctx: &EvalContext) -> u16 {
+        self.eval_with(ctx.post_clear, ctx.summary)
+    }
 }
 
-/// Returns a list of all 16 evaluators in the correct order.
+/// Returns a list of all evaluators in the correct order.
 /// We use Box<dyn EvalFn> to store different types in one list.
 #[must_use]
 pub fn get_all_evaluators() -> Vec<Box<dyn EvalFn>> {
@@ -39,8 +111,8 @@ pub fn get_all_evaluators() -> Vec<Box<dyn EvalFn>> {
         Box::new(ef02_holes::Holes),
         Box::new(ef03_connected_holes::ConnectedHoles),
         Box::new(ef05_altitude_diff::AltitudeDiff),
-        Box::new(ef06_max_well_depth::MaxWellDepth),
-        Box::new(ef07_sum_of_wells::SumOfWells),
+        Box::new(ef06_max_well_depth::MaxWellDepth::default()),
+        Box::new(ef07_sum_of_wells::SumOfWells::default()),
         Box::new(ef09_blocks::Blocks),
         Box::new(ef10_weighted_blocks::WeightedBlocks),
         Box::new(ef11_row_transitions::RowTransitions),
@@ -51,9 +123,67 @@ pub fn get_all_evaluators() -> Vec<Box<dyn EvalFn>> {
         Box::new(ef16_smoothness::Smoothness),
         Box::new(ef18_row_holes::RowHoles),
         Box::new(ef19_hole_depth::HoleDepth),
+        Box::new(ef45_bumpiness_skipping_one::BumpinessSkippingOne),
+        Box::new(ef20_covered_cells::CoveredCells),
+        Box::new(ef21_well_count::WellCount),
+        Box::new(ef22_almost_full_rows::AlmostFullRows),
+        Box::new(ef23_deepest_well_column::DeepestWellColumn),
+        Box::new(ef24_packing_density::PackingDensity),
+        Box::new(ef25_mean_height::MeanHeight),
+        Box::new(ef26_height_variance::HeightVariance),
+        Box::new(ef27_overhangs::Overhangs),
+        Box::new(ef28_enclosed_holes::EnclosedHoles),
+        Box::new(ef29_solid_height::SolidHeight),
     ]
 }
 
+/// `snake_case` names of the evaluators, in weight order.
+#[must_use]
+pub fn eval_names() -> [&'static str; weights::NUM_WEIGHTS] {
+    let evaluators = get_all_evaluators();
+    std::array::from_fn(|i| evaluators[i].name())
+}
+
+/// Display labels of the evaluators, in weight order.
+#[must_use]
+pub fn eval_labels() -> [&'static str; weights::NUM_WEIGHTS] {
+    let evaluators = get_all_evaluators();
+    std::array::from_fn(|i| evaluators[i].label())
+}
+
+/// Formats one line per evaluator: index, name and description.
+#[must_use]
+pub fn list_evaluators() -> String {
+    let mut out = String::new();
+    for (i, evaluator) in get_all_evaluators().iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{i:>2}  {:<28} {}",
+            evaluator.name(),
+            evaluator.description()
+        );
+    }
+    out
+}
+
+/// Evaluates every heuristic on the board, in weight order.
+#[must_use]
+pub fn feature_vector(board: &Board) -> [u16; weights::NUM_WEIGHTS] {
+    let evaluators = get_all_evaluators();
+    let summary = BoardSummary::new(board);
+    std::array::from_fn(|i| evaluators[i].eval_with(board, &summary))
+}
+
+/// Evaluates the heuristic whose [`EvalFn::name`] is `name`, e.g.
+/// `"sum_of_wells"`. Returns `None` for an unknown name.
+#[must_use]
+pub fn eval_by_name(name: &str, board: &Board) -> Option<u16> {
+    get_all_evaluators()
+        .into_iter()
+        .find(|evaluator| evaluator.name() == name)
+        .map(|evaluator| evaluator.eval(board))
+}
+
 /// Calculates the weighted sum of the first `n_weights` heuristics.
 #[must_use]
 pub fn calculate_weighted_score_n(
@@ -61,16 +191,202 @@ pub fn calculate_weighted_score_n(
     weights: &[f64; weights::NUM_WEIGHTS],
     n_weights: usize,
 ) -> f64 {
+    let summary = BoardSummary::new(board);
     get_all_evaluators()
         .iter()
         .zip(weights.iter())
         .take(n_weights)
-        .map(|(evaluator, &weight)| f64::from(evaluator.eval(board)) * weight)
+        .map(|(evaluator, &weight)| f64::from(evaluator.eval_with(board, &summary)) * weight)
         .sum()
 }
 
-/// Calculates the weighted sum of all 16 heuristics.
+/// Calculates the weighted sum of the first `n_weights` heuristics for a placement,
+/// letting each evaluator choose between the pre-clear and post-clear board.
+#[must_use]
+pub fn calculate_weighted_score_ctx(
+    ctx: &EvalContext,
+    weights: &[f64; weights::NUM_WEIGHTS],
+    n_weights: usize,
+) -> f64 {
+    get_all_evaluators()
+        .iter()
+        .zip(weights.iter())
+        .take(n_weights)
+        .map(|(evaluator, &weight)| f64::from(evaluator.eval_ctx(ctx)) * weight)
+        .sum()
+}
+
+/// Calculates the weighted sum of all heuristics.
 #[must_use]
 pub fn calculate_weighted_score(board: &Board, weights: &[f64; weights::NUM_WEIGHTS]) -> f64 {
     calculate_weighted_score_n(board, weights, weights::NUM_WEIGHTS)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval_by_name_matches_the_evaluator() {
+        let mut board = Board::new();
+        board[0][2] = true;
+        board[3][2] = true;
+        board[1][5] = true;
+
+        let holes = ef02_holes::Holes.eval(&board);
+        assert_eq!(holes, 3);
+        assert_eq!(eval_by_name("holes", &board), Some(holes));
+        assert_eq!(eval_by_name("Holes", &board), None);
+        assert_eq!(eval_by_name("not a heuristic", &board), None);
+    }
+
+    #[test]
+    fn eval_by_name_accepts_multi_word_names() {
+        let mut board = Board::new();
+        for (col, row) in board.positions() {
+            board[row][col] = row < 3 && col != 4;
+        }
+
+        let sum_of_wells = ef07_sum_of_wells::SumOfWells::default().eval(&board);
+        assert_eq!(sum_of_wells, 3);
+        assert_eq!(eval_by_name("sum_of_wells", &board), Some(sum_of_wells));
+        assert_eq!(
+            eval_by_name("deepest_well_column", &board),
+            Some(ef23_deepest_well_column::DeepestWellColumn.eval(&board))
+        );
+        assert_eq!(eval_by_name("Sum of Wells", &board), None);
+    }
+
+    #[test]
+    fn evaluator_count_matches_weights() {
+        assert_eq!(get_all_evaluators().len(), weights::NUM_WEIGHTS);
+    }
+
+    #[test]
+    fn evaluator_names_are_unique() {
+        let mut names: Vec<&str> = get_all_evaluators().iter().map(|e| e.name()).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), weights::NUM_WEIGHTS);
+    }
+
+    #[test]
+    fn evaluator_names_are_snake_case() {
+        for evaluator in get_all_evaluators() {
+            let name = evaluator.name();
+            assert!(
+                !name.is_empty()
+                    && !name.starts_with('_')
+                    && name.chars().all(|c| c.is_ascii_lowercase() || c == '_'),
+                "{name} is not snake_case"
+            );
+            assert!(!evaluator.label().is_empty());
+            assert!(!evaluator.description().is_empty());
+        }
+    }
+
+    /// Counts cells on the pre-clear board.
+    struct PreClearBlocks;
+
+    impl EvalFn for PreClearBlocks {
+        fn name(&self) -> &'static str {
+            "pre_clear_blocks"
+        }
+
+        fn label(&self) -> &'static str {
+            "Pre-clear Blocks"
+        }
+
+        fn description(&self) -> &'static str {
+            "Filled cells before rows are cleared"
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        fn eval(&self, board: &Board) -> u16 {
+            board.cell_count() as u16
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        fn eval_ctx(&self, ctx: &EvalContext) -> u16 {
+            ctx.pre_clear.cell_count() as u16
+        }
+    }
+
+    #[test]
+    fn eval_ctx_sees_both_boards() {
+        let mut pre_clear = Board::new();
+        pre_clear[0].fill(true);
+        pre_clear[1][0] = true;
+        let mut post_clear = pre_clear.clone();
+        let rows_cleared = post_clear.clear_full_rows();
+        let ctx = EvalContext {
+            pre_clear: &pre_clear,
+            post_clear: &post_clear,
+            summary: &BoardSummary::new(&post_clear),
+            piece: FallingPiece::spawn(crate::game::Tetromino::I),
+            rows_cleared,
+        };
+
+        assert_eq!(PreClearBlocks.eval_ctx(&ctx), 11);
+        assert_eq!(ef09_blocks::Blocks.eval_ctx(&ctx), 1);
+    }
+
+    #[test]
+    fn summary_matches_standalone_evaluation() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+        let evaluators = get_all_evaluators();
+        for _ in 0..200 {
+            let mut board = Board::new();
+            let top = rng.random_range(0..Board::HEIGHT);
+            for (col, row) in board.positions() {
+                board[row][col] = row < top && rng.random_bool(0.6);
+            }
+            let summary = BoardSummary::new(&board);
+            assert_eq!(summary.heights, board.column_heights());
+            assert_eq!(summary.holes, board.holes());
+            for evaluator in &evaluators {
+                assert_eq!(
+                    evaluator.eval_with(&board, &summary),
+                    evaluator.eval(&board),
+                    "{}",
+                    evaluator.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn narrow_board_ignores_columns_outside_it() {
+        let board = Board::with_dimensions(6, 10);
+        let summary = BoardSummary::new(&board);
+        let evaluators = get_all_evaluators();
+        let value = |name: &str| {
+            let evaluator = evaluators.iter().find(|e| e.name() == name).expect(name);
+            assert_eq!(
+                evaluator.eval_with(&board, &summary),
+                evaluator.eval(&board)
+            );
+            evaluator.eval(&board)
+        };
+
+        // Both walls of each of the 10 rows, and the floor under each of the 6 columns
+        assert_eq!(value("row_transitions"), 20);
+        assert_eq!(value("col_transitions"), 6);
+        assert_eq!(value("altitude_diff"), 0);
+        assert_eq!(value("smoothness"), 0);
+        assert_eq!(value("sum_of_wells"), 0);
+    }
+
+    #[test]
+    fn feature_vector_matches_weighted_score() {
+        let mut board = Board::new();
+        board[0][0] = true;
+        board[2][0] = true;
+        let weights = [1.0; weights::NUM_WEIGHTS];
+
+        let sum: f64 = feature_vector(&board).iter().map(|&v| f64::from(v)).sum();
+        assert!((sum - calculate_weighted_score(&board, &weights)).abs() < f64::EPSILON);
+    }
+}
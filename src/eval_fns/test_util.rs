@@ -0,0 +1,14 @@
+//! Board fixtures shared by the evaluator tests.
+
+use crate::game::Board;
+
+/// Fills the bottom `height` rows of every column except those in `open`.
+pub fn board_with_open_columns(height: usize, open: &[usize]) -> Board {
+    let mut board = Board::new();
+    for row in 0..height {
+        for col in (0..Board::WIDTH).filter(|col| !open.contains(col)) {
+            board[row][col] = true;
+        }
+    }
+    board
+}
@@ -1,101 +1,332 @@
 use std::fmt::{self, Display, Write};
 use std::ops::{Index, IndexMut};
 
-use super::tetromino::FallingPiece;
+use rand::Rng;
 
-/// A 10x20 Tetris board.
+use super::tetromino::{FallingPiece, Rotation, Tetromino};
+
+/// A Tetris board, 10x20 by default.
 ///
 /// Coordinate system:
 /// - `board[0]` is the **bottom** row
-/// - `board[19]` is the **top** row
+/// - `board[height - 1]` is the **top** row
 /// - `board[row][0]` is the **left** column
-/// - `board[row][9]` is the **right** column
+/// - `board[row][width - 1]` is the **right** column
+///
+/// Supports indexing: `board[row][col]` or `board[row]` for a full row of
+/// [`Board::width`] cells.
 ///
-/// Supports indexing: `board[row][col]` or `board[row]` for a full row.
-#[derive(Debug, Clone, Copy)]
+/// Cells are stored row by row, bottom row first, so boards of any size
+/// ([`Board::with_dimensions`]) share the same layout.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Board {
-    cells: [[bool; 10]; 20],
+    cells: Vec<bool>,
+    width: usize,
+    height: usize,
 }
 
 impl Index<usize> for Board {
-    type Output = [bool; 10];
+    type Output = [bool];
 
     fn index(&self, row: usize) -> &Self::Output {
-        &self.cells[row]
+        assert!(row < self.height, "Row out of bounds: {row}");
+        &self.cells[row * self.width..][..self.width]
     }
 }
 
 impl IndexMut<usize> for Board {
     fn index_mut(&mut self, row: usize) -> &mut Self::Output {
-        &mut self.cells[row]
+        assert!(row < self.height, "Row out of bounds: {row}");
+        &mut self.cells[row * self.width..][..self.width]
     }
 }
 
 impl Board {
+    /// Width of a standard board.
     pub const WIDTH: usize = 10;
+    /// Height of a standard board.
     pub const HEIGHT: usize = 20;
+    /// Largest width or height: piece coordinates are `i8`.
+    pub const MAX_DIMENSION: usize = i8::MAX as usize;
+
+    /// Creates a new empty 10x20 board.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_dimensions(Self::WIDTH, Self::HEIGHT)
+    }
 
-    /// Creates a new empty board.
+    /// Creates an empty board of `width` columns and `height` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is 0 or larger than [`Board::MAX_DIMENSION`].
     #[must_use]
-    pub const fn new() -> Self {
+    pub fn with_dimensions(width: usize, height: usize) -> Self {
+        assert!(
+            (1..=Self::MAX_DIMENSION).contains(&width)
+                && (1..=Self::MAX_DIMENSION).contains(&height),
+            "Board dimensions out of range: {width}x{height}"
+        );
         Self {
-            cells: [[false; Self::WIDTH]; Self::HEIGHT],
+            cells: vec![false; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a board from its rows, bottom row first. The board is as wide
+    /// as the rows and as tall as their count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows differ in length, or if the resulting dimensions
+    /// are out of range for [`Board::with_dimensions`].
+    #[must_use]
+    pub fn from_cells<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+        let width = rows.first().map_or(0, |row| row.as_ref().len());
+        let mut board = Self::with_dimensions(width, rows.len());
+        for (row, cells) in rows.iter().enumerate() {
+            board[row].copy_from_slice(cells.as_ref());
         }
+        board
+    }
+
+    /// Number of columns.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells of `row`, left to right. Same as `&board[row]`.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[bool] {
+        &self[row]
     }
 
-    /// Creates a board from a cell array.
+    /// Returns the cells as a row-major vector, bottom row first, with 1.0 for
+    /// filled cells and 0.0 for empty ones. Index `row * width() + col`
+    /// matches `board[row][col]`.
     #[must_use]
-    pub const fn from_cells(cells: [[bool; 10]; 20]) -> Self {
-        Self { cells }
+    pub fn to_flat(&self) -> Vec<f32> {
+        self.cells
+            .iter()
+            .map(|&filled| if filled { 1.0 } else { 0.0 })
+            .collect()
+    }
+
+    /// Builds a `width`-column board from the layout of [`Board::to_flat`],
+    /// filling every cell whose value is at least `threshold`.
+    ///
+    /// Returns `None` if `values` does not hold a whole number of rows, or if
+    /// the dimensions are out of range.
+    #[must_use]
+    pub fn from_flat(values: &[f32], width: usize, threshold: f32) -> Option<Self> {
+        if values.is_empty() || !values.len().is_multiple_of(width) {
+            return None;
+        }
+        let height = values.len() / width;
+        if width > Self::MAX_DIMENSION || height > Self::MAX_DIMENSION {
+            return None;
+        }
+        Some(Self {
+            cells: values.iter().map(|&value| value >= threshold).collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Packs the occupied rows into one `u16` each, bottom row first, with bit
+    /// `col` set for a filled cell. Empty rows above the stack are dropped, so
+    /// the result has [`Board::occupied_height`] entries.
+    ///
+    /// The rows do not record the board size; pass [`Board::width`] and
+    /// [`Board::height`] to [`Board::from_trimmed_rows`] to restore it.
+    /// Returns `None` for boards wider than 16 columns.
+    #[must_use]
+    pub fn to_trimmed_rows(&self) -> Option<Vec<u16>> {
+        if self.width > u16::BITS as usize {
+            return None;
+        }
+        let rows = (0..self.occupied_height())
+            .map(|row| {
+                self.row(row)
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &filled)| filled)
+                    .fold(0, |bits, (col, _)| bits | 1 << col)
+            })
+            .collect();
+        Some(rows)
+    }
+
+    /// Rebuilds a `width` x `height` board from [`Board::to_trimmed_rows`],
+    /// leaving the rows above `rows` empty.
+    ///
+    /// Returns `None` if the dimensions are out of range, or for more than
+    /// `height` rows or bits beyond `width`.
+    #[must_use]
+    pub fn from_trimmed_rows(rows: &[u16], width: usize, height: usize) -> Option<Self> {
+        let in_range = |len: usize| (1..=Self::MAX_DIMENSION).contains(&len);
+        if !in_range(width)
+            || !in_range(height)
+            || width > u16::BITS as usize
+            || rows.len() > height
+            || rows.iter().any(|&bits| u32::from(bits) >> width != 0)
+        {
+            return None;
+        }
+        let mut board = Self::with_dimensions(width, height);
+        for (row, &bits) in rows.iter().enumerate() {
+            for col in 0..width {
+                board[row][col] = bits & 1 << col != 0;
+            }
+        }
+        Some(board)
+    }
+
+    /// Returns the cells as a `height() x width()` array in the layout of
+    /// [`Board::to_flat`].
+    #[cfg(feature = "ndarray")]
+    #[must_use]
+    pub fn to_array2(&self) -> ndarray::Array2<f32> {
+        ndarray::Array2::from_shape_fn((self.height, self.width), |(row, col)| {
+            if self[row][col] { 1.0 } else { 0.0 }
+        })
     }
 
     /// Returns the height of a column (number of rows from bottom to highest block).
     /// Returns 0 if the column is empty.
     #[must_use]
     pub fn column_height(&self, col: usize) -> usize {
-        for row in (0..Self::HEIGHT).rev() {
-            if self.cells[row][col] {
+        for row in (0..self.height()).rev() {
+            if self[row][col] {
                 return row + 1;
             }
         }
         0
     }
 
+    /// Returns the height of every column, as [`Board::column_height`] would,
+    /// in a single top-down pass.
+    #[must_use]
+    pub fn column_heights(&self) -> Vec<usize> {
+        let mut heights = vec![0; self.width()];
+        let mut remaining = self.width();
+        for row in (0..self.height()).rev() {
+            for (col, height) in heights.iter_mut().enumerate() {
+                if *height == 0 && self[row][col] {
+                    *height = row + 1;
+                    remaining -= 1;
+                }
+            }
+            if remaining == 0 {
+                break;
+            }
+        }
+        heights
+    }
+
+    /// Formats [`Board::column_heights`], e.g. `[3,3,0,5]`.
+    #[must_use]
+    pub fn heightmap_string(&self) -> String {
+        let list: Vec<String> = self
+            .column_heights()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        format!("[{}]", list.join(","))
+    }
+
+    /// The surface of the stack as the height of each column. Same as
+    /// [`Board::column_heights`], named for evaluators that reason about shape.
+    #[must_use]
+    pub fn surface_profile(&self) -> Vec<usize> {
+        self.column_heights()
+    }
+
+    /// Renders the surface as one bar character per column, scaled to the
+    /// board height: `.` for an empty column, then `▁` up to `█` for a full one.
+    #[must_use]
+    pub fn surface_bars(&self) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        self.column_heights()
+            .iter()
+            .map(|&height| {
+                if height == 0 {
+                    '.'
+                } else {
+                    BARS[(height * BARS.len()).div_ceil(self.height()) - 1]
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the height of the stack: the highest occupied row + 1, or 0 for
+    /// an empty board. This is the tallest of [`Board::column_heights`].
+    #[must_use]
+    pub fn stack_height(&self) -> usize {
+        (0..self.height())
+            .rev()
+            .find(|&row| !self.is_row_empty(row))
+            .map_or(0, |row| row + 1)
+    }
+
+    /// The number of rows from the bottom up to the highest filled cell. Same
+    /// as [`Board::stack_height`], named for serializers that drop the empty
+    /// rows above it.
+    #[must_use]
+    pub fn occupied_height(&self) -> usize {
+        self.stack_height()
+    }
+
     /// Iterates rows from bottom to top.
-    pub fn rows_bottom_up(&self) -> impl Iterator<Item = (usize, &[bool; 10])> {
-        self.cells.iter().enumerate()
+    pub fn rows_bottom_up(&self) -> impl Iterator<Item = (usize, &[bool])> {
+        (0..self.height()).map(|row| (row, self.row(row)))
     }
 
     /// Iterates rows from top to bottom. (0 is the top row)
-    pub fn rows_top_down(&self) -> impl Iterator<Item = (usize, &[bool; 10])> {
-        self.cells.iter().rev().enumerate()
+    pub fn rows_top_down(&self) -> impl Iterator<Item = (usize, &[bool])> {
+        (0..self.height())
+            .rev()
+            .map(|row| self.row(row))
+            .enumerate()
     }
 
-    /// Returns an iterator over all cell positions (col, row).
-    pub fn all_positions() -> impl Iterator<Item = (usize, usize)> {
-        (0..Self::WIDTH).flat_map(|col| (0..Self::HEIGHT).map(move |row| (col, row)))
+    /// Returns an iterator over all cell positions (col, row) of this board.
+    ///
+    /// The iterator does not borrow the board, so cells can be written while
+    /// walking it.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        let height = self.height;
+        (0..self.width).flat_map(move |col| (0..height).map(move |row| (col, row)))
     }
 
     /// Returns an iterator with all cells flattened (occupied: true).
     pub fn all_cells(&self) -> impl Iterator<Item = &bool> {
-        self.cells.iter().flat_map(|row| row.iter())
+        self.cells.iter()
     }
 
     /// Checks if a cell position is within board bounds.
     #[must_use]
-    #[allow(clippy::cast_possible_truncation)]
-    pub const fn in_bounds(col: i8, row: i8) -> bool {
-        col >= 0 && col < Self::WIDTH as i8 && row >= 0 && row < Self::HEIGHT as i8
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn in_bounds(&self, col: i8, row: i8) -> bool {
+        col >= 0 && (col as usize) < self.width && row >= 0 && (row as usize) < self.height
     }
 
     /// Checks if a cell position is occupied (out of bounds counts as occupied).
     #[must_use]
     #[allow(clippy::cast_sign_loss)]
-    pub const fn is_occupied(&self, col: i8, row: i8) -> bool {
-        if !Self::in_bounds(col, row) {
+    pub fn is_occupied(&self, col: i8, row: i8) -> bool {
+        if !self.in_bounds(col, row) {
             return true;
         }
-        self.cells[row as usize][col as usize]
+        self[row as usize][col as usize]
     }
 
     /// Checks if a piece can be placed at its current position.
@@ -129,10 +360,10 @@ impl Board {
     pub fn place(&mut self, piece: &FallingPiece) {
         for (col, row) in piece.cells() {
             debug_assert!(
-                Self::in_bounds(col, row),
+                self.in_bounds(col, row),
                 "Piece cell out of bounds: ({col}, {row})",
             );
-            self.cells[row as usize][col as usize] = true;
+            self[row as usize][col as usize] = true;
         }
     }
 
@@ -140,93 +371,186 @@ impl Board {
     /// Panics if the piece cannot be placed.
     #[must_use]
     pub fn with_piece(&self, piece: &FallingPiece) -> Self {
-        let mut new_board = *self;
+        let mut new_board = self.clone();
         new_board.place(piece);
         new_board
     }
 
+    /// Returns a board with every cell filled on either board (OR).
+    /// The result keeps `self`'s dimensions.
+    #[must_use]
+    pub fn overlay(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns a board with the cells filled on exactly one of the boards (XOR).
+    /// The result keeps `self`'s dimensions.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Applies `op` cell by cell within `self`'s bounds. Cells outside
+    /// `other` count as empty.
+    fn combine(&self, other: &Self, op: impl Fn(bool, bool) -> bool) -> Self {
+        let mut combined = self.clone();
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                let theirs = row < other.height() && col < other.width() && other[row][col];
+                combined[row][col] = op(self[row][col], theirs);
+            }
+        }
+        combined
+    }
+
     /// Checks if a row is completely filled.
     #[must_use]
     pub fn is_row_full(&self, row: usize) -> bool {
-        self.cells[row].iter().all(|&c| c)
+        self.row(row).iter().all(|&c| c)
+    }
+
+    /// Checks if a row has no filled cells.
+    #[must_use]
+    pub fn is_row_empty(&self, row: usize) -> bool {
+        self.row(row).iter().all(|&c| !c)
     }
 
     /// Returns indices of all full rows (bottom to top order).
     #[must_use]
     pub fn full_rows(&self) -> Vec<usize> {
-        (0..Self::HEIGHT).filter(|&r| self.is_row_full(r)).collect()
+        (0..self.height())
+            .filter(|&r| self.is_row_full(r))
+            .collect()
     }
 
     /// Clears full rows and returns the number of rows cleared.
     /// Rows above cleared rows drop down.
     #[allow(clippy::cast_possible_truncation)]
     pub fn clear_full_rows(&mut self) -> u32 {
-        let full = self.full_rows();
-        let count = full.len() as u32;
+        self.clear_full_rows_indices().len() as u32
+    }
 
-        if count == 0 {
-            return 0;
-        }
+    /// Clears full rows and returns their indices (bottom to top order)
+    /// as they were before clearing. Rows above cleared rows drop down.
+    pub fn clear_full_rows_indices(&mut self) -> Vec<usize> {
+        let full = self.full_rows();
 
         // Clear rows from top to bottom to simplify shifting
         for &row in full.iter().rev() {
             self.remove_row(row);
         }
 
-        count
+        full
     }
 
     /// Removes a single row and shifts all rows above it down.
     fn remove_row(&mut self, row: usize) {
-        for r in row..Self::HEIGHT - 1 {
-            self.cells[r] = self.cells[r + 1];
-        }
+        let (width, top) = (self.width(), self.height() - 1);
+        let len = self.cells.len();
+        self.cells.copy_within((row + 1) * width..len, row * width);
         // Clear the top row
-        self.cells[Self::HEIGHT - 1] = [false; Self::WIDTH];
+        self[top].fill(false);
+    }
+
+    /// Pushes `count` garbage rows in from the bottom, shifting the stack up.
+    ///
+    /// Every garbage row is filled except for a single gap at `gap_col`, or at
+    /// a column chosen by `rng` when `gap_col` is `None`.
+    /// Returns `true` if any occupied cell was pushed off the top of the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gap_col` is outside the board.
+    pub fn add_garbage_rows<R: Rng + ?Sized>(
+        &mut self,
+        count: usize,
+        gap_col: Option<usize>,
+        rng: &mut R,
+    ) -> bool {
+        let (width, height) = (self.width(), self.height());
+        let count = count.min(height);
+        if count == 0 {
+            return false;
+        }
+
+        let gap = gap_col.unwrap_or_else(|| rng.random_range(0..width));
+        assert!(gap < width, "Garbage gap column out of bounds: {gap}");
+
+        let topped_out = self.stack_height() > height - count;
+
+        self.cells
+            .copy_within(0..(height - count) * width, count * width);
+        for row in 0..count {
+            self[row].fill(true);
+            self[row][gap] = false;
+        }
+
+        topped_out
     }
 
     /// Drops a piece down as far as possible (hard drop).
     /// Returns the piece at its final position, or None if it can't be placed at all.
     #[must_use]
     pub fn hard_drop(&self, piece: &FallingPiece) -> Option<FallingPiece> {
-        if !self.can_place(piece) {
-            return None;
-        }
+        self.can_place(piece).then(|| self.landing(*piece))
+    }
 
-        let mut dropped = *piece;
-        while self.can_place(&dropped.moved(0, -1)) {
-            dropped = dropped.moved(0, -1);
-        }
-        Some(dropped)
+    /// Hard-drops `tetromino` with `rotation` at column `col` from the top of the board.
+    ///
+    /// `col` is the column of the piece's bounding box, as in
+    /// [`FallingPiece::col`]. The piece starts with its highest cell in the
+    /// top row, so tall rotations fit too. Returns the resting piece, or
+    /// `None` if it does not fit there.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn landing_for(
+        &self,
+        tetromino: Tetromino,
+        rotation: Rotation,
+        col: i8,
+    ) -> Option<FallingPiece> {
+        let top = tetromino
+            .cells(rotation)
+            .iter()
+            .map(|&(_, dr)| dr)
+            .max()
+            .unwrap_or(0);
+        let spawned = FallingPiece {
+            tetromino,
+            rotation,
+            col,
+            row: self.height as i8 - 1 - top,
+        };
+        self.hard_drop(&spawned)
     }
 
     /// Returns the number of rows the piece would drop.
     #[must_use]
+    #[allow(clippy::cast_sign_loss)]
     pub fn drop_distance(&self, piece: &FallingPiece) -> u32 {
-        let mut distance = 0;
-        let mut test_piece = *piece;
-        while self.can_place(&test_piece.moved(0, -1)) {
-            test_piece = test_piece.moved(0, -1);
-            distance += 1;
+        (piece.row - self.landing(*piece).row) as u32
+    }
+
+    /// Moves `piece` down until the next step would collide.
+    fn landing(&self, piece: FallingPiece) -> FallingPiece {
+        let mut dropped = piece;
+        while self.can_place(&dropped.moved(0, -1)) {
+            dropped = dropped.moved(0, -1);
         }
-        distance
+        dropped
     }
 
     /// Counts total occupied cells on the board.
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
     pub fn cell_count(&self) -> u32 {
-        self.cells
-            .iter()
-            .flat_map(|row| row.iter())
-            .filter(|&&c| c)
-            .count() as u32
+        self.all_cells().filter(|&&c| c).count() as u32
     }
 
     /// Checks if the board is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.cells.iter().all(|row| row.iter().all(|&c| !c))
+        self.all_cells().all(|&c| !c)
     }
 }
 
@@ -240,9 +564,7 @@ impl Display for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         #[allow(clippy::cast_possible_truncation)]
         let cells = self
-            .cells
-            .iter()
-            .enumerate()
+            .rows_bottom_up()
             .flat_map(|(row, cols)| {
                 cols.iter()
                     .enumerate()
@@ -251,7 +573,7 @@ impl Display for Board {
             })
             .collect::<Vec<_>>();
 
-        visualize_cells(f, &cells, Self::WIDTH, Self::HEIGHT)
+        visualize_cells(f, &cells, self.width(), self.height())
     }
 }
 
@@ -297,3 +619,324 @@ pub fn visualize_cells(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn trimmed_rows_round_trip() {
+        let mut board = Board::new();
+        board[0].copy_from_slice(&[true, true, false, true, true, true, true, true, true, true]);
+        board[1][0] = true;
+        board[3][9] = true;
+
+        let rows = board.to_trimmed_rows().expect("10 columns fit in a u16");
+        assert_eq!(rows, [0b11_1111_1011, 0b1, 0, 0b10_0000_0000]);
+        assert_eq!(rows.len(), board.occupied_height());
+        assert_eq!(
+            Board::from_trimmed_rows(&rows, Board::WIDTH, Board::HEIGHT),
+            Some(board)
+        );
+        assert_eq!(Board::new().to_trimmed_rows(), Some(vec![]));
+        assert_eq!(
+            Board::from_trimmed_rows(&[], Board::WIDTH, Board::HEIGHT),
+            Some(Board::new())
+        );
+        assert_eq!(
+            Board::from_trimmed_rows(&[1 << Board::WIDTH], Board::WIDTH, Board::HEIGHT),
+            None
+        );
+        assert_eq!(
+            Board::from_trimmed_rows(&[0; Board::HEIGHT + 1], Board::WIDTH, Board::HEIGHT),
+            None
+        );
+    }
+
+    #[test]
+    fn trimmed_rows_round_trip_keeps_board_size() {
+        let mut board = Board::with_dimensions(6, 10);
+        board[0][..5].fill(true);
+        board[2][5] = true;
+
+        let rows = board.to_trimmed_rows().expect("6 columns fit in a u16");
+        assert_eq!(rows, [0b01_1111, 0, 0b10_0000]);
+        let restored = Board::from_trimmed_rows(&rows, 6, 10).expect("rows fit a 6x10 board");
+        assert_eq!((restored.width(), restored.height()), (6, 10));
+        assert_eq!(restored, board);
+
+        assert_eq!(Board::from_trimmed_rows(&[1 << 6], 6, 10), None);
+        assert_eq!(Board::with_dimensions(17, 20).to_trimmed_rows(), None);
+    }
+
+    #[test]
+    fn overlay_with_complement_fills_the_board() {
+        let mut board = Board::new();
+        board[0].copy_from_slice(&[
+            true, false, true, true, false, true, true, true, true, false,
+        ]);
+        board[3][7] = true;
+        let mut complement = Board::new();
+        for (col, row) in board.positions() {
+            complement[row][col] = !board[row][col];
+        }
+
+        let full = board.overlay(&complement);
+        assert_eq!(full.cell_count() as usize, Board::WIDTH * Board::HEIGHT);
+        assert_eq!(board.difference(&complement), full);
+    }
+
+    #[test]
+    fn difference_with_itself_is_empty() {
+        let mut board = Board::with_dimensions(6, 8);
+        board[0][..5].fill(true);
+        board[2][3] = true;
+        assert!(board.difference(&board).is_empty());
+        assert_eq!(board.overlay(&board), board);
+    }
+
+    #[test]
+    fn clear_full_rows_indices_reports_separated_rows() {
+        let mut board = Board::new();
+        board[1].fill(true);
+        board[2][4] = true;
+        board[3].fill(true);
+        board[4][0] = true;
+
+        let cleared = board.clear_full_rows_indices();
+
+        assert_eq!(cleared, vec![1, 3]);
+        assert!(board[1][4]);
+        assert!(board[2][0]);
+        assert_eq!(board.cell_count(), 2);
+    }
+
+    #[test]
+    fn narrow_board_clears_rows_of_its_width() {
+        let mut board = Board::with_dimensions(6, 10);
+        assert_eq!((board.width(), board.height()), (6, 10));
+        assert!(board.is_occupied(6, 0));
+        assert!(board.is_occupied(0, 10));
+
+        for col in 0..5 {
+            board[0][col] = true;
+        }
+        assert!(!board.is_row_full(0));
+        board[0][5] = true;
+        board[1][2] = true;
+        assert!(board.is_row_full(0));
+
+        assert_eq!(board.clear_full_rows(), 1);
+        assert!(board[0][2]);
+        assert_eq!(board.cell_count(), 1);
+        assert_eq!(board.column_heights(), [0, 0, 1, 0, 0, 0]);
+        assert_eq!(board[0].len(), 6);
+    }
+
+    #[test]
+    fn tall_board_holds_a_stack_above_row_twenty() {
+        let mut board = Board::with_dimensions(10, 40);
+        for row in 0..30 {
+            board[row][row % 10] = true;
+        }
+        board[35].fill(true);
+        board[36][3] = true;
+        assert_eq!(board[39].len(), Board::WIDTH);
+        assert_eq!(board.stack_height(), 37);
+
+        assert_eq!(board.clear_full_rows_indices(), [35]);
+        assert!(board[35][3]);
+        assert_eq!(board.stack_height(), 36);
+        assert_eq!(board.column_height(3), 36);
+    }
+
+    #[test]
+    fn from_cells_takes_its_size_from_the_rows() {
+        let rows = vec![[true, false, false, true]; 25];
+        let board = Board::from_cells(&rows);
+        assert_eq!((board.width(), board.height()), (4, 25));
+        assert_eq!(board.column_heights(), [25, 0, 0, 25]);
+        assert_eq!(board.row(24), [true, false, false, true]);
+    }
+
+    #[test]
+    fn landing_for_starts_at_the_top_of_a_short_board() {
+        let board = Board::with_dimensions(6, 10);
+        let vertical = Rotation(1);
+        let landed = board
+            .landing_for(Tetromino::I, vertical, 0)
+            .expect("fits on an empty board");
+        assert!(landed.cells().iter().all(|&(_, row)| (0..4).contains(&row)));
+        assert!(board.landing_for(Tetromino::I, Rotation(0), 3).is_none());
+    }
+
+    #[test]
+    fn landing_for_drops_i_piece_into_empty_column() {
+        let mut board = Board::new();
+        for row in 0..4 {
+            board[row].fill(true);
+            board[row][9] = false;
+        }
+        let vertical = Rotation(1);
+        let offset = Tetromino::I.cells(vertical)[0].0;
+        let landed = board
+            .landing_for(Tetromino::I, vertical, 9 - offset)
+            .expect("column 9 is open");
+        let mut cells = landed.cells();
+        cells.sort_unstable();
+        assert_eq!(cells, [(9, 0), (9, 1), (9, 2), (9, 3)]);
+    }
+
+    #[test]
+    fn landing_for_rejects_blocked_column() {
+        let mut board = Board::new();
+        for row in 0..Board::HEIGHT {
+            board[row][0] = true;
+        }
+        let vertical = Rotation(1);
+        let offset = Tetromino::I.cells(vertical)[0].0;
+        assert!(board.landing_for(Tetromino::I, vertical, -offset).is_none());
+        assert!(
+            board
+                .landing_for(Tetromino::I, vertical, 1 - offset)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn hard_drop_and_drop_distance_agree() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        for _ in 0..50 {
+            let mut board = Board::new();
+            for (col, row) in board.positions() {
+                board[row][col] = row < 10 && rng.random_bool(0.4);
+            }
+            for tetromino in Tetromino::ALL {
+                for rotation in Rotation::all() {
+                    for col in 0..Board::WIDTH {
+                        let mut piece = FallingPiece::spawn(tetromino);
+                        piece.rotation = rotation;
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                        {
+                            piece.col = col as i8;
+                        }
+                        let Some(dropped) = board.hard_drop(&piece) else {
+                            continue;
+                        };
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                        let distance = board.drop_distance(&piece) as i8;
+                        assert_eq!(dropped.row + distance, piece.row);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn column_heights_matches_column_height() {
+        let mut board = Board::new();
+        for (col, height) in [0, 5, 2, 20, 1, 0, 7, 3, 3, 11].into_iter().enumerate() {
+            for row in 0..height {
+                board[row][col] = true;
+            }
+        }
+        // Holes and overhangs must not change the reported heights
+        board[1][1] = false;
+        board[0][6] = false;
+        board[12][0] = true;
+
+        let expected: [usize; Board::WIDTH] = std::array::from_fn(|col| board.column_height(col));
+        assert_eq!(board.column_heights(), expected);
+        assert_eq!(Board::new().column_heights(), [0; Board::WIDTH]);
+    }
+
+    #[test]
+    fn staircase_heightmap_and_surface_bars() {
+        let mut board = Board::new();
+        for col in 0..Board::WIDTH {
+            for row in 0..2 * col {
+                board[row][col] = true;
+            }
+        }
+
+        assert_eq!(board.heightmap_string(), "[0,2,4,6,8,10,12,14,16,18]");
+        assert_eq!(board.surface_bars(), ".▁▂▃▄▄▅▆▇█");
+        assert_eq!(board.surface_profile(), board.column_heights());
+        assert_eq!(Board::with_dimensions(4, 8).heightmap_string(), "[0,0,0,0]");
+    }
+
+    #[test]
+    fn add_garbage_rows_shifts_stack_up() {
+        let mut board = Board::new();
+        board[0][3] = true;
+        board[1][7] = true;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+
+        let topped_out = board.add_garbage_rows(2, None, &mut rng);
+
+        assert!(!topped_out);
+        assert!(board[2][3]);
+        assert!(board[3][7]);
+        for row in 0..2 {
+            assert_eq!(board[row].iter().filter(|&&c| !c).count(), 1);
+        }
+        assert_eq!(board.cell_count(), 2 + 2 * 9);
+    }
+
+    #[test]
+    fn stack_height_finds_highest_row() {
+        let mut board = Board::new();
+        assert_eq!(board.stack_height(), 0);
+        assert!(board.is_row_empty(0));
+
+        board[0][0] = true;
+        board[1][3] = true;
+        board[6][9] = true;
+        assert_eq!(board.stack_height(), 7);
+        assert!(!board.is_row_empty(6));
+        assert!(board.is_row_empty(5));
+
+        board[Board::HEIGHT - 1][4] = true;
+        assert_eq!(board.stack_height(), Board::HEIGHT);
+    }
+
+    #[test]
+    fn flat_round_trip() {
+        let mut board = Board::new();
+        board[0][0] = true;
+        board[0][9] = true;
+        board[19][3] = true;
+
+        let flat = board.to_flat();
+        assert!((flat[9] - 1.0).abs() < f32::EPSILON);
+        assert!((flat[19 * Board::WIDTH + 3] - 1.0).abs() < f32::EPSILON);
+        assert!((flat.iter().sum::<f32>() - 3.0).abs() < f32::EPSILON);
+
+        assert_eq!(Board::from_flat(&flat, Board::WIDTH, 0.5), Some(board));
+        assert_eq!(Board::from_flat(&flat[1..], Board::WIDTH, 0.5), None);
+    }
+
+    #[test]
+    fn add_garbage_rows_uses_given_gap() {
+        let mut board = Board::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+
+        board.add_garbage_rows(3, Some(4), &mut rng);
+
+        for row in 0..3 {
+            assert!(!board[row][4]);
+            assert_eq!(board[row].iter().filter(|&&c| c).count(), 9);
+        }
+        assert!(board[3].iter().all(|&c| !c));
+    }
+
+    #[test]
+    fn add_garbage_rows_reports_top_out() {
+        let mut board = Board::new();
+        board[19][0] = true;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+
+        assert!(board.add_garbage_rows(1, None, &mut rng));
+    }
+}
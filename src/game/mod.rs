@@ -1,8 +1,10 @@
 pub mod board;
+mod rng;
 mod rotations;
 pub mod state;
 pub mod tetromino;
 
 pub use board::{Board, visualize_cells};
-pub use state::{GamePhase, GameState, MoveResult};
+pub use rng::seed_default_rng;
+pub use state::{GameOverReason, GamePhase, GameState, MoveResult, TSpin, garbage_lines};
 pub use tetromino::{FallingPiece, Rotation, Tetromino};
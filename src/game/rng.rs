@@ -0,0 +1,31 @@
+//! Thread-local generator behind the RNG-less constructors.
+//!
+//! [`Tetromino::random`](super::Tetromino::random), [`GameState::new`](super::GameState::new)
+//! and [`GameState::from_board`](super::GameState::from_board) draw from here.
+//! Until [`seed_default_rng`] is called this is the OS-seeded thread RNG;
+//! afterwards it is a seeded [`StdRng`], so a whole session replays exactly.
+//! The `_with_rng` variants never touch it.
+
+use std::cell::RefCell;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+thread_local! {
+    static SEEDED: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Makes the default generator of the current thread deterministic.
+///
+/// Calling it again restarts the sequence from `seed`.
+pub fn seed_default_rng(seed: u64) {
+    SEEDED.with(|rng| *rng.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Runs `f` with the default generator of the current thread.
+pub fn with_default_rng<T>(f: impl FnOnce(&mut dyn rand::RngCore) -> T) -> T {
+    SEEDED.with(|seeded| match seeded.borrow_mut().as_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::rng()),
+    })
+}
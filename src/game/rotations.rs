@@ -10,7 +10,7 @@
 //!                 ██         ███         ██           ██         ███         ███
 //! ```
 
-use super::Tetromino;
+use super::{Rotation, Tetromino};
 
 /// I piece rotations in a 4x4 bounding box.
 /// ```text
@@ -113,7 +113,7 @@ impl Tetromino {
     /// Returns the cell offsets for this piece at the given rotation state.
     #[must_use]
     pub const fn rotation_cells(self, rotation: u8) -> [(i8, i8); 4] {
-        let r = (rotation % 4) as usize;
+        let r = Rotation::from_u8(rotation).0 as usize;
         match self {
             Self::I => I[r],
             Self::O => O[r],
@@ -172,9 +172,9 @@ mod tests {
     #[test]
     fn o_all_rotations_identical() {
         let expected = cell_set([(0, 0), (1, 0), (0, 1), (1, 1)]);
-        for rot in 0..4 {
-            let cells = Tetromino::O.cells(Rotation(rot));
-            assert_eq!(cell_set(cells), expected, "O rotation {rot} differs");
+        for rot in Rotation::all() {
+            let cells = Tetromino::O.cells(rot);
+            assert_eq!(cell_set(cells), expected, "O rotation {} differs", rot.0);
         }
     }
 
@@ -325,13 +325,14 @@ mod tests {
     #[test]
     fn all_pieces_have_4_unique_cells() {
         for piece in Tetromino::ALL {
-            for rot in 0..4 {
-                let cells = piece.cells(Rotation(rot));
+            for rot in Rotation::all() {
+                let cells = piece.cells(rot);
                 let unique: HashSet<_> = cells.into_iter().collect();
                 assert_eq!(
                     unique.len(),
                     4,
-                    "{piece:?} rotation {rot} has {} unique cells",
+                    "{piece:?} rotation {} has {} unique cells",
+                    rot.0,
                     unique.len()
                 );
             }
@@ -350,8 +351,8 @@ mod tests {
     #[test]
     fn all_cells_are_connected() {
         for piece in Tetromino::ALL {
-            for rot in 0..4 {
-                let cells = piece.cells(Rotation(rot));
+            for rot in Rotation::all() {
+                let cells = piece.cells(rot);
                 let set: HashSet<_> = cells.into_iter().collect();
 
                 for &(col, row) in &set {
@@ -363,7 +364,8 @@ mod tests {
                     ];
                     assert!(
                         neighbors.iter().any(|n| set.contains(n)),
-                        "{piece:?} rotation {rot}: cell ({col}, {row}) is disconnected"
+                        "{piece:?} rotation {}: cell ({col}, {row}) is disconnected",
+                        rot.0
                     );
                 }
             }
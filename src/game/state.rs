@@ -1,4 +1,9 @@
-use crate::game::{Board, FallingPiece, Tetromino};
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::game::rng::with_default_rng;
+use crate::game::{Board, FallingPiece, Rotation, Tetromino};
 
 /// The result of attempting a move.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,10 +12,52 @@ pub enum MoveResult {
     Moved,
     /// Move was blocked (e.g., hit wall or other piece).
     Blocked,
+    /// Piece touched down and is waiting for the lock delay to expire.
+    Landed,
     /// Piece landed and was locked in place.
-    Locked { rows_cleared: u32 },
-    /// Game is over (piece couldn't spawn).
-    GameOver,
+    Locked { rows_cleared: u32, t_spin: TSpin },
+    /// Game is over, for the given reason.
+    GameOver(GameOverReason),
+}
+
+/// Why a game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverReason {
+    /// The next piece overlapped the stack where it spawns.
+    BlockOut,
+    /// Incoming garbage pushed the stack off the top or into the current piece.
+    TopOut,
+}
+
+impl fmt::Display for GameOverReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockOut => write!(f, "Block out"),
+            Self::TopOut => write!(f, "Topped out"),
+        }
+    }
+}
+
+/// Number of garbage rows sent for a line clear (double 1, triple 2, tetris 4).
+#[must_use]
+pub const fn garbage_lines(rows_cleared: u32) -> u32 {
+    match rows_cleared {
+        0 | 1 => 0,
+        2 => 1,
+        3 => 2,
+        _ => 4,
+    }
+}
+
+/// Kind of T-spin performed by a locked piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TSpin {
+    #[default]
+    None,
+    /// Three corners occupied, but only one in front of the T.
+    Mini,
+    /// Three corners occupied, including both in front of the T.
+    Full,
 }
 
 /// Current phase of the game.
@@ -18,6 +65,8 @@ pub enum MoveResult {
 pub enum GamePhase {
     /// A piece is falling and can be controlled.
     Falling,
+    /// Full rows are marked and wait for [`GameState::collapse_rows`].
+    Clearing,
     /// Game has ended.
     GameOver,
 }
@@ -28,16 +77,42 @@ pub struct GameState {
     pub board: Board,
     pub current: Option<FallingPiece>,
     pub next: Tetromino,
+    /// Pieces that follow `next`, in order. Random pieces are drawn once it is empty.
+    pub queue: VecDeque<Tetromino>,
     pub rows_cleared: u32,
+    /// Drop points: 1 per soft-dropped cell and 2 per hard-dropped cell.
+    pub score: u32,
     pub phase: GamePhase,
+    /// Why the game ended, set once `phase` is [`GamePhase::GameOver`].
+    pub game_over_reason: Option<GameOverReason>,
+    /// Time a landed piece waits before locking. Zero locks immediately.
+    pub lock_delay: Duration,
+    /// When the current piece touched down, if it is resting on the stack.
+    pub landed_at: Option<Instant>,
+    /// Number of lock-delay resets used by the current piece.
+    pub lock_resets: u32,
+    /// Whether the last successful action on the current piece was a rotation.
+    pub last_action_rotation: bool,
+    /// Whether locking stops at [`GamePhase::Clearing`] instead of collapsing
+    /// full rows right away.
+    pub defer_clears: bool,
+    /// Full rows marked by the last lock, bottom to top, while clearing is deferred.
+    pub pending_clear: Vec<usize>,
+    /// Rotation new pieces spawn in. Rotation 0 is the standard spawn.
+    pub spawn_rotation: Rotation,
 }
 
 impl GameState {
-    /// Creates a new game with an empty board and random pieces.
+    /// Lock delay used by the interactive TUIs.
+    pub const DEFAULT_LOCK_DELAY: Duration = Duration::from_millis(500);
+    /// Maximum number of times moves/rotations can reset the lock delay per piece.
+    pub const MAX_LOCK_RESETS: u32 = 15;
+
+    /// Creates a new game with an empty board and random pieces drawn from the
+    /// default generator (see [`crate::game::seed_default_rng`]).
     #[must_use]
     pub fn new() -> Self {
-        let mut rng = rand::rng();
-        Self::new_with_rng(&mut rng)
+        with_default_rng(|rng| Self::new_with_rng(rng))
     }
 
     /// Creates a new game with an empty board using a provided RNG.
@@ -47,42 +122,135 @@ impl GameState {
             board: Board::new(),
             current: Some(FallingPiece::spawn(Tetromino::random_with_rng(rng))),
             next: Tetromino::random_with_rng(rng),
+            queue: VecDeque::new(),
             rows_cleared: 0,
+            score: 0,
             phase: GamePhase::Falling,
+            game_over_reason: None,
+            lock_delay: Duration::ZERO,
+            landed_at: None,
+            lock_resets: 0,
+            last_action_rotation: false,
+            defer_clears: false,
+            pending_clear: Vec::new(),
+            spawn_rotation: Rotation(0),
         }
     }
 
     /// Creates a new game with specified starting pieces (useful for testing/AI).
     #[must_use]
-    pub const fn with_pieces(current: Tetromino, next: Tetromino) -> Self {
+    pub fn with_pieces(current: Tetromino, next: Tetromino) -> Self {
         Self {
             board: Board::new(),
             current: Some(FallingPiece::spawn(current)),
             next,
+            queue: VecDeque::new(),
             rows_cleared: 0,
+            score: 0,
             phase: GamePhase::Falling,
+            game_over_reason: None,
+            lock_delay: Duration::ZERO,
+            landed_at: None,
+            lock_resets: 0,
+            last_action_rotation: false,
+            defer_clears: false,
+            pending_clear: Vec::new(),
+            spawn_rotation: Rotation(0),
         }
     }
 
+    /// Creates a new game whose pieces come from `pieces` in order.
+    ///
+    /// The first two pieces become `current` and `next` and the rest are
+    /// queued; random pieces are used once the slice is exhausted.
+    #[must_use]
+    pub fn with_queue(pieces: &[Tetromino]) -> Self {
+        let mut queue: VecDeque<Tetromino> = pieces.iter().copied().collect();
+        let current = queue.pop_front().unwrap_or_else(Tetromino::random);
+        let next = queue.pop_front().unwrap_or_else(Tetromino::random);
+        let mut game = Self::with_pieces(current, next);
+        game.queue = queue;
+        game
+    }
+
     /// Creates a game state from an existing board (useful for AI evaluation).
     #[must_use]
     pub fn from_board(board: Board) -> Self {
-        let mut rng = rand::rng();
-        Self::from_board_with_rng(board, &mut rng)
+        with_default_rng(|rng| Self::from_board_with_rng(board, rng))
     }
 
     /// Creates a game state from an existing board using a provided RNG.
     #[must_use]
     pub fn from_board_with_rng<R: rand::Rng + ?Sized>(board: Board, rng: &mut R) -> Self {
         Self {
+            current: Some(FallingPiece::spawn_on(
+                Tetromino::random_with_rng(rng),
+                &board,
+            )),
             board,
-            current: Some(FallingPiece::spawn(Tetromino::random_with_rng(rng))),
             next: Tetromino::random_with_rng(rng),
+            queue: VecDeque::new(),
             rows_cleared: 0,
+            score: 0,
             phase: GamePhase::Falling,
+            game_over_reason: None,
+            lock_delay: Duration::ZERO,
+            landed_at: None,
+            lock_resets: 0,
+            last_action_rotation: false,
+            defer_clears: false,
+            pending_clear: Vec::new(),
+            spawn_rotation: Rotation(0),
         }
     }
 
+    /// Restarts this game on `board` in place, as [`GameState::from_board_with_rng`]
+    /// would, drawing the same pieces from `rng`. Keeps the lock delay and the
+    /// queue's allocation, so a simulation can reuse one state for every move.
+    pub fn reset_to_board<R: rand::Rng + ?Sized>(&mut self, board: Board, rng: &mut R) {
+        self.board = board;
+        self.current = Some(self.spawn_piece(Tetromino::random_with_rng(rng)));
+        self.next = Tetromino::random_with_rng(rng);
+        self.queue.clear();
+        self.rows_cleared = 0;
+        self.score = 0;
+        self.phase = GamePhase::Falling;
+        self.game_over_reason = None;
+        self.landed_at = None;
+        self.lock_resets = 0;
+        self.last_action_rotation = false;
+        self.pending_clear.clear();
+    }
+
+    /// Sets the lock delay for landed pieces.
+    #[must_use]
+    pub const fn with_lock_delay(mut self, lock_delay: Duration) -> Self {
+        self.lock_delay = lock_delay;
+        self
+    }
+
+    /// Spawns pieces turned to `rotation`, including the current one.
+    /// Pieces that would stick out of the top are lowered to fit.
+    #[must_use]
+    pub fn with_spawn_rotation(mut self, rotation: Rotation) -> Self {
+        self.spawn_rotation = rotation;
+        self.current = self.current.map(|p| self.spawn_piece(p.tetromino));
+        self
+    }
+
+    /// Places `tetromino` at the spawn position and rotation of this game.
+    fn spawn_piece(&self, tetromino: Tetromino) -> FallingPiece {
+        FallingPiece::spawn_rotated_on(tetromino, self.spawn_rotation, &self.board)
+    }
+
+    /// Holds full rows on the board after a lock until [`GameState::collapse_rows`],
+    /// so a UI can show them before they disappear.
+    #[must_use]
+    pub const fn with_deferred_clears(mut self) -> Self {
+        self.defer_clears = true;
+        self
+    }
+
     /// Returns true if the game is still active.
     #[must_use]
     pub const fn is_active(&self) -> bool {
@@ -105,29 +273,35 @@ impl GameState {
         self.try_move(1, 0)
     }
 
-    /// Attempts to move the current piece down (soft drop).
+    /// Attempts to move the current piece down (soft drop), scoring a point if it moves.
     pub fn move_down(&mut self) -> MoveResult {
-        self.try_move(0, -1)
+        let result = self.try_move(0, -1);
+        if result == MoveResult::Moved {
+            self.score += 1;
+        }
+        result
     }
 
     /// Attempts to move the piece by the given offset.
     fn try_move(&mut self, dcol: i8, drow: i8) -> MoveResult {
         if self.phase != GamePhase::Falling {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         }
 
         let Some(piece) = self.current else {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         };
 
         let new_piece = piece.moved(dcol, drow);
 
         if self.board.can_place(&new_piece) {
             self.current = Some(new_piece);
+            self.last_action_rotation = false;
+            self.reset_lock_delay();
             MoveResult::Moved
         } else if drow < 0 {
-            // Moving down and blocked means lock the piece
-            self.lock_piece()
+            // Moving down and blocked means the piece has landed
+            self.land()
         } else {
             MoveResult::Blocked
         }
@@ -146,11 +320,11 @@ impl GameState {
     /// Attempts rotation with basic wall kicks.
     fn try_rotate(&mut self, clockwise: bool) -> MoveResult {
         if self.phase != GamePhase::Falling {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         }
 
         let Some(piece) = self.current else {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         };
 
         let rotated = if clockwise {
@@ -167,6 +341,8 @@ impl GameState {
             let kicked = rotated.moved(dcol, drow);
             if self.board.can_place(&kicked) {
                 self.current = Some(kicked);
+                self.last_action_rotation = true;
+                self.reset_lock_delay();
                 return MoveResult::Moved;
             }
         }
@@ -177,53 +353,239 @@ impl GameState {
     /// Hard drops the current piece to the bottom.
     pub fn hard_drop(&mut self) -> MoveResult {
         if self.phase != GamePhase::Falling {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         }
 
         let Some(piece) = self.current else {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         };
 
         if let Some(dropped) = self.board.hard_drop(&piece) {
+            if dropped.row != piece.row {
+                self.last_action_rotation = false;
+            }
+            self.score += 2 * self.board.drop_distance(&piece);
             self.current = Some(dropped);
             self.lock_piece()
         } else {
-            MoveResult::GameOver
+            self.end_game(GameOverReason::BlockOut)
+        }
+    }
+
+    /// Drops the current piece to the bottom without locking it (sonic drop).
+    /// The piece then waits for the lock delay like any landed piece.
+    pub fn sonic_drop(&mut self) -> MoveResult {
+        if self.phase != GamePhase::Falling {
+            return self.game_over_result();
+        }
+
+        let Some(piece) = self.current else {
+            return self.game_over_result();
+        };
+
+        if let Some(dropped) = self.board.hard_drop(&piece) {
+            if dropped.row != piece.row {
+                self.last_action_rotation = false;
+            }
+            self.current = Some(dropped);
+            self.land()
+        } else {
+            self.end_game(GameOverReason::BlockOut)
+        }
+    }
+
+    /// Handles a piece that could not move down.
+    /// Locks immediately without a lock delay, otherwise starts the countdown.
+    fn land(&mut self) -> MoveResult {
+        if self.lock_delay.is_zero() {
+            return self.lock_piece();
+        }
+        if self.landed_at.is_none() {
+            self.landed_at = Some(Instant::now());
         }
+        MoveResult::Landed
+    }
+
+    /// Restarts the lock countdown after a successful move or rotation.
+    /// A piece moved off its support starts falling again.
+    fn reset_lock_delay(&mut self) {
+        if self.landed_at.is_none() {
+            return;
+        }
+        if !self.is_grounded() {
+            self.landed_at = None;
+        } else if self.lock_resets < Self::MAX_LOCK_RESETS {
+            self.landed_at = Some(Instant::now());
+            self.lock_resets += 1;
+        }
+    }
+
+    /// Returns true if the current piece is resting on the stack or floor.
+    fn is_grounded(&self) -> bool {
+        self.current
+            .is_some_and(|p| !self.board.can_place(&p.moved(0, -1)))
+    }
+
+    /// Locks a landed piece once its lock delay has expired at `now`.
+    /// Returns `None` if no piece is waiting to lock or the delay is still running.
+    pub fn lock_if_expired(&mut self, now: Instant) -> Option<MoveResult> {
+        let landed_at = self.landed_at?;
+        if self.phase != GamePhase::Falling
+            || now.saturating_duration_since(landed_at) < self.lock_delay
+        {
+            return None;
+        }
+        if !self.is_grounded() {
+            self.landed_at = None;
+            return None;
+        }
+        Some(self.lock_piece())
     }
 
     /// Locks the current piece in place and spawns the next piece.
     fn lock_piece(&mut self) -> MoveResult {
         let Some(piece) = self.current.take() else {
-            return MoveResult::GameOver;
+            return self.game_over_result();
         };
+        self.landed_at = None;
+        self.lock_resets = 0;
+        let t_spin = self.detect_t_spin(piece);
+        self.last_action_rotation = false;
 
         // Place the piece on the board
         self.board.place(&piece);
 
-        // Clear any full rows
-        let cleared = self.board.clear_full_rows();
+        // Mark full rows; they collapse now unless clearing is deferred
+        let full = self.board.full_rows();
+        #[allow(clippy::cast_possible_truncation)]
+        let cleared = full.len() as u32;
         self.rows_cleared += cleared;
+        let result = MoveResult::Locked {
+            rows_cleared: cleared,
+            t_spin,
+        };
 
-        // Spawn the next piece
-        let next_piece = FallingPiece::spawn(self.next);
-        self.next = Tetromino::random();
+        if self.defer_clears && !full.is_empty() {
+            self.pending_clear = full;
+            self.phase = GamePhase::Clearing;
+            return result;
+        }
+        self.board.clear_full_rows();
+        self.spawn_next(result)
+    }
+
+    /// Collapses the rows marked by a deferred lock and spawns the next piece.
+    ///
+    /// Returns [`MoveResult::Blocked`] unless the game is in
+    /// [`GamePhase::Clearing`].
+    pub fn collapse_rows(&mut self) -> MoveResult {
+        if self.phase != GamePhase::Clearing {
+            return MoveResult::Blocked;
+        }
+        self.board.clear_full_rows();
+        self.pending_clear.clear();
+        self.phase = GamePhase::Falling;
+        self.spawn_next(MoveResult::Moved)
+    }
+
+    /// Spawns the next piece and returns `result`, or ends the game if the
+    /// spawn position is blocked.
+    fn spawn_next(&mut self, result: MoveResult) -> MoveResult {
+        let next_piece = self.spawn_piece(self.next);
+        self.next = self.queue.pop_front().unwrap_or_else(Tetromino::random);
 
-        // Check if the new piece can be placed (game over check)
         if self.board.can_place(&next_piece) {
             self.current = Some(next_piece);
-            MoveResult::Locked {
-                rows_cleared: cleared,
-            }
+            result
+        } else {
+            self.end_game(GameOverReason::BlockOut)
+        }
+    }
+
+    /// Ends the game for `reason` and returns the matching result.
+    const fn end_game(&mut self, reason: GameOverReason) -> MoveResult {
+        self.current = None;
+        self.landed_at = None;
+        self.phase = GamePhase::GameOver;
+        self.game_over_reason = Some(reason);
+        MoveResult::GameOver(reason)
+    }
+
+    /// Result for actions attempted once the game has ended, or without a piece.
+    /// Actions while rows are waiting to collapse are simply blocked.
+    fn game_over_result(&self) -> MoveResult {
+        if self.phase == GamePhase::Clearing {
+            return MoveResult::Blocked;
+        }
+        MoveResult::GameOver(self.game_over_reason.unwrap_or(GameOverReason::BlockOut))
+    }
+
+    /// Classifies a T piece about to lock using the 3-corner rule.
+    ///
+    /// The last action must have been a rotation and at least three of the four
+    /// diagonal corners around the T's center must be occupied (walls and floor
+    /// count). It is a full T-spin if both corners in front of the T (the side
+    /// its nub points to) are occupied, otherwise a mini.
+    fn detect_t_spin(&self, piece: FallingPiece) -> TSpin {
+        if piece.tetromino != Tetromino::T || !self.last_action_rotation {
+            return TSpin::None;
+        }
+
+        let cells = piece.cells();
+        let is_adjacent =
+            |(c1, r1): (i8, i8), (c2, r2): (i8, i8)| (c1 - c2).abs() + (r1 - r2).abs() == 1;
+        let Some(&(cx, cy)) = cells
+            .iter()
+            .find(|&&cell| cells.iter().filter(|&&o| is_adjacent(cell, o)).count() == 3)
+        else {
+            return TSpin::None;
+        };
+        // The nub is the only arm without an opposite arm
+        let Some(&(nc, nr)) = cells
+            .iter()
+            .find(|&&(c, r)| (c, r) != (cx, cy) && !cells.contains(&(2 * cx - c, 2 * cy - r)))
+        else {
+            return TSpin::None;
+        };
+        let (dc, dr) = (nc - cx, nr - cy);
+
+        let occupied = |(c, r): (i8, i8)| self.board.is_occupied(c, r);
+        let corners = [(-1, -1), (-1, 1), (1, -1), (1, 1)]
+            .into_iter()
+            .filter(|&(oc, or)| occupied((cx + oc, cy + or)))
+            .count();
+        if corners < 3 {
+            return TSpin::None;
+        }
+
+        let front = [(cx + dc + dr, cy + dr + dc), (cx + dc - dr, cy + dr - dc)];
+        if front.into_iter().all(occupied) {
+            TSpin::Full
         } else {
-            self.phase = GamePhase::GameOver;
-            MoveResult::GameOver
+            TSpin::Mini
         }
     }
 
-    /// Advances the game by one gravity tick (piece falls one row).
+    /// Adds `count` garbage rows to the bottom of the board (versus attacks).
+    ///
+    /// Ends the game if the stack is pushed off the top or the raised stack
+    /// now overlaps the current piece.
+    pub fn receive_garbage<R: rand::Rng + ?Sized>(&mut self, count: u32, rng: &mut R) {
+        if self.phase != GamePhase::Falling || count == 0 {
+            return;
+        }
+
+        let topped_out = self.board.add_garbage_rows(count as usize, None, rng);
+        let blocked = self.current.is_some_and(|p| !self.board.can_place(&p));
+
+        if topped_out || blocked {
+            self.end_game(GameOverReason::TopOut);
+        }
+    }
+
+    /// Advances the game by one gravity tick (piece falls one row, unscored).
     pub fn tick(&mut self) -> MoveResult {
-        self.move_down()
+        self.try_move(0, -1)
     }
 
     /// Returns the ghost piece position (where piece would land).
@@ -253,6 +615,28 @@ mod tests {
         assert_eq!(game.rows_cleared, 0);
     }
 
+    #[test]
+    fn test_with_queue_locks_pieces_in_order() {
+        let pieces = [
+            Tetromino::I,
+            Tetromino::O,
+            Tetromino::T,
+            Tetromino::S,
+            Tetromino::L,
+        ];
+        let mut game = GameState::with_queue(&pieces);
+        assert_eq!(game.next, Tetromino::O);
+
+        let mut locked = Vec::new();
+        for _ in 0..pieces.len() {
+            locked.push(game.current.expect("should have piece").tetromino);
+            assert!(matches!(game.hard_drop(), MoveResult::Locked { .. }));
+        }
+        assert_eq!(locked, pieces);
+        assert!(game.queue.is_empty());
+        assert!(game.current.is_some());
+    }
+
     #[test]
     fn test_move_left_right() {
         let mut game = GameState::with_pieces(Tetromino::T, Tetromino::I);
@@ -274,7 +658,13 @@ mod tests {
         let result = game.hard_drop();
 
         assert!(
-            matches!(result, MoveResult::Locked { rows_cleared: 0 }),
+            matches!(
+                result,
+                MoveResult::Locked {
+                    rows_cleared: 0,
+                    ..
+                }
+            ),
             "Expected Locked result with 0 rows cleared"
         );
 
@@ -283,6 +673,19 @@ mod tests {
         assert!(game.current.is_some());
     }
 
+    #[test]
+    fn test_drops_score_points() {
+        let mut game = GameState::with_pieces(Tetromino::I, Tetromino::O);
+        // The flat I spawns with its cells on row 19 and falls to row 0
+        game.hard_drop();
+        assert_eq!(game.score, 2 * 19);
+
+        // Soft drops score a point per cell; gravity does not
+        assert_eq!(game.move_down(), MoveResult::Moved);
+        assert_eq!(game.tick(), MoveResult::Moved);
+        assert_eq!(game.score, 2 * 19 + 1);
+    }
+
     #[test]
     fn test_rotation() {
         let mut game = GameState::with_pieces(Tetromino::T, Tetromino::I);
@@ -321,8 +724,335 @@ mod tests {
 
         let result = game.hard_drop();
         assert!(
-            matches!(result, MoveResult::Locked { rows_cleared: 1 }),
+            matches!(
+                result,
+                MoveResult::Locked {
+                    rows_cleared: 1,
+                    ..
+                }
+            ),
             "Expected Locked result with 1 row cleared"
         );
     }
+
+    #[test]
+    fn spawn_rotation_applies_to_every_piece() {
+        let mut game =
+            GameState::with_pieces(Tetromino::T, Tetromino::I).with_spawn_rotation(Rotation(2));
+        let current = game.current.expect("current piece");
+        assert_eq!(current.rotation, Rotation(2));
+        assert_eq!(current.cells(), [(3, 18), (4, 18), (5, 18), (4, 17)]);
+
+        game.hard_drop();
+        let next = game.current.expect("next piece");
+        assert_eq!((next.tetromino, next.rotation), (Tetromino::I, Rotation(2)));
+        assert!(game.board.can_place(&next));
+    }
+
+    #[test]
+    fn deferred_clear_waits_for_collapse() {
+        let mut game = GameState::with_pieces(Tetromino::I, Tetromino::O).with_deferred_clears();
+        for col in 4..10 {
+            game.board[0][col] = true;
+        }
+        game.board[1][9] = true;
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::I,
+            rotation: Rotation(0),
+            col: 0,
+            row: 1,
+        });
+
+        assert!(matches!(
+            game.hard_drop(),
+            MoveResult::Locked {
+                rows_cleared: 1,
+                ..
+            }
+        ));
+        assert_eq!(game.phase, GamePhase::Clearing);
+        assert_eq!(game.pending_clear, vec![0]);
+        assert!(game.board.is_row_full(0));
+        assert!(game.current.is_none());
+        assert_eq!(game.move_left(), MoveResult::Blocked);
+
+        assert_eq!(game.collapse_rows(), MoveResult::Moved);
+        assert_eq!(game.phase, GamePhase::Falling);
+        assert!(game.pending_clear.is_empty());
+        assert!(game.board[0][9] && !game.board[1][9]);
+        assert_eq!(game.current.map(|p| p.tetromino), Some(Tetromino::O));
+        assert_eq!(game.collapse_rows(), MoveResult::Blocked);
+    }
+
+    /// Returns a game with a T piece resting on the floor and lock delay enabled.
+    fn landed_game() -> GameState {
+        let mut game = GameState::with_pieces(Tetromino::T, Tetromino::I)
+            .with_lock_delay(GameState::DEFAULT_LOCK_DELAY);
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::T,
+            rotation: Rotation(0),
+            col: 3,
+            row: -1,
+        });
+        game
+    }
+
+    #[test]
+    fn test_lock_delay_lands_instead_of_locking() {
+        let mut game = landed_game();
+
+        assert_eq!(game.move_down(), MoveResult::Landed);
+        assert!(game.landed_at.is_some());
+        assert!(game.board.is_empty());
+        assert_eq!(game.lock_if_expired(Instant::now()), None);
+    }
+
+    #[test]
+    fn test_lock_delay_locks_after_expiry() {
+        let mut game = landed_game();
+        game.move_down();
+
+        let later = Instant::now() + GameState::DEFAULT_LOCK_DELAY;
+        assert_eq!(
+            game.lock_if_expired(later),
+            Some(MoveResult::Locked {
+                rows_cleared: 0,
+                t_spin: TSpin::None,
+            })
+        );
+        assert_eq!(game.board.cell_count(), 4);
+        assert!(game.landed_at.is_none());
+        assert_eq!(game.lock_resets, 0);
+    }
+
+    #[test]
+    fn test_lock_delay_reset_by_move_up_to_cap() {
+        let mut game = landed_game();
+        game.move_down();
+
+        for i in 0..GameState::MAX_LOCK_RESETS + 3 {
+            if i % 2 == 0 {
+                game.move_left();
+            } else {
+                game.move_right();
+            }
+        }
+        assert_eq!(game.lock_resets, GameState::MAX_LOCK_RESETS);
+        assert!(game.landed_at.is_some());
+    }
+
+    #[test]
+    fn test_lock_delay_cleared_when_sliding_off_ledge() {
+        let mut game = landed_game();
+        game.board[0][0] = true;
+        game.board[0][1] = true;
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::O,
+            rotation: Rotation(0),
+            col: 0,
+            row: 1,
+        });
+
+        assert_eq!(game.move_down(), MoveResult::Landed);
+        assert_eq!(game.move_right(), MoveResult::Moved);
+        assert_eq!(game.move_right(), MoveResult::Moved);
+        assert!(game.landed_at.is_none());
+        assert_eq!(game.move_down(), MoveResult::Moved);
+    }
+
+    #[test]
+    fn test_sonic_drop_does_not_lock() {
+        let mut game = GameState::with_pieces(Tetromino::O, Tetromino::I)
+            .with_lock_delay(GameState::DEFAULT_LOCK_DELAY);
+
+        assert_eq!(game.sonic_drop(), MoveResult::Landed);
+        assert_eq!(game.current.expect("should have piece").row, 0);
+        assert!(game.board.is_empty());
+        assert_eq!(game.move_right(), MoveResult::Moved);
+    }
+
+    #[test]
+    fn test_garbage_top_out_ends_game() {
+        use rand::SeedableRng;
+
+        let mut game = GameState::with_pieces(Tetromino::O, Tetromino::I);
+        for row in 0..10 {
+            game.board[row][0] = true;
+        }
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        game.receive_garbage(2, &mut rng);
+        assert!(game.is_active());
+
+        game.receive_garbage(10, &mut rng);
+        assert!(game.is_game_over());
+        assert_eq!(game.game_over_reason, Some(GameOverReason::TopOut));
+        assert_eq!(
+            game.move_left(),
+            MoveResult::GameOver(GameOverReason::TopOut)
+        );
+    }
+
+    #[test]
+    fn test_spawn_collision_is_block_out() {
+        let mut game = GameState::with_pieces(Tetromino::O, Tetromino::O);
+        // Occupy the cells where the next piece spawns
+        game.board.place(&FallingPiece::spawn(Tetromino::O));
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::O,
+            rotation: Rotation(0),
+            col: 0,
+            row: 0,
+        });
+
+        assert_eq!(
+            game.hard_drop(),
+            MoveResult::GameOver(GameOverReason::BlockOut)
+        );
+        assert!(game.is_game_over());
+        assert_eq!(game.game_over_reason, Some(GameOverReason::BlockOut));
+        assert!(game.current.is_none());
+    }
+
+    #[test]
+    fn test_narrow_board_spawns_centered_and_clears_rows() {
+        let mut board = Board::with_dimensions(6, 10);
+        board[0][0] = true;
+        board[0][5] = true;
+        let mut game = GameState::from_board(board);
+        game.current = Some(FallingPiece::spawn_on(Tetromino::I, &game.board));
+
+        let cells = game.current.expect("current piece").cells();
+        assert_eq!(cells.map(|(col, _)| col), [1, 2, 3, 4]);
+        assert!(cells.iter().all(|&(_, row)| row == 9));
+
+        let result = game.hard_drop();
+        assert!(matches!(
+            result,
+            MoveResult::Locked {
+                rows_cleared: 1,
+                ..
+            }
+        ));
+        assert!(game.board.is_empty());
+    }
+
+    #[test]
+    fn test_reset_to_board_matches_a_fresh_state() {
+        use rand::SeedableRng;
+
+        let mut game = GameState::with_queue(&[Tetromino::I, Tetromino::O, Tetromino::T]);
+        game.rows_cleared = 12;
+        game.score = 40;
+        game.end_game(GameOverReason::BlockOut);
+
+        let mut board = Board::with_dimensions(6, 10);
+        board[0][2] = true;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        game.reset_to_board(board.clone(), &mut rng);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let fresh = GameState::from_board_with_rng(board, &mut rng);
+        assert_eq!(game.board, fresh.board);
+        let cells = |state: &GameState| state.current.map(FallingPiece::cells);
+        assert_eq!(cells(&game), cells(&fresh));
+        assert_eq!(game.next, fresh.next);
+        assert!(game.queue.is_empty());
+        assert_eq!((game.rows_cleared, game.score), (0, 0));
+        assert!(game.is_active());
+        assert_eq!(game.game_over_reason, None);
+
+        // The new piece spawns at the top of the short board
+        let top = game
+            .current
+            .expect("current piece")
+            .cells()
+            .map(|(_, row)| row);
+        assert_eq!(top.iter().max(), Some(&9));
+    }
+
+    #[test]
+    fn test_locking_into_spawn_rows_blocks_out() {
+        let mut game = GameState::with_pieces(Tetromino::O, Tetromino::O);
+        // A tower under the O spawn columns, with its top at row 16
+        for row in 0..17 {
+            game.board[row][4] = true;
+            game.board[row][5] = true;
+        }
+
+        // The O rests on rows 17 and 18, inside the next O's spawn cells
+        assert_eq!(
+            game.hard_drop(),
+            MoveResult::GameOver(GameOverReason::BlockOut)
+        );
+        assert!(game.board[18][4] && game.board[18][5]);
+        assert!(game.is_game_over());
+    }
+
+    /// Builds a T-spin double slot centered at (4, 1) with an overhang at (3, 2).
+    fn t_spin_double_board() -> Board {
+        let mut board = Board::new();
+        board[0].fill(true);
+        board[0][4] = false;
+        board[1].fill(true);
+        for col in 3..=5 {
+            board[1][col] = false;
+        }
+        board[2][3] = true;
+        board
+    }
+
+    #[test]
+    fn t_spin_double_is_detected() {
+        let mut game = GameState::with_pieces(Tetromino::T, Tetromino::O);
+        game.board = t_spin_double_board();
+        // Pointing left above the slot; rotating CCW points it down into the slot
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::T,
+            rotation: Rotation(3),
+            col: 3,
+            row: 0,
+        });
+
+        assert_eq!(game.rotate_ccw(), MoveResult::Moved);
+        assert_eq!(
+            game.hard_drop(),
+            MoveResult::Locked {
+                rows_cleared: 2,
+                t_spin: TSpin::Full,
+            }
+        );
+    }
+
+    #[test]
+    fn t_spin_requires_rotation() {
+        let mut game = GameState::with_pieces(Tetromino::T, Tetromino::O);
+        game.board = t_spin_double_board();
+        game.current = Some(FallingPiece {
+            tetromino: Tetromino::T,
+            rotation: Rotation(2),
+            col: 3,
+            row: 0,
+        });
+
+        assert!(matches!(
+            game.hard_drop(),
+            MoveResult::Locked {
+                t_spin: TSpin::None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn flat_drop_is_not_a_t_spin() {
+        let mut game = GameState::with_pieces(Tetromino::T, Tetromino::O);
+        assert!(matches!(
+            game.hard_drop(),
+            MoveResult::Locked {
+                rows_cleared: 0,
+                t_spin: TSpin::None,
+            }
+        ));
+    }
 }
@@ -1,7 +1,11 @@
+use std::fmt;
+
 use rand::Rng;
 
+use super::board::Board;
+
 /// The 7 standard Tetris pieces.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Tetromino {
     I,
     O,
@@ -17,14 +21,48 @@ pub enum Tetromino {
 pub struct Rotation(pub u8);
 
 impl Rotation {
+    /// Returns an iterator over the four rotation states, starting at spawn.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..4).map(Self)
+    }
+
+    /// Creates a rotation from any number of clockwise quarter turns.
+    #[must_use]
+    pub const fn from_u8(turns: u8) -> Self {
+        Self(turns % 4)
+    }
+
     #[must_use]
     pub const fn clockwise(self) -> Self {
-        Self((self.0 + 1) % 4)
+        Self::from_u8(self.0 + 1)
     }
 
     #[must_use]
     pub const fn counter_clockwise(self) -> Self {
-        Self((self.0 + 3) % 4)
+        Self::from_u8(self.0 + 3)
+    }
+}
+
+impl TryFrom<u8> for Rotation {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value < 4 {
+            Ok(Self(value))
+        } else {
+            Err(format!("invalid rotation {value}, expected 0-3"))
+        }
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 % 4 {
+            0 => write!(f, "spawn"),
+            1 => write!(f, "CW"),
+            2 => write!(f, "180"),
+            _ => write!(f, "CCW"),
+        }
     }
 }
 
@@ -53,6 +91,46 @@ impl FallingPiece {
         }
     }
 
+    /// Creates a new piece at the spawn position of `board`: centered on its
+    /// width and at the top of its height. Matches [`FallingPiece::spawn`] on
+    /// a 10x20 board.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn spawn_on(tetromino: Tetromino, board: &Board) -> Self {
+        let piece = Self::spawn(tetromino);
+        Self {
+            col: piece.col + (board.width() as i8 - Board::WIDTH as i8) / 2,
+            row: piece.row + (board.height() as i8 - Board::HEIGHT as i8),
+            ..piece
+        }
+    }
+
+    /// Creates a piece at an explicit rotation and origin, for puzzle setups
+    /// and reproducing exact positions.
+    #[must_use]
+    pub const fn spawn_at(tetromino: Tetromino, rotation: Rotation, col: i8, row: i8) -> Self {
+        Self {
+            tetromino,
+            rotation,
+            col,
+            row,
+        }
+    }
+
+    /// Like [`FallingPiece::spawn_on`] with the piece turned to `rotation`,
+    /// lowered just enough for its top cell to fit on `board`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn spawn_rotated_on(tetromino: Tetromino, rotation: Rotation, board: &Board) -> Self {
+        let piece = Self {
+            rotation,
+            ..Self::spawn_on(tetromino, board)
+        };
+        let top = piece.cells().iter().map(|&(_, row)| row).max().unwrap_or(0);
+        let overflow = top - (board.height() as i8 - 1);
+        piece.moved(0, -overflow.max(0))
+    }
+
     /// Returns the absolute cell positions for this piece.
     #[must_use]
     pub fn cells(self) -> [(i8, i8); 4] {
@@ -107,11 +185,41 @@ impl Tetromino {
         Self::L,
     ];
 
-    /// Returns a random tetromino.
+    /// Returns the piece's letter (`I`, `O`, `T`, `S`, `Z`, `J` or `L`).
+    #[must_use]
+    pub const fn to_char(self) -> char {
+        match self {
+            Self::I => 'I',
+            Self::O => 'O',
+            Self::T => 'T',
+            Self::S => 'S',
+            Self::Z => 'Z',
+            Self::J => 'J',
+            Self::L => 'L',
+        }
+    }
+
+    /// Parses a piece from its letter, ignoring case.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'I' => Some(Self::I),
+            'O' => Some(Self::O),
+            'T' => Some(Self::T),
+            'S' => Some(Self::S),
+            'Z' => Some(Self::Z),
+            'J' => Some(Self::J),
+            'L' => Some(Self::L),
+            _ => None,
+        }
+    }
+
+    /// Returns a random tetromino from the default generator.
+    ///
+    /// See [`seed_default_rng`](super::seed_default_rng) for reproducible sequences.
     #[must_use]
     pub fn random() -> Self {
-        let mut rng = rand::rng();
-        Self::random_with_rng(&mut rng)
+        super::rng::with_default_rng(|rng| Self::random_with_rng(rng))
     }
 
     /// Returns a random tetromino using the provided RNG.
@@ -120,6 +228,48 @@ impl Tetromino {
         Self::ALL[rng.random_range(0..Self::ALL.len())]
     }
 
+    /// Returns a random tetromino with probabilities proportional to
+    /// `weights`, indexed in the order of [`Tetromino::ALL`].
+    ///
+    /// Falls back to [`Tetromino::random_with_rng`] if the weights are
+    /// negative, non-finite or all zero.
+    pub fn weighted_random_with_rng<R: Rng + ?Sized>(rng: &mut R, weights: &[f64; 7]) -> Self {
+        let total: f64 = weights.iter().sum();
+        if weights.iter().any(|&w| w < 0.0) || !total.is_finite() || total <= 0.0 {
+            return Self::random_with_rng(rng);
+        }
+
+        let mut target = rng.random::<f64>() * total;
+        for (piece, &weight) in Self::ALL.iter().zip(weights) {
+            if target < weight {
+                return *piece;
+            }
+            target -= weight;
+        }
+        // Rounding can leave `target` just past the last bucket
+        Self::ALL
+            .into_iter()
+            .zip(weights)
+            .rev()
+            .find(|&(_, &weight)| weight > 0.0)
+            .map_or(Self::ALL[0], |(piece, _)| piece)
+    }
+
+    /// Returns the display color of this piece as RGB, used by the image
+    /// export (`render::to_svg`).
+    #[must_use]
+    pub const fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::I => (0, 240, 240),
+            Self::O => (240, 240, 0),
+            Self::T => (160, 0, 240),
+            Self::S => (0, 240, 0),
+            Self::Z => (240, 0, 0),
+            Self::J => (0, 0, 240),
+            Self::L => (240, 160, 0),
+        }
+    }
+
     /// Returns the spawn position (col, row) for this piece.
     /// Pieces spawn at the top-center of the 10-wide board.
     /// Position is chosen so all cells fit within the 20-row board.
@@ -157,10 +307,105 @@ mod tests {
         assert_eq!(moved.row, piece.row - 1);
     }
 
+    #[test]
+    fn spawn_at_rotation_two() {
+        let piece = FallingPiece::spawn_at(Tetromino::T, Rotation(2), 3, 17);
+        assert_eq!(piece.cells(), [(3, 18), (4, 18), (5, 18), (4, 17)]);
+        assert_eq!(
+            FallingPiece::spawn_rotated_on(Tetromino::T, Rotation(2), &Board::new()).cells(),
+            piece.cells()
+        );
+    }
+
+    #[test]
+    fn rotated_spawn_fits_below_the_top() {
+        let board = Board::new();
+        let piece = FallingPiece::spawn_rotated_on(Tetromino::I, Rotation(1), &board);
+        assert_eq!(piece.cells(), [(5, 16), (5, 17), (5, 18), (5, 19)]);
+        assert!(board.can_place(&piece));
+        assert_eq!(
+            FallingPiece::spawn_rotated_on(Tetromino::L, Rotation(0), &board).cells(),
+            FallingPiece::spawn(Tetromino::L).cells()
+        );
+    }
+
     #[test]
     fn rotation_state_cycle() {
         let r = Rotation(0);
         assert_eq!(r.clockwise().clockwise().clockwise().clockwise(), r);
         assert_eq!(r.counter_clockwise(), Rotation(3));
     }
+
+    #[test]
+    fn rotation_from_u8_wraps() {
+        assert_eq!(Rotation::from_u8(5), Rotation(1));
+        assert_eq!(Rotation::from_u8(u8::MAX), Rotation(3));
+        assert_eq!(Rotation::try_from(3), Ok(Rotation(3)));
+        assert!(Rotation::try_from(4).is_err());
+    }
+
+    #[test]
+    fn rotation_display_names() {
+        let names: Vec<String> = Rotation::all().map(|r| r.to_string()).collect();
+        assert_eq!(names, ["spawn", "CW", "180", "CCW"]);
+    }
+
+    #[test]
+    fn weighted_random_favors_heavy_pieces() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        // S is ten times as likely as each other piece; Z never appears
+        let weights = [1.0, 1.0, 1.0, 10.0, 0.0, 1.0, 1.0];
+        let mut counts = [0u32; 7];
+        for _ in 0..10_000 {
+            let piece = Tetromino::weighted_random_with_rng(&mut rng, &weights);
+            let index = Tetromino::ALL
+                .iter()
+                .position(|&p| p == piece)
+                .expect("piece");
+            counts[index] += 1;
+        }
+
+        // Expected: S about 6667, Z 0, the others about 667 each
+        assert!(counts[3] > 6_300, "{counts:?}");
+        assert_eq!(counts[4], 0);
+        for i in [0, 1, 2, 5, 6] {
+            assert!((500..850).contains(&counts[i]), "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn weighted_random_with_invalid_weights_is_uniform() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let mut seen = [false; 7];
+        for _ in 0..500 {
+            let piece = Tetromino::weighted_random_with_rng(&mut rng, &[0.0; 7]);
+            seen[Tetromino::ALL
+                .iter()
+                .position(|&p| p == piece)
+                .expect("piece")] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn char_round_trip() {
+        for piece in Tetromino::ALL {
+            assert_eq!(Tetromino::from_char(piece.to_char()), Some(piece));
+            assert_eq!(
+                Tetromino::from_char(piece.to_char().to_ascii_lowercase()),
+                Some(piece)
+            );
+        }
+        assert_eq!(Tetromino::from_char('X'), None);
+    }
+
+    #[test]
+    fn rotation_all_yields_four_states() {
+        let all: Vec<_> = Rotation::all().collect();
+        assert_eq!(all, [Rotation(0), Rotation(1), Rotation(2), Rotation(3)]);
+    }
 }
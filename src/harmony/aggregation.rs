@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+/// Statistic used to combine per-run scores when fitness is averaged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggregation {
+    /// Arithmetic mean of all runs.
+    #[default]
+    Mean,
+    /// Middle value (mean of the two middle values for an even count).
+    Median,
+    /// Worst run.
+    Min,
+    /// Mean after dropping the lowest and highest [`Aggregation::TRIM_FRACTION`] of runs.
+    TrimmedMean,
+}
+
+impl Aggregation {
+    /// Fraction of runs dropped from each end by [`Aggregation::TrimmedMean`].
+    pub const TRIM_FRACTION: f64 = 0.1;
+
+    /// Reduces `scores` to a single value. Sorts `scores` in place.
+    /// Returns 0.0 for an empty slice.
+    #[must_use]
+    pub fn apply(self, scores: &mut [f64]) -> f64 {
+        if scores.is_empty() {
+            return 0.0;
+        }
+        scores.sort_by(f64::total_cmp);
+        let n = scores.len();
+
+        match self {
+            Self::Mean => mean(scores),
+            Self::Median => {
+                if n % 2 == 1 {
+                    scores[n / 2]
+                } else {
+                    f64::midpoint(scores[n / 2 - 1], scores[n / 2])
+                }
+            }
+            Self::Min => scores[0],
+            Self::TrimmedMean => {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    clippy::cast_precision_loss
+                )]
+                let trim = (n as f64 * Self::TRIM_FRACTION).floor() as usize;
+                mean(&scores[trim..n - trim])
+            }
+        }
+    }
+}
+
+impl FromStr for Aggregation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Self::Mean),
+            "median" => Ok(Self::Median),
+            "min" => Ok(Self::Min),
+            "trimmed-mean" => Ok(Self::TrimmedMean),
+            other => Err(format!(
+                "unknown aggregation '{other}': expected mean, median, min, or trimmed-mean"
+            )),
+        }
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / f64::from(u32::try_from(values.len()).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scores with one catastrophic early top-out.
+    fn scores() -> Vec<f64> {
+        vec![40.0, 2.0, 38.0, 42.0, 44.0, 36.0, 41.0, 39.0, 43.0, 35.0]
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn mean_of_scores() {
+        assert_close(Aggregation::Mean.apply(&mut scores()), 36.0);
+    }
+
+    #[test]
+    fn median_of_even_count() {
+        assert_close(Aggregation::Median.apply(&mut scores()), 39.5);
+    }
+
+    #[test]
+    fn median_of_odd_count() {
+        assert_close(Aggregation::Median.apply(&mut [3.0, 1.0, 2.0]), 2.0);
+    }
+
+    #[test]
+    fn min_of_scores() {
+        assert_close(Aggregation::Min.apply(&mut scores()), 2.0);
+    }
+
+    #[test]
+    fn trimmed_mean_drops_extremes() {
+        // Drops 2.0 and 44.0, averages the remaining 8
+        assert_close(Aggregation::TrimmedMean.apply(&mut scores()), 314.0 / 8.0);
+    }
+
+    #[test]
+    fn empty_scores_are_zero() {
+        assert_close(Aggregation::Median.apply(&mut []), 0.0);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::weights;
+
+/// Per-weight `(min, max)` search ranges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds(pub [(f64, f64); weights::NUM_WEIGHTS]);
+
+impl Bounds {
+    /// Uses the same `(min, max)` range for every weight.
+    #[must_use]
+    pub const fn uniform(min: f64, max: f64) -> Self {
+        Self([(min, max); weights::NUM_WEIGHTS])
+    }
+
+    /// Returns the `(min, max)` range for weight `index`.
+    #[must_use]
+    pub const fn get(&self, index: usize) -> (f64, f64) {
+        self.0[index]
+    }
+}
+
+impl From<(f64, f64)> for Bounds {
+    fn from((min, max): (f64, f64)) -> Self {
+        Self::uniform(min, max)
+    }
+}
@@ -0,0 +1,658 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand_distr::StandardNormal;
+
+use super::aggregation::Aggregation;
+use super::search::{
+    OptimizeResult, averaged_spread, evaluate_weights, fitness_stats, log_rank_weights,
+};
+use super::verbosity::Verbosity;
+use crate::agent::simulator::ScoringMode;
+use crate::weights;
+
+const N: usize = weights::NUM_WEIGHTS;
+
+type Vector = [f64; N];
+type Matrix = [[f64; N]; N];
+
+/// Configuration for a CMA-ES optimization run.
+#[derive(Debug, Clone)]
+pub struct CmaConfig {
+    pub population: usize,
+    pub iterations: usize,
+    pub initial_sigma: f64,
+    pub sim_length: usize,
+    pub n_weights: usize,
+    pub averaged: bool,
+    pub cache_moves: bool,
+    pub verbosity: Verbosity,
+    pub averaged_runs: usize,
+    pub aggregation: Aggregation,
+    pub scoring: ScoringMode,
+    pub normalize: bool,
+    pub early_stop_patience: usize,
+    pub early_stop_target: f64,
+}
+
+impl CmaConfig {
+    /// The usual `4 + 3 ln(n)` offspring for the weight dimension.
+    pub const DEFAULT_POPULATION: usize = offspring_for(N);
+    pub const DEFAULT_ITERATIONS: usize = 100;
+    pub const DEFAULT_INITIAL_SIGMA: f64 = 0.5;
+    pub const DEFAULT_SIM_LENGTH: usize = 1000;
+    pub const DEFAULT_N_WEIGHTS: usize = weights::NUM_WEIGHTS;
+    pub const DEFAULT_AVERAGED_RUNS: usize = 20;
+    pub const DEFAULT_EARLY_STOP_TARGET: f64 = f64::INFINITY;
+
+    /// Games a full run simulates without early stopping or restarts:
+    /// every offspring of every generation, plus the final spread report when averaged.
+    #[must_use]
+    pub const fn estimated_games(&self) -> usize {
+        let runs = if self.averaged { self.averaged_runs } else { 1 };
+        let spread = if self.averaged { self.averaged_runs } else { 0 };
+        self.iterations * self.population * runs + spread
+    }
+
+    /// Returns a usage string describing CMA-ES-specific options.
+    #[must_use]
+    pub fn usage() -> String {
+        format!(
+            "\
+CMA-ES options:
+  --population <N>      Offspring per generation        [default: {}]
+  --iterations <N>      Number of generations           [default: {}]
+  --initial-sigma <F>   Initial step size               [default: {}]
+  --sim-length <N>      Pieces per simulation game      [default: {}]
+  --n-weights <N>       Number of eval functions         [default: {}]
+  --averaged            Average fitness over multiple runs
+  --averaged-runs <N>   Runs per averaged evaluation    [default: {}]
+  --cache-moves         Share move searches across averaged runs
+  --aggregation <AGG>   Averaged statistic: mean, median, min, trimmed-mean [default: mean]
+  --scoring <MODE>      Game fitness: rows, survival    [default: rows]
+  --early-stop-patience <N> Stop after N iterations without improvement
+  --early-stop-target <F>   Stop once best fitness >= target [default: {}]",
+            Self::DEFAULT_POPULATION,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_INITIAL_SIGMA,
+            Self::DEFAULT_SIM_LENGTH,
+            Self::DEFAULT_N_WEIGHTS,
+            Self::DEFAULT_AVERAGED_RUNS,
+            Self::DEFAULT_EARLY_STOP_TARGET,
+        )
+    }
+}
+
+impl Default for CmaConfig {
+    fn default() -> Self {
+        Self {
+            population: Self::DEFAULT_POPULATION,
+            iterations: Self::DEFAULT_ITERATIONS,
+            initial_sigma: Self::DEFAULT_INITIAL_SIGMA,
+            sim_length: Self::DEFAULT_SIM_LENGTH,
+            n_weights: Self::DEFAULT_N_WEIGHTS,
+            averaged: false,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
+            averaged_runs: Self::DEFAULT_AVERAGED_RUNS,
+            aggregation: Aggregation::Mean,
+            scoring: ScoringMode::Rows,
+            normalize: false,
+            early_stop_patience: 0,
+            early_stop_target: Self::DEFAULT_EARLY_STOP_TARGET,
+        }
+    }
+}
+
+/// Covariance Matrix Adaptation Evolution Strategy over the weight vector.
+///
+/// Follows the standard formulation: weighted recombination of the best
+/// half of each generation, cumulative step-size adaptation, and rank-one
+/// plus rank-μ covariance updates.
+#[derive(Debug)]
+pub struct CmaEsSearch {
+    pub population_size: usize,
+    pub max_iter: usize,
+    pub mean: Vector,
+    pub sigma: f64,
+    pub covariance: Matrix,
+    /// Share a [`MoveCache`](crate::agent::simulator::MoveCache) between the games of an averaged evaluation.
+    pub cache_moves: bool,
+    /// How much progress to print.
+    pub verbosity: Verbosity,
+}
+
+/// Strategy parameters derived from the population size.
+struct Params {
+    recombination: Vec<f64>,
+    mu_eff: f64,
+    c_sigma: f64,
+    d_sigma: f64,
+    c_c: f64,
+    c_1: f64,
+    c_mu: f64,
+    chi_n: f64,
+}
+
+impl Params {
+    fn new(population_size: usize) -> Self {
+        let n = to_f64(N);
+        let mu = (population_size / 2).max(1);
+        let recombination = log_rank_weights(mu);
+        let mu_eff = 1.0 / recombination.iter().map(|w| w * w).sum::<f64>();
+
+        let c_sigma = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let d_sigma =
+            2.0f64.mul_add((((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0), 1.0) + c_sigma;
+        let c_c = (4.0 + mu_eff / n) / (2.0f64.mul_add(mu_eff / n, n + 4.0));
+        let c_1 = 2.0 / (n + 1.3).mul_add(n + 1.3, mu_eff);
+        let c_mu = (1.0 - c_1)
+            .min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / (n + 2.0).mul_add(n + 2.0, mu_eff));
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        Self {
+            recombination,
+            mu_eff,
+            c_sigma,
+            d_sigma,
+            c_c,
+            c_1,
+            c_mu,
+            chi_n,
+        }
+    }
+}
+
+impl CmaEsSearch {
+    /// Creates a new [`CmaEsSearch`] centred on the zero vector with an
+    /// identity covariance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `population_size` is less than 2 or `initial_sigma` is not
+    /// positive.
+    #[must_use]
+    pub fn new(population_size: usize, max_iter: usize, initial_sigma: f64) -> Self {
+        assert!(population_size >= 2, "Population size must be >= 2");
+        assert!(initial_sigma > 0.0, "Initial sigma must be > 0");
+        Self {
+            population_size,
+            max_iter,
+            mean: [0.0; N],
+            sigma: initial_sigma,
+            covariance: identity(),
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
+        }
+    }
+
+    /// Shares found moves between the games of an averaged evaluation.
+    /// Fitness is unchanged; only repeated searches are skipped.
+    #[must_use]
+    pub const fn with_move_cache(mut self, cache_moves: bool) -> Self {
+        self.cache_moves = cache_moves;
+        self
+    }
+
+    /// Sets how much progress is printed while optimizing.
+    #[must_use]
+    pub const fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Samples and scores one generation as `(candidate, step, fitness)`, best first.
+    ///
+    /// Each step is `y = B D z` and each candidate `x = m + sigma * y`.
+    fn sample_offspring<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        scales: &Vector,
+        basis: &Matrix,
+        evaluate: impl Fn(&mut R, Vector) -> f64,
+    ) -> Vec<(Vector, Vector, f64)> {
+        let mut offspring = Vec::with_capacity(self.population_size);
+        for _ in 0..self.population_size {
+            let z: Vector = std::array::from_fn(|_| rng.sample(StandardNormal));
+            let stretched: Vector = std::array::from_fn(|i| scales[i] * z[i]);
+            let step = mat_vec(basis, &stretched);
+            let candidate: Vector =
+                std::array::from_fn(|i| self.sigma.mul_add(step[i], self.mean[i]));
+            let fitness = evaluate(rng, candidate);
+            offspring.push((candidate, step, fitness));
+        }
+        offspring.sort_by(|a, b| b.2.total_cmp(&a.2));
+        offspring
+    }
+
+    /// Runs the CMA-ES optimization loop.
+    ///
+    /// Returns the best weights sampled in any generation and their fitness.
+    pub fn optimize_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        sim_length: usize,
+        n_weights: usize,
+        averaged: bool,
+        averaged_runs: usize,
+        aggregation: Aggregation,
+        scoring: ScoringMode,
+        early_stop_patience: usize,
+        early_stop_target: f64,
+        rng: &mut R,
+        mut log: Option<&mut dyn Write>,
+        progress: &mut dyn Write,
+    ) -> OptimizeResult {
+        let params = Params::new(self.population_size);
+        let mut path_sigma = [0.0; N];
+        let mut path_c = [0.0; N];
+        let mut best_weights = self.mean;
+        let mut best_fitness = f64::NEG_INFINITY;
+        let mut no_improve = 0usize;
+        let mut iterations_used = 0usize;
+
+        for generation in 0..self.max_iter {
+            iterations_used = generation + 1;
+            let (eigenvalues, basis) = symmetric_eigen(&self.covariance);
+            let scales = eigenvalues.map(|value| value.max(f64::MIN_POSITIVE).sqrt());
+
+            let offspring = self.sample_offspring(rng, &scales, &basis, |rng, candidate| {
+                evaluate_weights(
+                    rng,
+                    candidate,
+                    sim_length,
+                    n_weights,
+                    averaged,
+                    averaged_runs,
+                    aggregation,
+                    scoring,
+                    self.cache_moves,
+                )
+            });
+
+            let fitnesses: Vec<f64> = offspring.iter().map(|(_, _, f)| *f).collect();
+            let (best, mean, worst) = fitness_stats(&fitnesses);
+            self.verbosity.progress(
+                progress,
+                format_args!("Generation {generation}: best={best:.5}"),
+            );
+            if let Some(log) = log.as_mut() {
+                let _ = writeln!(log, "{generation},{best:.5},{mean:.5},{worst:.5}");
+            }
+
+            if best > best_fitness {
+                best_fitness = best;
+                best_weights = offspring[0].0;
+                no_improve = 0;
+            } else if early_stop_patience > 0 {
+                no_improve += 1;
+            }
+
+            // Recombination of the best half
+            let mut step_w = [0.0; N];
+            for ((_, step, _), &w) in offspring.iter().zip(&params.recombination) {
+                for (acc, &s) in step_w.iter_mut().zip(step) {
+                    *acc = w.mul_add(s, *acc);
+                }
+            }
+            for (m, &s) in self.mean.iter_mut().zip(&step_w) {
+                *m = self.sigma.mul_add(s, *m);
+            }
+
+            // Step-size path uses C^(-1/2) * step_w = B D^-1 B^T step_w
+            let rotated = mat_t_vec(&basis, &step_w);
+            let whitened = mat_vec(&basis, &std::array::from_fn(|i| rotated[i] / scales[i]));
+            let sigma_norm = (params.c_sigma * (2.0 - params.c_sigma) * params.mu_eff).sqrt();
+            for (p, &w) in path_sigma.iter_mut().zip(&whitened) {
+                *p = (1.0 - params.c_sigma).mul_add(*p, sigma_norm * w);
+            }
+            let path_sigma_len = norm(&path_sigma);
+            let decay = (1.0 - params.c_sigma).powf(2.0 * to_f64(generation + 1));
+            let path_short = path_sigma_len / (1.0 - decay).sqrt()
+                < (1.4 + 2.0 / (to_f64(N) + 1.0)) * params.chi_n;
+            let h_sigma = if path_short { 1.0 } else { 0.0 };
+
+            let c_norm = (params.c_c * (2.0 - params.c_c) * params.mu_eff).sqrt();
+            for (p, &s) in path_c.iter_mut().zip(&step_w) {
+                *p = (1.0 - params.c_c).mul_add(*p, h_sigma * c_norm * s);
+            }
+
+            // Rank-one and rank-mu covariance updates
+            let keep = 1.0 - params.c_1 - params.c_mu;
+            let correction = (1.0 - h_sigma) * params.c_c * (2.0 - params.c_c);
+            for (i, row) in self.covariance.iter_mut().enumerate() {
+                for (j, c) in row.iter_mut().enumerate() {
+                    let rank_one = path_c[i].mul_add(path_c[j], correction * *c);
+                    let rank_mu: f64 = offspring
+                        .iter()
+                        .zip(&params.recombination)
+                        .map(|((_, step, _), &w)| w * step[i] * step[j])
+                        .sum();
+                    *c = params
+                        .c_mu
+                        .mul_add(rank_mu, keep.mul_add(*c, params.c_1 * rank_one));
+                }
+            }
+
+            self.sigma *=
+                ((params.c_sigma / params.d_sigma) * (path_sigma_len / params.chi_n - 1.0)).exp();
+
+            if best_fitness >= early_stop_target {
+                break;
+            }
+            if early_stop_patience > 0 && no_improve >= early_stop_patience {
+                break;
+            }
+        }
+
+        OptimizeResult {
+            weights: best_weights,
+            best_score: best_fitness,
+            iterations: iterations_used,
+            spread: None,
+        }
+    }
+}
+
+/// Runs CMA-ES optimization and saves the best weights.
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be written.
+pub fn optimize_weights_cmaes(config: &CmaConfig, output: &Path) -> io::Result<OptimizeResult> {
+    optimize_weights_cmaes_with_seed(config, output, None, None)
+}
+
+/// Runs CMA-ES optimization with optional seed/logging.
+///
+/// # Errors
+///
+/// Returns an error if the weights file or log CSV cannot be written.
+pub fn optimize_weights_cmaes_with_seed(
+    config: &CmaConfig,
+    output: &Path,
+    seed: Option<u64>,
+    log_csv: Option<&Path>,
+) -> io::Result<OptimizeResult> {
+    seed.map_or_else(
+        || {
+            let mut rng = rand::rng();
+            optimize_weights_cmaes_with_rng(config, output, &mut rng, log_csv)
+        },
+        |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            optimize_weights_cmaes_with_rng(config, output, &mut rng, log_csv)
+        },
+    )
+}
+
+fn optimize_weights_cmaes_with_rng<R: Rng + ?Sized>(
+    config: &CmaConfig,
+    output: &Path,
+    rng: &mut R,
+    log_csv: Option<&Path>,
+) -> io::Result<OptimizeResult> {
+    let mut stdout = io::stdout();
+    let mut solver = CmaEsSearch::new(config.population, config.iterations, config.initial_sigma)
+        .with_move_cache(config.cache_moves)
+        .with_verbosity(config.verbosity);
+
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Starting CMA-ES optimization ({} generations, n_weights={}, averaged={})...",
+            config.iterations, config.n_weights, config.averaged,
+        ),
+    );
+
+    let mut log_writer = if let Some(path) = log_csv {
+        let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+        writeln!(file, "iteration,best,mean,worst")?;
+        Some(file)
+    } else {
+        None
+    };
+
+    let mut result = solver.optimize_with_rng(
+        config.sim_length,
+        config.n_weights,
+        config.averaged,
+        config.averaged_runs,
+        config.aggregation,
+        config.scoring,
+        config.early_stop_patience,
+        config.early_stop_target,
+        rng,
+        log_writer.as_mut().map(|writer| writer as &mut dyn Write),
+        &mut stdout,
+    );
+    if config.normalize {
+        weights::normalize(&mut result.weights);
+    }
+
+    println!(
+        "Best fitness: {:.5} (iterations: {})",
+        result.best_score, result.iterations
+    );
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
+            result.weights[0], result.weights[1], result.weights[2]
+        ),
+    );
+    result.spread = averaged_spread(
+        rng,
+        result.weights,
+        config.averaged.then_some(config.averaged_runs),
+        config.sim_length,
+        config.n_weights,
+        config.scoring,
+        config.verbosity,
+    );
+
+    weights::save_with_scoring(output, &result.weights, Some(config.scoring))?;
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!("Weights saved to {}", output.display()),
+    );
+
+    Ok(result)
+}
+
+/// `4 + floor(3 ln(n))`, found by counting the powers of `e` up to `n^3`.
+#[allow(clippy::cast_precision_loss, clippy::while_float)]
+const fn offspring_for(n: usize) -> usize {
+    let cube = n.pow(3) as f64;
+    let mut offspring = 4;
+    let mut power = std::f64::consts::E;
+    while power <= cube {
+        offspring += 1;
+        power *= std::f64::consts::E;
+    }
+    offspring
+}
+
+fn to_f64(n: usize) -> f64 {
+    f64::from(u32::try_from(n).unwrap_or(u32::MAX))
+}
+
+fn identity() -> Matrix {
+    std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }))
+}
+
+fn norm(v: &Vector) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Computes `m * v`.
+fn mat_vec(m: &Matrix, v: &Vector) -> Vector {
+    std::array::from_fn(|i| m[i].iter().zip(v).map(|(a, b)| a * b).sum())
+}
+
+/// Computes `m^T * v`.
+fn mat_t_vec(m: &Matrix, v: &Vector) -> Vector {
+    std::array::from_fn(|j| m.iter().zip(v).map(|(row, b)| row[j] * b).sum())
+}
+
+/// Eigen-decomposes a symmetric matrix with cyclic Jacobi rotations.
+///
+/// Returns the eigenvalues and a matrix whose columns are the matching
+/// unit eigenvectors.
+fn symmetric_eigen(matrix: &Matrix) -> (Vector, Matrix) {
+    const MAX_SWEEPS: usize = 50;
+    const TOLERANCE: f64 = 1e-22;
+
+    let mut a = *matrix;
+    let mut vectors = identity();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..N)
+            .flat_map(|p| ((p + 1)..N).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        if off_diagonal < TOLERANCE {
+            break;
+        }
+
+        for p in 0..N {
+            for q in (p + 1)..N {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotation angle that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + theta.mul_add(theta, 1.0).sqrt());
+                let cos = 1.0 / t.mul_add(t, 1.0).sqrt();
+                let sin = t * cos;
+
+                for row in &mut a {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = cos.mul_add(kp, -sin * kq);
+                    row[q] = sin.mul_add(kp, cos * kq);
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                for k in 0..N {
+                    a[p][k] = cos.mul_add(row_p[k], -sin * row_q[k]);
+                    a[q][k] = sin.mul_add(row_p[k], cos * row_q[k]);
+                }
+                for row in &mut vectors {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = cos.mul_add(kp, -sin * kq);
+                    row[q] = sin.mul_add(kp, cos * kq);
+                }
+            }
+        }
+    }
+
+    (std::array::from_fn(|i| a[i][i]), vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(seed: u64, iterations: usize) -> OptimizeResult {
+        let mut solver = CmaEsSearch::new(4, iterations, CmaConfig::DEFAULT_INITIAL_SIGMA);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        solver.optimize_with_rng(
+            40,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Rows,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            None,
+            &mut io::sink(),
+        )
+    }
+
+    #[test]
+    fn default_population_follows_weight_dimension() {
+        assert_eq!(offspring_for(16), 12);
+        assert_eq!(offspring_for(27), 13);
+        assert_eq!(offspring_for(100), 17);
+    }
+
+    #[test]
+    fn cmaes_is_reproducible_with_seed() {
+        let a = run(42, 2);
+        let b = run(42, 2);
+        assert_eq!(a.weights.map(f64::to_bits), b.weights.map(f64::to_bits));
+        assert!((a.best_score - b.best_score).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cmaes_beats_random_baseline() {
+        // Survival scoring still ranks weights that clear no rows, so short
+        // games give CMA-ES a slope to follow instead of a flat zero
+        const GENERATIONS: usize = 6;
+        const POPULATION: usize = 4;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let samples = GENERATIONS * POPULATION;
+        #[allow(clippy::cast_precision_loss)]
+        let baseline: f64 = (0..samples)
+            .map(|_| {
+                let weights: Vector = std::array::from_fn(|_| rng.random_range(-1.0..=1.0));
+                evaluate_weights(
+                    &mut rng,
+                    weights,
+                    40,
+                    N,
+                    false,
+                    1,
+                    Aggregation::Mean,
+                    ScoringMode::Survival,
+                    false,
+                )
+            })
+            .sum::<f64>()
+            / samples as f64;
+
+        let mut solver =
+            CmaEsSearch::new(POPULATION, GENERATIONS, CmaConfig::DEFAULT_INITIAL_SIGMA);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let result = solver.optimize_with_rng(
+            40,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Survival,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            None,
+            &mut io::sink(),
+        );
+        assert!(
+            result.best_score > baseline,
+            "CMA-ES best {} not above random mean {baseline}",
+            result.best_score
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen_reconstructs_matrix() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let factor: Matrix = std::array::from_fn(|_| std::array::from_fn(|_| rng.random()));
+        // factor * factor^T is symmetric positive semi-definite
+        let matrix: Matrix = std::array::from_fn(|i| {
+            std::array::from_fn(|j| factor[i].iter().zip(&factor[j]).map(|(a, b)| a * b).sum())
+        });
+
+        let (values, vectors) = symmetric_eigen(&matrix);
+        for i in 0..N {
+            for j in 0..N {
+                let rebuilt: f64 = (0..N)
+                    .map(|k| vectors[i][k] * values[k] * vectors[j][k])
+                    .sum();
+                assert!((rebuilt - matrix[i][j]).abs() < 1e-8, "({i}, {j})");
+            }
+        }
+    }
+}
@@ -1,27 +1,82 @@
 use std::io::{self, Write};
 use std::path::Path;
+use std::str::FromStr;
 
 use rand::Rng;
 use rand::SeedableRng;
 use rand_distr::{Distribution, Normal};
 
-use crate::agent::simulator::Simulator;
+use super::aggregation::Aggregation;
+use super::plateau::PlateauStop;
+use super::search::{averaged_spread, evaluate_weights, log_rank_weights};
+use super::verbosity::Verbosity;
+use crate::agent::simulator::ScoringMode;
+use crate::stats::SampleSummary;
 use crate::weights;
 
+/// How elite samples are weighted when refitting the sampling distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EliteWeighting {
+    /// Every elite counts equally.
+    #[default]
+    Uniform,
+    /// Better elites count more, with log-rank weights as in CMA-ES recombination.
+    LogRank,
+}
+
+impl EliteWeighting {
+    /// Normalized weights for `n` elites, best first. Sums to 1.
+    #[must_use]
+    pub fn weights(self, n: usize) -> Vec<f64> {
+        match self {
+            Self::Uniform => {
+                let share = 1.0 / f64::from(u32::try_from(n).unwrap_or(u32::MAX));
+                vec![share; n]
+            }
+            Self::LogRank => log_rank_weights(n),
+        }
+    }
+}
+
+impl FromStr for EliteWeighting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uniform" => Ok(Self::Uniform),
+            "log-rank" => Ok(Self::LogRank),
+            other => Err(format!(
+                "unknown elite weighting '{other}': expected uniform or log-rank"
+            )),
+        }
+    }
+}
+
 /// Configuration for a Cross-Entropy Search optimization run.
 #[derive(Debug, Clone)]
 pub struct CeConfig {
     pub n_samples: usize,
     pub n_elite: usize,
+    pub elite_weighting: EliteWeighting,
     pub iterations: usize,
     pub sim_length: usize,
     pub n_weights: usize,
     pub averaged: bool,
+    pub cache_moves: bool,
+    pub verbosity: Verbosity,
     pub averaged_runs: usize,
+    pub aggregation: Aggregation,
+    pub scoring: ScoringMode,
+    pub normalize: bool,
     pub initial_std_dev: f64,
     pub std_dev_floor: f64,
+    pub restart_patience: usize,
+    pub max_restarts: usize,
     pub early_stop_patience: usize,
     pub early_stop_target: f64,
+    /// Window of the rolling-mean stop rule (0 = off, see [`PlateauStop`]).
+    pub rel_improve_window: usize,
+    pub rel_improve_threshold: f64,
 }
 
 impl CeConfig {
@@ -34,6 +89,81 @@ impl CeConfig {
     pub const DEFAULT_INITIAL_STD_DEV: f64 = 10.0;
     pub const DEFAULT_STD_DEV_FLOOR: f64 = 0.01;
     pub const DEFAULT_EARLY_STOP_TARGET: f64 = f64::INFINITY;
+    pub const DEFAULT_REL_IMPROVE_THRESHOLD: f64 = 0.001;
+    pub const DEFAULT_MAX_RESTARTS: usize = 3;
+
+    /// Games a full run simulates without early stopping or restarts:
+    /// every sample of every iteration, plus the final spread report when averaged.
+    #[must_use]
+    pub const fn estimated_games(&self) -> usize {
+        let runs = if self.averaged { self.averaged_runs } else { 1 };
+        let spread = if self.averaged { self.averaged_runs } else { 0 };
+        self.iterations * self.n_samples * runs + spread
+    }
+
+    /// Parameters `benchmark --sweep` varies with CES instead of HSA.
+    pub const SWEEP_PARAMS: [&'static str; 3] = ["n-samples", "n-elite", "initial-std-dev"];
+
+    /// Builds a solver with this config's search settings.
+    #[must_use]
+    pub fn solver(&self) -> CrossEntropySearch {
+        CrossEntropySearch::new(
+            self.n_samples,
+            self.n_elite,
+            self.iterations,
+            self.initial_std_dev,
+        )
+        .with_elite_weighting(self.elite_weighting)
+        .with_restarts(self.restart_patience, self.max_restarts)
+        .with_plateau_stop(self.rel_improve_window, self.rel_improve_threshold)
+        .with_move_cache(self.cache_moves)
+        .with_verbosity(self.verbosity)
+    }
+
+    /// Builds the labeled configurations a sweep of `param` visits, varying
+    /// one of [`CeConfig::SWEEP_PARAMS`] from this config.
+    ///
+    /// Returns `None` for any other parameter. Sweeping `n-samples` lowers
+    /// `n_elite` where it would exceed the sample count.
+    #[must_use]
+    pub fn sweep_configs(&self, param: &str) -> Option<Vec<(String, Self)>> {
+        let configs = match param {
+            "n-samples" => (20..=100)
+                .step_by(20)
+                .map(|v| {
+                    let config = Self {
+                        n_samples: v,
+                        n_elite: self.n_elite.min(v),
+                        ..self.clone()
+                    };
+                    (format!("{v}"), config)
+                })
+                .collect(),
+            "n-elite" => [2, 5, 10, 15, 20, 25]
+                .into_iter()
+                .filter(|&v| v <= self.n_samples)
+                .map(|v| {
+                    let config = Self {
+                        n_elite: v,
+                        ..self.clone()
+                    };
+                    (format!("{v}"), config)
+                })
+                .collect(),
+            "initial-std-dev" => [0.5, 1.0, 2.0, 5.0, 10.0, 20.0]
+                .into_iter()
+                .map(|v| {
+                    let config = Self {
+                        initial_std_dev: v,
+                        ..self.clone()
+                    };
+                    (format!("{v}"), config)
+                })
+                .collect(),
+            _ => return None,
+        };
+        Some(configs)
+    }
 
     /// Returns a usage string describing CE-specific options.
     #[must_use]
@@ -43,15 +173,23 @@ impl CeConfig {
 Cross-Entropy Search options:
   --n-samples <N>       Candidate samples per iteration [default: {}]
   --n-elite <N>         Elite samples for distribution  [default: {}]
+  --elite-weighting <W> Elite weights: uniform, log-rank [default: uniform]
   --iterations <N>      Number of CES iterations        [default: {}]
   --sim-length <N>      Pieces per simulation game      [default: {}]
   --n-weights <N>       Number of eval functions         [default: {}]
   --averaged            Average fitness over multiple runs
   --averaged-runs <N>   Runs per averaged evaluation    [default: {}]
+  --cache-moves         Share move searches across averaged runs
+  --aggregation <AGG>   Averaged statistic: mean, median, min, trimmed-mean [default: mean]
+  --scoring <MODE>      Game fitness: rows, survival    [default: rows]
   --initial-std-dev <F> Initial standard deviation      [default: {}]
   --std-dev-floor <F>   Minimum standard deviation      [default: {}]
+  --restart-patience <N> Reset the distribution after N iterations without improvement
+  --max-restarts <N>    Maximum restarts per run        [default: {}]
   --early-stop-patience <N> Stop after N iterations without improvement
-  --early-stop-target <F>   Stop once best fitness >= target [default: {}]",
+  --early-stop-target <F>   Stop once best fitness >= target [default: {}]
+  --rel-improve-window <N>  Stop when the mean best over N iterations stalls
+  --rel-improve-threshold <F> Minimum relative gain per window [default: {}]",
             Self::DEFAULT_N_SAMPLES,
             Self::DEFAULT_N_ELITE,
             Self::DEFAULT_ITERATIONS,
@@ -60,7 +198,9 @@ Cross-Entropy Search options:
             Self::DEFAULT_AVERAGED_RUNS,
             Self::DEFAULT_INITIAL_STD_DEV,
             Self::DEFAULT_STD_DEV_FLOOR,
+            Self::DEFAULT_MAX_RESTARTS,
             Self::DEFAULT_EARLY_STOP_TARGET,
+            Self::DEFAULT_REL_IMPROVE_THRESHOLD,
         )
     }
 }
@@ -70,15 +210,25 @@ impl Default for CeConfig {
         Self {
             n_samples: Self::DEFAULT_N_SAMPLES,
             n_elite: Self::DEFAULT_N_ELITE,
+            elite_weighting: EliteWeighting::Uniform,
             iterations: Self::DEFAULT_ITERATIONS,
             sim_length: Self::DEFAULT_SIM_LENGTH,
             n_weights: Self::DEFAULT_N_WEIGHTS,
             averaged: false,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
             averaged_runs: Self::DEFAULT_AVERAGED_RUNS,
+            aggregation: Aggregation::Mean,
+            scoring: ScoringMode::Rows,
+            normalize: false,
             initial_std_dev: Self::DEFAULT_INITIAL_STD_DEV,
             std_dev_floor: Self::DEFAULT_STD_DEV_FLOOR,
+            restart_patience: 0,
+            max_restarts: Self::DEFAULT_MAX_RESTARTS,
             early_stop_patience: 0,
             early_stop_target: Self::DEFAULT_EARLY_STOP_TARGET,
+            rel_improve_window: 0,
+            rel_improve_threshold: Self::DEFAULT_REL_IMPROVE_THRESHOLD,
         }
     }
 }
@@ -87,9 +237,21 @@ impl Default for CeConfig {
 pub struct CrossEntropySearch {
     pub n_samples: usize,
     pub n_elite: usize,
+    pub elite_weighting: EliteWeighting,
     pub max_iter: usize,
     pub means: [f64; weights::NUM_WEIGHTS],
     pub std_devs: [f64; weights::NUM_WEIGHTS],
+    pub initial_std_dev: f64,
+    /// Stagnant iterations before the distribution is reset (0 = never).
+    pub restart_patience: usize,
+    pub max_restarts: usize,
+    /// Window of the rolling-mean stop rule (0 = off, see [`PlateauStop`]).
+    pub plateau_window: usize,
+    pub plateau_threshold: f64,
+    /// Share a [`MoveCache`](crate::agent::simulator::MoveCache) between the games of an averaged evaluation.
+    pub cache_moves: bool,
+    /// How much progress to print.
+    pub verbosity: Verbosity,
 }
 
 impl CrossEntropySearch {
@@ -108,12 +270,62 @@ impl CrossEntropySearch {
         Self {
             n_samples,
             n_elite,
+            elite_weighting: EliteWeighting::Uniform,
             max_iter,
             means: [0.0; weights::NUM_WEIGHTS],
             std_devs: [initial_std_dev; weights::NUM_WEIGHTS],
+            initial_std_dev,
+            restart_patience: 0,
+            max_restarts: 0,
+            plateau_window: 0,
+            plateau_threshold: 0.0,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
         }
     }
 
+    /// Shares found moves between the games of an averaged evaluation.
+    /// Fitness is unchanged; only repeated searches are skipped.
+    #[must_use]
+    pub const fn with_move_cache(mut self, cache_moves: bool) -> Self {
+        self.cache_moves = cache_moves;
+        self
+    }
+
+    /// Sets how elite samples are weighted when refitting the distribution.
+    #[must_use]
+    pub const fn with_elite_weighting(mut self, elite_weighting: EliteWeighting) -> Self {
+        self.elite_weighting = elite_weighting;
+        self
+    }
+
+    /// Stops once the mean best fitness over `window` iterations gains less
+    /// than `threshold` (relative) on the window before it. A `window` of
+    /// zero disables the rule.
+    #[must_use]
+    pub const fn with_plateau_stop(mut self, window: usize, threshold: f64) -> Self {
+        self.plateau_window = window;
+        self.plateau_threshold = threshold;
+        self
+    }
+
+    /// Sets how much progress is printed while optimizing.
+    #[must_use]
+    pub const fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Resets the sampling distribution around the best weights after
+    /// `patience` iterations without improvement, at most `max_restarts`
+    /// times. A `patience` of zero disables restarts.
+    #[must_use]
+    pub const fn with_restarts(mut self, patience: usize, max_restarts: usize) -> Self {
+        self.restart_patience = patience;
+        self.max_restarts = max_restarts;
+        self
+    }
+
     /// Runs the Cross-Entropy Search optimization loop.
     ///
     /// Returns the best weights found and their fitness score.
@@ -127,16 +339,21 @@ impl CrossEntropySearch {
         n_weights: usize,
         averaged: bool,
         averaged_runs: usize,
+        aggregation: Aggregation,
+        scoring: ScoringMode,
         std_dev_floor: f64,
         early_stop_patience: usize,
         early_stop_target: f64,
         rng: &mut R,
         mut log: Option<&mut dyn Write>,
+        progress: &mut dyn Write,
     ) -> CeOptimizeResult {
         let mut best_weights = [0.0; weights::NUM_WEIGHTS];
         let mut best_fitness = f64::NEG_INFINITY;
         let mut no_improve = 0usize;
+        let mut restarts = 0usize;
         let mut iterations_used = 0usize;
+        let mut plateau = PlateauStop::new(self.plateau_window, self.plateau_threshold);
 
         for iteration in 0..self.max_iter {
             iterations_used = iteration + 1;
@@ -159,8 +376,17 @@ impl CrossEntropySearch {
                 for (w, normal) in weights.iter_mut().zip(normals.iter()) {
                     *w = normal.sample(rng);
                 }
-                let fitness =
-                    evaluate_weights(rng, weights, sim_length, n_weights, averaged, averaged_runs);
+                let fitness = evaluate_weights(
+                    rng,
+                    weights,
+                    sim_length,
+                    n_weights,
+                    averaged,
+                    averaged_runs,
+                    aggregation,
+                    scoring,
+                    self.cache_moves,
+                );
                 candidates.push((weights, fitness));
             }
 
@@ -172,27 +398,16 @@ impl CrossEntropySearch {
                 best_fitness = candidates[0].1;
                 best_weights = candidates[0].0;
                 no_improve = 0;
-            } else if early_stop_patience > 0 {
+            } else {
                 no_improve += 1;
             }
 
-            println!("Iteration {iteration}: best={best_fitness:.5}");
-
-            // Update distribution from elite samples
-            let elite = &candidates[..self.n_elite];
-            let n_elite_f = f64::from(u32::try_from(self.n_elite).unwrap_or(u32::MAX));
+            self.verbosity.progress(
+                progress,
+                format_args!("Iteration {iteration}: best={best_fitness:.5}"),
+            );
 
-            for i in 0..weights::NUM_WEIGHTS {
-                let mean = elite.iter().map(|(w, _)| w[i]).sum::<f64>() / n_elite_f;
-                let var = elite
-                    .iter()
-                    .map(|(w, _)| (w[i] - mean).powi(2))
-                    .sum::<f64>()
-                    / n_elite_f;
-
-                self.means[i] = mean;
-                self.std_devs[i] = var.sqrt().max(std_dev_floor);
-            }
+            self.update_distribution(&candidates[..self.n_elite], std_dev_floor);
 
             if let Some(log) = log.as_mut() {
                 let (best, mean, worst) = fitness_stats(&candidates);
@@ -205,12 +420,57 @@ impl CrossEntropySearch {
             if early_stop_patience > 0 && no_improve >= early_stop_patience {
                 break;
             }
+            if plateau.record(best_fitness) {
+                break;
+            }
+            if self.restart_patience > 0
+                && no_improve >= self.restart_patience
+                && restarts < self.max_restarts
+            {
+                restarts += 1;
+                self.verbosity.progress(
+                    progress,
+                    format_args!(
+                        "Restart {restarts}: resetting distribution after {no_improve} stagnant iterations"
+                    ),
+                );
+                self.means = best_weights;
+                self.std_devs = [self.initial_std_dev; weights::NUM_WEIGHTS];
+                no_improve = 0;
+                plateau.reset();
+            }
         }
 
         CeOptimizeResult {
             weights: best_weights,
             best_score: best_fitness,
             iterations: iterations_used,
+            spread: None,
+        }
+    }
+
+    /// Refits the means and standard deviations to `elite`, sorted best first.
+    fn update_distribution(
+        &mut self,
+        elite: &[([f64; weights::NUM_WEIGHTS], f64)],
+        std_dev_floor: f64,
+    ) {
+        let elite_weights = self.elite_weighting.weights(elite.len());
+
+        for i in 0..weights::NUM_WEIGHTS {
+            let mean = elite
+                .iter()
+                .zip(&elite_weights)
+                .map(|((w, _), &ew)| ew * w[i])
+                .sum::<f64>();
+            let var = elite
+                .iter()
+                .zip(&elite_weights)
+                .map(|((w, _), &ew)| ew * (w[i] - mean).powi(2))
+                .sum::<f64>();
+
+            self.means[i] = mean;
+            self.std_devs[i] = var.sqrt().max(std_dev_floor);
         }
     }
 }
@@ -253,16 +513,15 @@ fn optimize_weights_ce_with_rng<R: Rng + ?Sized>(
     rng: &mut R,
     log_csv: Option<&Path>,
 ) -> io::Result<CeOptimizeResult> {
-    let mut solver = CrossEntropySearch::new(
-        config.n_samples,
-        config.n_elite,
-        config.iterations,
-        config.initial_std_dev,
-    );
-
-    println!(
-        "Starting CES optimization ({} iterations, n_weights={}, averaged={})...",
-        config.iterations, config.n_weights, config.averaged,
+    let mut stdout = io::stdout();
+    let mut solver = config.solver();
+
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Starting CES optimization ({} iterations, n_weights={}, averaged={})...",
+            config.iterations, config.n_weights, config.averaged,
+        ),
     );
 
     let mut log_writer = if let Some(path) = log_csv {
@@ -273,29 +532,50 @@ fn optimize_weights_ce_with_rng<R: Rng + ?Sized>(
         None
     };
 
-    let result = solver.optimize_with_rng(
+    let mut result = solver.optimize_with_rng(
         config.sim_length,
         config.n_weights,
         config.averaged,
         config.averaged_runs,
+        config.aggregation,
+        config.scoring,
         config.std_dev_floor,
         config.early_stop_patience,
         config.early_stop_target,
         rng,
         log_writer.as_mut().map(|writer| writer as &mut dyn Write),
+        &mut stdout,
     );
+    if config.normalize {
+        weights::normalize(&mut result.weights);
+    }
 
     println!(
         "Best fitness: {:.5} (iterations: {})",
         result.best_score, result.iterations
     );
-    println!(
-        "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
-        result.weights[0], result.weights[1], result.weights[2]
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
+            result.weights[0], result.weights[1], result.weights[2]
+        ),
+    );
+    result.spread = averaged_spread(
+        rng,
+        result.weights,
+        config.averaged.then_some(config.averaged_runs),
+        config.sim_length,
+        config.n_weights,
+        config.scoring,
+        config.verbosity,
     );
 
-    weights::save(output, &result.weights)?;
-    println!("Weights saved to {}", output.display());
+    weights::save_with_scoring(output, &result.weights, Some(config.scoring))?;
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!("Weights saved to {}", output.display()),
+    );
 
     Ok(result)
 }
@@ -305,6 +585,8 @@ pub struct CeOptimizeResult {
     pub weights: [f64; weights::NUM_WEIGHTS],
     pub best_score: f64,
     pub iterations: usize,
+    /// Per-run spread of the best weights, when fitness is averaged.
+    pub spread: Option<SampleSummary>,
 }
 
 fn fitness_stats(candidates: &[([f64; weights::NUM_WEIGHTS], f64)]) -> (f64, f64, f64) {
@@ -326,24 +608,122 @@ fn fitness_stats(candidates: &[([f64; weights::NUM_WEIGHTS], f64)]) -> (f64, f64
     (best, mean, worst)
 }
 
-fn evaluate_weights<R: Rng + ?Sized>(
-    rng: &mut R,
-    weights: [f64; weights::NUM_WEIGHTS],
-    sim_length: usize,
-    n_weights: usize,
-    averaged: bool,
-    averaged_runs: usize,
-) -> f64 {
-    if averaged {
-        let total: f64 = (0..averaged_runs)
-            .map(|_| {
-                let sim = Simulator::new(weights, sim_length).with_n_weights(n_weights);
-                f64::from(sim.simulate_game_with_rng(rng))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn n_elite_sweep_keeps_other_settings() {
+        let base = CeConfig {
+            n_samples: 12,
+            sim_length: 40,
+            ..CeConfig::default()
+        };
+        let configs = base.sweep_configs("n-elite").expect("CES parameter");
+        let labels: Vec<&str> = configs.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["2", "5", "10"]);
+        for (label, config) in &configs {
+            assert_eq!(config.n_elite.to_string(), *label);
+            assert_eq!((config.n_samples, config.sim_length), (12, 40));
+        }
+        assert!(base.sweep_configs("bandwidth").is_none());
+    }
+
+    /// Elites sorted best first, with weight 0 falling from 10 to 0.
+    fn elite() -> Vec<([f64; weights::NUM_WEIGHTS], f64)> {
+        [10.0, 7.5, 5.0, 2.5, 0.0]
+            .into_iter()
+            .map(|value| {
+                let mut w = [0.0; weights::NUM_WEIGHTS];
+                w[0] = value;
+                (w, value)
             })
-            .sum();
-        total / f64::from(u32::try_from(averaged_runs).unwrap_or(u32::MAX))
-    } else {
-        let sim = Simulator::new(weights, sim_length).with_n_weights(n_weights);
-        f64::from(sim.simulate_game_with_rng(rng))
+            .collect()
+    }
+
+    fn fitted_mean(elite_weighting: EliteWeighting) -> f64 {
+        let mut search =
+            CrossEntropySearch::new(5, 5, 1, 1.0).with_elite_weighting(elite_weighting);
+        search.update_distribution(&elite(), 0.0);
+        search.means[0]
+    }
+
+    #[test]
+    fn elite_weights_sum_to_one_and_favor_the_best() {
+        for weighting in [EliteWeighting::Uniform, EliteWeighting::LogRank] {
+            let weights = weighting.weights(10);
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            assert!(weights.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+        let log_rank = EliteWeighting::LogRank.weights(10);
+        assert!(log_rank[0] > log_rank[9] && log_rank[9] > 0.0);
+    }
+
+    #[test]
+    fn log_rank_mean_leans_toward_the_top_elite() {
+        let uniform = fitted_mean(EliteWeighting::Uniform);
+        let log_rank = fitted_mean(EliteWeighting::LogRank);
+
+        assert!((uniform - 5.0).abs() < 1e-12);
+        assert!(log_rank > uniform + 1.0, "log-rank mean {log_rank}");
+        assert!(log_rank < 10.0);
+    }
+
+    /// Runs two iterations of one-piece games, which never clear a row, so
+    /// the second iteration cannot improve on the first.
+    fn run_stagnant(search: &mut CrossEntropySearch) -> CeOptimizeResult {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        search.optimize_with_rng(
+            1,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Rows,
+            0.0,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            None,
+            &mut io::sink(),
+        )
+    }
+
+    #[test]
+    fn restart_resets_distribution_around_best_weights() {
+        let mut search = CrossEntropySearch::new(8, 2, 2, 2.0).with_verbosity(Verbosity::Quiet);
+        run_stagnant(&mut search);
+        assert!(
+            search
+                .std_devs
+                .iter()
+                .any(|&s| (s - 2.0).abs() > f64::EPSILON)
+        );
+
+        let mut search = CrossEntropySearch::new(8, 2, 2, 2.0)
+            .with_restarts(1, 1)
+            .with_verbosity(Verbosity::Quiet);
+        let result = run_stagnant(&mut search);
+        assert_eq!(result.iterations, 2);
+        assert!(
+            search
+                .std_devs
+                .iter()
+                .all(|&s| (s - search.initial_std_dev).abs() < f64::EPSILON)
+        );
+        assert!(
+            search
+                .means
+                .iter()
+                .zip(result.weights.iter())
+                .all(|(m, w)| (m - w).abs() < f64::EPSILON)
+        );
+    }
+
+    #[test]
+    fn elite_weighting_parses() {
+        assert_eq!("uniform".parse(), Ok(EliteWeighting::Uniform));
+        assert_eq!("log-rank".parse(), Ok(EliteWeighting::LogRank));
+        assert!("rank".parse::<EliteWeighting>().is_err());
     }
 }
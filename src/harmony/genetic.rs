@@ -0,0 +1,536 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+
+use super::aggregation::Aggregation;
+use super::bounds::Bounds;
+use super::search::{OptimizeResult, averaged_spread, evaluate_weights, fitness_stats};
+use super::verbosity::Verbosity;
+use crate::agent::simulator::ScoringMode;
+use crate::weights;
+
+/// Configuration for a Genetic Algorithm optimization run.
+#[derive(Debug, Clone)]
+pub struct GaConfig {
+    pub population: usize,
+    pub iterations: usize,
+    pub mutation_rate: f64,
+    pub mutation_std_dev: f64,
+    pub crossover_rate: f64,
+    pub elitism: usize,
+    pub tournament_size: usize,
+    pub sim_length: usize,
+    pub bounds: Bounds,
+    pub n_weights: usize,
+    pub averaged: bool,
+    pub cache_moves: bool,
+    pub verbosity: Verbosity,
+    pub averaged_runs: usize,
+    pub aggregation: Aggregation,
+    pub scoring: ScoringMode,
+    pub normalize: bool,
+    pub early_stop_patience: usize,
+    pub early_stop_target: f64,
+}
+
+impl GaConfig {
+    pub const DEFAULT_POPULATION: usize = 20;
+    pub const DEFAULT_ITERATIONS: usize = 100;
+    pub const DEFAULT_MUTATION_RATE: f64 = 0.1;
+    pub const DEFAULT_MUTATION_STD_DEV: f64 = 0.2;
+    pub const DEFAULT_CROSSOVER_RATE: f64 = 0.9;
+    pub const DEFAULT_ELITISM: usize = 2;
+    pub const DEFAULT_TOURNAMENT_SIZE: usize = 3;
+    pub const DEFAULT_SIM_LENGTH: usize = 1000;
+    pub const DEFAULT_BOUNDS: Bounds = Bounds::uniform(-1.0, 1.0);
+    pub const DEFAULT_N_WEIGHTS: usize = weights::NUM_WEIGHTS;
+    pub const DEFAULT_AVERAGED_RUNS: usize = 20;
+    pub const DEFAULT_EARLY_STOP_TARGET: f64 = f64::INFINITY;
+
+    /// Games a full run simulates without early stopping or restarts:
+    /// the initial population and every non-elite child, plus the final spread report when averaged.
+    #[must_use]
+    pub const fn estimated_games(&self) -> usize {
+        let runs = if self.averaged { self.averaged_runs } else { 1 };
+        let spread = if self.averaged { self.averaged_runs } else { 0 };
+        (self.population + self.iterations * self.population.saturating_sub(self.elitism)) * runs
+            + spread
+    }
+
+    /// Returns a usage string describing GA-specific options.
+    #[must_use]
+    pub fn usage() -> String {
+        format!(
+            "\
+Genetic Algorithm options:
+  --population <N>      Individuals per generation      [default: {}]
+  --iterations <N>      Number of generations           [default: {}]
+  --mutation-rate <F>   Per-gene mutation probability   [default: {}]
+  --mutation-std-dev <F> Gaussian mutation std dev      [default: {}]
+  --crossover-rate <F>  Uniform crossover probability   [default: {}]
+  --elitism <N>         Best individuals kept per gen   [default: {}]
+  --tournament-size <N> Tournament selection size       [default: {}]
+  --sim-length <N>      Pieces per simulation game      [default: {}]
+  --n-weights <N>       Number of eval functions         [default: {}]
+  --averaged            Average fitness over multiple runs
+  --averaged-runs <N>   Runs per averaged evaluation    [default: {}]
+  --cache-moves         Share move searches across averaged runs
+  --aggregation <AGG>   Averaged statistic: mean, median, min, trimmed-mean [default: mean]
+  --scoring <MODE>      Game fitness: rows, survival    [default: rows]
+  --early-stop-patience <N> Stop after N iterations without improvement
+  --early-stop-target <F>   Stop once best fitness >= target [default: {}]",
+            Self::DEFAULT_POPULATION,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_MUTATION_RATE,
+            Self::DEFAULT_MUTATION_STD_DEV,
+            Self::DEFAULT_CROSSOVER_RATE,
+            Self::DEFAULT_ELITISM,
+            Self::DEFAULT_TOURNAMENT_SIZE,
+            Self::DEFAULT_SIM_LENGTH,
+            Self::DEFAULT_N_WEIGHTS,
+            Self::DEFAULT_AVERAGED_RUNS,
+            Self::DEFAULT_EARLY_STOP_TARGET,
+        )
+    }
+}
+
+impl Default for GaConfig {
+    fn default() -> Self {
+        Self {
+            population: Self::DEFAULT_POPULATION,
+            iterations: Self::DEFAULT_ITERATIONS,
+            mutation_rate: Self::DEFAULT_MUTATION_RATE,
+            mutation_std_dev: Self::DEFAULT_MUTATION_STD_DEV,
+            crossover_rate: Self::DEFAULT_CROSSOVER_RATE,
+            elitism: Self::DEFAULT_ELITISM,
+            tournament_size: Self::DEFAULT_TOURNAMENT_SIZE,
+            sim_length: Self::DEFAULT_SIM_LENGTH,
+            bounds: Self::DEFAULT_BOUNDS,
+            n_weights: Self::DEFAULT_N_WEIGHTS,
+            averaged: false,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
+            averaged_runs: Self::DEFAULT_AVERAGED_RUNS,
+            aggregation: Aggregation::Mean,
+            scoring: ScoringMode::Rows,
+            normalize: false,
+            early_stop_patience: 0,
+            early_stop_target: Self::DEFAULT_EARLY_STOP_TARGET,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GeneticAlgorithm {
+    pub population_size: usize,
+    pub max_iter: usize,
+    pub mutation_rate: f64,
+    pub mutation_std_dev: f64,
+    pub crossover_rate: f64,
+    pub elitism: usize,
+    pub tournament_size: usize,
+    pub population: Vec<[f64; weights::NUM_WEIGHTS]>,
+    pub fitness: Vec<f64>,
+    /// Share a [`MoveCache`](crate::agent::simulator::MoveCache) between the games of an averaged evaluation.
+    pub cache_moves: bool,
+    /// How much progress to print.
+    pub verbosity: Verbosity,
+}
+
+impl GeneticAlgorithm {
+    /// Creates a new [`GeneticAlgorithm`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `population_size` or `tournament_size` is zero, if `elitism`
+    /// exceeds `population_size`, or if `mutation_rate` or `crossover_rate`
+    /// are not in the range [0, 1].
+    #[must_use]
+    pub fn new(
+        population_size: usize,
+        max_iter: usize,
+        mutation_rate: f64,
+        mutation_std_dev: f64,
+        crossover_rate: f64,
+        elitism: usize,
+        tournament_size: usize,
+    ) -> Self {
+        assert!(population_size > 0, "Population size must be > 0");
+        assert!(tournament_size > 0, "Tournament size must be > 0");
+        assert!(
+            elitism <= population_size,
+            "elitism ({elitism}) must be <= population size ({population_size})"
+        );
+        assert!(
+            (0.0..=1.0).contains(&mutation_rate),
+            "Mutation rate must be in [0, 1]"
+        );
+        assert!(
+            (0.0..=1.0).contains(&crossover_rate),
+            "Crossover rate must be in [0, 1]"
+        );
+        Self {
+            population_size,
+            max_iter,
+            mutation_rate,
+            mutation_std_dev,
+            crossover_rate,
+            elitism,
+            tournament_size,
+            population: Vec::with_capacity(population_size),
+            fitness: Vec::with_capacity(population_size),
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
+        }
+    }
+
+    /// Shares found moves between the games of an averaged evaluation.
+    /// Fitness is unchanged; only repeated searches are skipped.
+    #[must_use]
+    pub const fn with_move_cache(mut self, cache_moves: bool) -> Self {
+        self.cache_moves = cache_moves;
+        self
+    }
+
+    /// Sets how much progress is printed while optimizing.
+    #[must_use]
+    pub const fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Runs the Genetic Algorithm optimization loop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mutation_std_dev` is negative or not finite.
+    pub fn optimize_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        sim_length: usize,
+        bounds: Bounds,
+        n_weights: usize,
+        averaged: bool,
+        averaged_runs: usize,
+        aggregation: Aggregation,
+        scoring: ScoringMode,
+        early_stop_patience: usize,
+        early_stop_target: f64,
+        rng: &mut R,
+        mut log: Option<&mut dyn Write>,
+        progress: &mut dyn Write,
+    ) -> OptimizeResult {
+        let mutation = Normal::new(0.0, self.mutation_std_dev)
+            .expect("Mutation std dev must be finite and >= 0");
+        let mut best_fitness = f64::NEG_INFINITY;
+        let mut no_improve = 0usize;
+        let mut iterations_used = 0usize;
+
+        self.population.clear();
+        self.fitness.clear();
+
+        // Initialization
+        for _ in 0..self.population_size {
+            let mut individual = [0.0; weights::NUM_WEIGHTS];
+            for (i, gene) in individual.iter_mut().enumerate() {
+                let (min_bound, max_bound) = bounds.get(i);
+                *gene = rng.random_range(min_bound..=max_bound);
+            }
+            self.population.push(individual);
+            self.fitness.push(evaluate_weights(
+                rng,
+                individual,
+                sim_length,
+                n_weights,
+                averaged,
+                averaged_runs,
+                aggregation,
+                scoring,
+                self.cache_moves,
+            ));
+        }
+
+        for generation in 0..self.max_iter {
+            iterations_used = generation + 1;
+
+            // Elitism: carry the best individuals forward unchanged
+            let mut ranked: Vec<usize> = (0..self.population_size).collect();
+            ranked.sort_by(|&a, &b| self.fitness[b].total_cmp(&self.fitness[a]));
+
+            let mut next_population = Vec::with_capacity(self.population_size);
+            let mut next_fitness = Vec::with_capacity(self.population_size);
+            for &idx in ranked.iter().take(self.elitism) {
+                next_population.push(self.population[idx]);
+                next_fitness.push(self.fitness[idx]);
+            }
+
+            while next_population.len() < self.population_size {
+                let first = self.tournament(rng);
+                let second = self.tournament(rng);
+
+                let mut child = self.population[first];
+                if rng.random::<f64>() < self.crossover_rate {
+                    // Uniform crossover
+                    for (gene, &other) in child.iter_mut().zip(self.population[second].iter()) {
+                        if rng.random::<bool>() {
+                            *gene = other;
+                        }
+                    }
+                }
+
+                // Gaussian mutation
+                for gene in &mut child {
+                    if rng.random::<f64>() < self.mutation_rate {
+                        *gene += mutation.sample(rng);
+                    }
+                }
+
+                next_fitness.push(evaluate_weights(
+                    rng,
+                    child,
+                    sim_length,
+                    n_weights,
+                    averaged,
+                    averaged_runs,
+                    aggregation,
+                    scoring,
+                    self.cache_moves,
+                ));
+                next_population.push(child);
+            }
+
+            self.population = next_population;
+            self.fitness = next_fitness;
+
+            let (best, mean, worst) = fitness_stats(&self.fitness);
+            self.verbosity.progress(
+                progress,
+                format_args!("Generation {generation}: best={best:.5}"),
+            );
+            if let Some(log) = log.as_mut() {
+                let _ = writeln!(log, "{generation},{best:.5},{mean:.5},{worst:.5}");
+            }
+
+            if best > best_fitness {
+                best_fitness = best;
+                no_improve = 0;
+            } else if early_stop_patience > 0 {
+                no_improve += 1;
+            }
+
+            if best_fitness >= early_stop_target {
+                break;
+            }
+            if early_stop_patience > 0 && no_improve >= early_stop_patience {
+                break;
+            }
+        }
+
+        let (best_idx, &best_fitness) = self
+            .fitness
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .expect("Population should not be empty");
+
+        OptimizeResult {
+            weights: self.population[best_idx],
+            best_score: best_fitness,
+            iterations: iterations_used,
+            spread: None,
+        }
+    }
+
+    /// Picks the fittest of `tournament_size` randomly drawn individuals.
+    fn tournament<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let mut best = rng.random_range(0..self.population_size);
+        for _ in 1..self.tournament_size {
+            let challenger = rng.random_range(0..self.population_size);
+            if self.fitness[challenger] > self.fitness[best] {
+                best = challenger;
+            }
+        }
+        best
+    }
+}
+
+/// Runs Genetic Algorithm optimization and saves the best weights.
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be written.
+pub fn optimize_weights_ga(config: &GaConfig, output: &Path) -> io::Result<OptimizeResult> {
+    optimize_weights_ga_with_seed(config, output, None, None)
+}
+
+/// Runs Genetic Algorithm optimization with optional seed/logging.
+///
+/// # Errors
+///
+/// Returns an error if the weights file or log CSV cannot be written.
+pub fn optimize_weights_ga_with_seed(
+    config: &GaConfig,
+    output: &Path,
+    seed: Option<u64>,
+    log_csv: Option<&Path>,
+) -> io::Result<OptimizeResult> {
+    seed.map_or_else(
+        || {
+            let mut rng = rand::rng();
+            optimize_weights_ga_with_rng(config, output, &mut rng, log_csv)
+        },
+        |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            optimize_weights_ga_with_rng(config, output, &mut rng, log_csv)
+        },
+    )
+}
+
+fn optimize_weights_ga_with_rng<R: Rng + ?Sized>(
+    config: &GaConfig,
+    output: &Path,
+    rng: &mut R,
+    log_csv: Option<&Path>,
+) -> io::Result<OptimizeResult> {
+    let mut stdout = io::stdout();
+    let mut solver = GeneticAlgorithm::new(
+        config.population,
+        config.iterations,
+        config.mutation_rate,
+        config.mutation_std_dev,
+        config.crossover_rate,
+        config.elitism,
+        config.tournament_size,
+    )
+    .with_move_cache(config.cache_moves)
+    .with_verbosity(config.verbosity);
+
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Starting GA optimization ({} generations, n_weights={}, averaged={})...",
+            config.iterations, config.n_weights, config.averaged,
+        ),
+    );
+
+    let mut log_writer = if let Some(path) = log_csv {
+        let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+        writeln!(file, "iteration,best,mean,worst")?;
+        Some(file)
+    } else {
+        None
+    };
+
+    let mut result = solver.optimize_with_rng(
+        config.sim_length,
+        config.bounds,
+        config.n_weights,
+        config.averaged,
+        config.averaged_runs,
+        config.aggregation,
+        config.scoring,
+        config.early_stop_patience,
+        config.early_stop_target,
+        rng,
+        log_writer.as_mut().map(|writer| writer as &mut dyn Write),
+        &mut stdout,
+    );
+    if config.normalize {
+        weights::normalize(&mut result.weights);
+    }
+
+    println!(
+        "Best fitness: {:.5} (iterations: {})",
+        result.best_score, result.iterations
+    );
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
+            result.weights[0], result.weights[1], result.weights[2]
+        ),
+    );
+    result.spread = averaged_spread(
+        rng,
+        result.weights,
+        config.averaged.then_some(config.averaged_runs),
+        config.sim_length,
+        config.n_weights,
+        config.scoring,
+        config.verbosity,
+    );
+
+    weights::save_with_scoring(output, &result.weights, Some(config.scoring))?;
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!("Weights saved to {}", output.display()),
+    );
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(seed: u64, iterations: usize) -> OptimizeResult {
+        let mut solver = GeneticAlgorithm::new(4, iterations, 0.2, 0.3, 0.9, 1, 2);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        solver.optimize_with_rng(
+            20,
+            GaConfig::DEFAULT_BOUNDS,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Rows,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            None,
+            &mut io::sink(),
+        )
+    }
+
+    #[test]
+    fn ga_is_reproducible_with_seed() {
+        let a = run(42, 3);
+        let b = run(42, 3);
+        assert_eq!(a.weights.map(f64::to_bits), b.weights.map(f64::to_bits));
+        assert!((a.best_score - b.best_score).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn ga_improves_on_initial_best() {
+        // Survival scoring over longer games separates weights that clear no
+        // rows, so a few generations reliably beat the initial population
+        let survival = |seed: u64, iterations: usize| {
+            let mut solver = GeneticAlgorithm::new(4, iterations, 0.2, 0.3, 0.9, 1, 2);
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            solver
+                .optimize_with_rng(
+                    40,
+                    GaConfig::DEFAULT_BOUNDS,
+                    weights::NUM_WEIGHTS,
+                    false,
+                    1,
+                    Aggregation::Mean,
+                    ScoringMode::Survival,
+                    0,
+                    f64::INFINITY,
+                    &mut rng,
+                    None,
+                    &mut io::sink(),
+                )
+                .best_score
+        };
+        for seed in [3, 7, 11] {
+            // Same seed, so both runs start from the same initial population
+            let initial = survival(seed, 0);
+            let evolved = survival(seed, 3);
+            assert!(evolved > initial, "seed {seed}: {evolved} <= {initial}");
+        }
+    }
+}
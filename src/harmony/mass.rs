@@ -0,0 +1,204 @@
+use std::io;
+
+use rand::SeedableRng;
+use rayon::prelude::*;
+
+use super::search::{HarmonySearch, OptimizeConfig, OptimizeResult};
+use crate::weights;
+
+/// One finished run of [`mass_optimize`].
+#[derive(Debug, Clone)]
+pub struct MassRun {
+    /// 1-based run index.
+    pub run: usize,
+    /// Seed the run was started from.
+    pub seed: u64,
+    pub result: OptimizeResult,
+}
+
+/// Runs `count` independent Harmony Search optimizations in parallel.
+///
+/// Run `i` (1-based) is seeded with `base_seed + i - 1`, so the output only
+/// depends on the configuration and the base seed. Results are returned in
+/// run order.
+///
+/// The runs execute on a dedicated pool of `threads` workers (`0` uses
+/// rayon's default of one per core). The parallel move search inside each
+/// game shares that pool instead of spawning more threads, so `threads`
+/// bounds the total CPU usage.
+///
+/// # Errors
+///
+/// Returns an error if the thread pool cannot be created.
+pub fn mass_optimize(
+    config: &OptimizeConfig,
+    count: usize,
+    base_seed: u64,
+    threads: usize,
+) -> io::Result<Vec<MassRun>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(io::Error::other)?;
+
+    Ok(pool.install(|| {
+        (1..=count)
+            .into_par_iter()
+            .map(|run| {
+                let seed = base_seed.wrapping_add(run as u64 - 1);
+                let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+                let mut solver = HarmonySearch::new(
+                    config.memory_size,
+                    config.iterations,
+                    config.accept_rate,
+                    config.pitch_adj_rate,
+                    config.bandwidth,
+                )
+                .with_elitism(config.elitism)
+                .with_restarts(config.restart_patience, config.max_restarts)
+                .with_plateau_stop(config.rel_improve_window, config.rel_improve_threshold)
+                .with_move_cache(config.cache_moves)
+                .with_verbosity(config.verbosity);
+                let result = solver.optimize_with_rng(
+                    config.sim_length,
+                    config.bounds,
+                    config.n_weights,
+                    config.averaged,
+                    config.averaged_runs,
+                    config.aggregation,
+                    config.scoring,
+                    config.early_stop_patience,
+                    config.early_stop_target,
+                    &mut rng,
+                    None,
+                    &mut io::stdout(),
+                );
+                MassRun { run, seed, result }
+            })
+            .collect()
+    }))
+}
+
+/// How spread out the solutions of several runs are, after scaling each to
+/// unit norm with [`weights::normalize`].
+#[derive(Debug, Clone)]
+pub struct Diversity {
+    /// Mean L2 distance over all pairs of solutions.
+    pub mean_pairwise_distance: f64,
+    /// Population variance of each weight across solutions.
+    pub variances: [f64; weights::NUM_WEIGHTS],
+}
+
+/// Measures how clustered `solutions` are. Low values mean the runs agree
+/// on one basin; high values mean they scatter.
+#[must_use]
+pub fn diversity(solutions: &[[f64; weights::NUM_WEIGHTS]]) -> Diversity {
+    let normalized: Vec<_> = solutions
+        .iter()
+        .map(|&w| {
+            let mut w = w;
+            weights::normalize(&mut w);
+            w
+        })
+        .collect();
+
+    let n = f64::from(u32::try_from(normalized.len()).unwrap_or(u32::MAX)).max(1.0);
+    let variances = std::array::from_fn(|i| {
+        let mean = normalized.iter().map(|w| w[i]).sum::<f64>() / n;
+        normalized
+            .iter()
+            .map(|w| (w[i] - mean).powi(2))
+            .sum::<f64>()
+            / n
+    });
+
+    Diversity {
+        mean_pairwise_distance: mean_pairwise_distance(&normalized),
+        variances,
+    }
+}
+
+/// Mean Euclidean distance over all unordered pairs of `vectors`; 0 for
+/// fewer than two vectors.
+#[must_use]
+pub fn mean_pairwise_distance(vectors: &[[f64; weights::NUM_WEIGHTS]]) -> f64 {
+    let mut total = 0.0;
+    let mut pairs = 0u32;
+    for (i, a) in vectors.iter().enumerate() {
+        for b in &vectors[i + 1..] {
+            total += a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            pairs += 1;
+        }
+    }
+    if pairs == 0 {
+        0.0
+    } else {
+        total / f64::from(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiny_config() -> OptimizeConfig {
+        OptimizeConfig {
+            memory_size: 2,
+            iterations: 2,
+            sim_length: 10,
+            ..OptimizeConfig::default()
+        }
+    }
+
+    #[test]
+    fn seeded_mass_optimize_is_reproducible() {
+        let config = tiny_config();
+        let a = mass_optimize(&config, 3, 100, 2).expect("pool");
+        let b = mass_optimize(&config, 3, 100, 3).expect("pool");
+
+        assert_eq!(a.iter().map(|r| r.run).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(
+            a.iter().map(|r| r.seed).collect::<Vec<_>>(),
+            [100, 101, 102]
+        );
+        for (x, y) in a.iter().zip(&b) {
+            assert_eq!(
+                x.result.weights.map(f64::to_bits),
+                y.result.weights.map(f64::to_bits)
+            );
+            assert_eq!(x.result.best_score.to_bits(), y.result.best_score.to_bits());
+        }
+    }
+
+    #[test]
+    fn pairwise_distance_averages_all_pairs() {
+        let mut a = [0.0; weights::NUM_WEIGHTS];
+        let mut b = a;
+        let mut c = a;
+        a[0] = 3.0;
+        b[1] = 4.0;
+        c[0] = 3.0;
+        c[1] = 4.0;
+        // |ab| = 5, |ac| = 4, |bc| = 3
+        assert!((mean_pairwise_distance(&[a, b, c]) - 4.0).abs() < 1e-12);
+        assert!(mean_pairwise_distance(&[a]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn scaled_copies_have_no_diversity() {
+        let a: [f64; weights::NUM_WEIGHTS] = std::array::from_fn(|i| {
+            let i = f64::from(u32::try_from(i).unwrap_or(u32::MAX));
+            i - 3.0
+        });
+        let b = a.map(|w| w * 2.5);
+
+        let d = diversity(&[a, b]);
+        assert!(d.mean_pairwise_distance < 1e-12);
+        assert!(d.variances.iter().all(|&v| v < 1e-12));
+    }
+}
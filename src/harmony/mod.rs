@@ -1,12 +1,31 @@
 //! Optimization algorithms for tuning Tetris evaluation weights.
 
+pub mod aggregation;
+pub mod bounds;
+pub mod cmaes;
 pub mod cross_entropy;
+pub mod genetic;
+pub mod mass;
+pub mod plateau;
 pub mod search;
+pub mod sensitivity;
+pub mod verbosity;
 
+pub use aggregation::Aggregation;
+pub use bounds::Bounds;
+pub use cmaes::{CmaConfig, CmaEsSearch, optimize_weights_cmaes, optimize_weights_cmaes_with_seed};
 pub use cross_entropy::{
-    CeConfig, CeOptimizeResult, CrossEntropySearch, optimize_weights_ce,
+    CeConfig, CeOptimizeResult, CrossEntropySearch, EliteWeighting, optimize_weights_ce,
     optimize_weights_ce_with_seed,
 };
+pub use genetic::{GaConfig, GeneticAlgorithm, optimize_weights_ga, optimize_weights_ga_with_seed};
+pub use mass::{Diversity, MassRun, diversity, mass_optimize, mean_pairwise_distance};
+pub use plateau::PlateauStop;
 pub use search::{
     HarmonySearch, OptimizeConfig, OptimizeResult, optimize_weights, optimize_weights_with_seed,
 };
+pub use sensitivity::{
+    LandscapePoint, Perturbation, Sensitivity, SensitivityReport, fitness_landscape,
+    perturbation_sensitivity, sensitivity_ranking,
+};
+pub use verbosity::Verbosity;
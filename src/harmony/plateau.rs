@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+/// Stops a search once its best fitness plateaus.
+///
+/// Compares the mean best fitness over the last `window` iterations with the
+/// mean over the `window` iterations before it, and stops once the relative
+/// gain falls below `threshold`. Unlike a patience counter, a single lucky
+/// iteration only moves the mean by a fraction of its gain.
+#[derive(Debug, Clone)]
+pub struct PlateauStop {
+    window: usize,
+    threshold: f64,
+    history: VecDeque<f64>,
+}
+
+impl PlateauStop {
+    /// Creates a stop rule over `window` iterations. A `window` of zero
+    /// never stops.
+    #[must_use]
+    pub fn new(window: usize, threshold: f64) -> Self {
+        Self {
+            window,
+            threshold,
+            history: VecDeque::with_capacity(2 * window),
+        }
+    }
+
+    /// Records one iteration's best fitness and returns whether to stop.
+    /// Never stops before `2 * window` iterations have been recorded.
+    pub fn record(&mut self, best: f64) -> bool {
+        if self.window == 0 {
+            return false;
+        }
+        if self.history.len() == 2 * self.window {
+            self.history.pop_front();
+        }
+        self.history.push_back(best);
+        if self.history.len() < 2 * self.window {
+            return false;
+        }
+
+        let window_f = f64::from(u32::try_from(self.window).unwrap_or(u32::MAX));
+        let earlier = self.history.range(..self.window).sum::<f64>() / window_f;
+        let recent = self.history.range(self.window..).sum::<f64>() / window_f;
+        let gain = recent - earlier;
+        gain <= 0.0 || gain < self.threshold * earlier.abs()
+    }
+
+    /// Forgets the recorded history, e.g. after a restart.
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_fitness_stops_after_two_windows() {
+        let mut stop = PlateauStop::new(5, 0.01);
+        let stopped_at = (0..100).find(|_| stop.record(42.0));
+        assert_eq!(stopped_at, Some(9));
+    }
+
+    #[test]
+    fn steady_improvement_keeps_going() {
+        let mut stop = PlateauStop::new(5, 0.01);
+        assert!((1..100).all(|i| !stop.record(100.0 * f64::from(i))));
+    }
+
+    #[test]
+    fn single_jump_is_averaged_out() {
+        let mut stop = PlateauStop::new(4, 0.05);
+        let bests = [10.0, 10.0, 10.0, 10.0, 12.0, 12.0, 12.0, 12.0, 12.0, 12.0];
+        let stopped_at = bests.iter().position(|&best| stop.record(best));
+        // The earlier window still averages in some 10s until the 11th iteration
+        assert_eq!(stopped_at, None);
+        assert!(stop.record(12.0));
+    }
+
+    #[test]
+    fn zero_window_never_stops() {
+        let mut stop = PlateauStop::new(0, 1.0);
+        assert!((0..10).all(|_| !stop.record(0.0)));
+    }
+
+    #[test]
+    fn reset_restarts_the_count() {
+        let mut stop = PlateauStop::new(2, 0.01);
+        for _ in 0..3 {
+            assert!(!stop.record(1.0));
+        }
+        stop.reset();
+        for _ in 0..3 {
+            assert!(!stop.record(1.0));
+        }
+        assert!(stop.record(1.0));
+    }
+}
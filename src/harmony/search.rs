@@ -4,11 +4,18 @@ use std::path::Path;
 use rand::Rng;
 use rand::SeedableRng;
 
-use crate::agent::simulator::Simulator;
+use super::aggregation::Aggregation;
+use super::bounds::Bounds;
+use super::cmaes::CmaConfig;
+use super::plateau::PlateauStop;
+use super::verbosity::Verbosity;
+use crate::agent::simulator::{MoveCache, ScoringMode, Simulator};
+use crate::stats::{SampleSummary, summarize};
 use crate::weights;
 
 /// Configuration for a full optimization run.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct OptimizeConfig {
     pub memory_size: usize,
     pub iterations: usize,
@@ -16,12 +23,24 @@ pub struct OptimizeConfig {
     pub pitch_adj_rate: f64,
     pub bandwidth: f64,
     pub sim_length: usize,
-    pub bounds: (f64, f64),
+    pub bounds: Bounds,
     pub n_weights: usize,
     pub averaged: bool,
+    pub cache_moves: bool,
+    pub verbosity: Verbosity,
     pub averaged_runs: usize,
+    pub aggregation: Aggregation,
+    pub scoring: ScoringMode,
+    pub normalize: bool,
+    /// Keep the best-ever harmony apart from the working memory.
+    pub elitism: bool,
+    pub restart_patience: usize,
+    pub max_restarts: usize,
     pub early_stop_patience: usize,
     pub early_stop_target: f64,
+    /// Window of the rolling-mean stop rule (0 = off, see [`PlateauStop`]).
+    pub rel_improve_window: usize,
+    pub rel_improve_threshold: f64,
 }
 
 impl OptimizeConfig {
@@ -31,10 +50,21 @@ impl OptimizeConfig {
     pub const DEFAULT_PITCH_ADJ_RATE: f64 = 0.99;
     pub const DEFAULT_BANDWIDTH: f64 = 0.1;
     pub const DEFAULT_SIM_LENGTH: usize = 1000;
-    pub const DEFAULT_BOUNDS: (f64, f64) = (-1.0, 1.0);
+    pub const DEFAULT_BOUNDS: Bounds = Bounds::uniform(-1.0, 1.0);
     pub const DEFAULT_N_WEIGHTS: usize = weights::NUM_WEIGHTS;
     pub const DEFAULT_AVERAGED_RUNS: usize = 20;
     pub const DEFAULT_EARLY_STOP_TARGET: f64 = f64::INFINITY;
+    pub const DEFAULT_REL_IMPROVE_THRESHOLD: f64 = 0.001;
+    pub const DEFAULT_MAX_RESTARTS: usize = 3;
+
+    /// Games a full run simulates without early stopping or restarts:
+    /// the initial memory and one harmony per iteration, plus the final spread report when averaged.
+    #[must_use]
+    pub const fn estimated_games(&self) -> usize {
+        let runs = if self.averaged { self.averaged_runs } else { 1 };
+        let spread = if self.averaged { self.averaged_runs } else { 0 };
+        (self.memory_size + self.iterations) * runs + spread
+    }
 
     /// Returns a usage string with the current default values.
     #[must_use]
@@ -46,7 +76,7 @@ Usage: harmonomino [OPTIONS]
 Runs Harmony Search optimization to find optimal Tetris agent weights.
 
 Options:
-  --algorithm <ALG>     Algorithm: hsa, ce            [default: hsa]
+  --algorithm <ALG>     Algorithm: hsa, ce, ga, cmaes [default: hsa]
   --memory-size <N>     Harmony memory size           [default: {}]
   --iterations <N>      Number of iterations          [default: {}]
   --accept-rate <F>     Memory consideration rate     [default: {}]
@@ -56,18 +86,44 @@ Options:
   --n-weights <N>       Number of eval functions      [default: {}]
   --averaged            Average fitness over multiple runs
   --averaged-runs <N>   Runs per averaged evaluation  [default: {}]
+  --cache-moves         Share move searches across averaged runs
+  --aggregation <AGG>   Averaged statistic: mean, median, min, trimmed-mean [default: mean]
+  --scoring <MODE>      Game fitness: rows, survival  [default: rows]
   --early-stop-patience <N> Stop after N iterations without improvement
   --early-stop-target <F>   Stop once best fitness >= target [default: {}]
+  --rel-improve-window <N>  Stop when the mean best over N iterations stalls (hsa, ce)
+  --rel-improve-threshold <F> Minimum relative gain per window [default: {}]
+  --restart-patience <N>    Restart after N iterations without improvement (hsa, ce)
+  --max-restarts <N>        Maximum restarts per run      [default: {}]
   --seed <N>            RNG seed for deterministic runs
+  --dry-run             Print the resolved configuration and exit
   --output <PATH>       Output weights file           [default: weights.txt]
+  --normalize           Rescale saved weights to unit L2 norm
+  --no-elitism          Return the memory's best instead of the best-ever harmony
   --log-csv <PATH>      Write per-iteration metrics to CSV
+  --quiet               Only print the final result line (--log-csv is still written)
+  --sensitivity         Rank weights by fitness drop when zeroed
+  --sensitivity-runs <N> Seeded games per sensitivity variant [default: 5]
+  --list-evals          List evaluation functions in weight order
   --help                Print this help message
 
 Cross-Entropy Search options (--algorithm ce):
   --n-samples <N>       Candidate samples per iteration [default: 50]
   --n-elite <N>         Elite samples for distribution  [default: 10]
   --initial-std-dev <F> Initial standard deviation      [default: 10.0]
-  --std-dev-floor <F>   Minimum standard deviation      [default: 0.01]",
+  --std-dev-floor <F>   Minimum standard deviation      [default: 0.01]
+
+Genetic Algorithm options (--algorithm ga):
+  --population <N>      Individuals per generation      [default: 20]
+  --mutation-rate <F>   Per-gene mutation probability   [default: 0.1]
+  --mutation-std-dev <F> Gaussian mutation std dev      [default: 0.2]
+  --crossover-rate <F>  Uniform crossover probability   [default: 0.9]
+  --elitism <N>         Best individuals kept per gen   [default: 2]
+  --tournament-size <N> Tournament selection size       [default: 3]
+
+CMA-ES options (--algorithm cmaes):
+  --population <N>      Offspring per generation        [default: {}]
+  --initial-sigma <F>   Initial step size               [default: 0.5]",
             Self::DEFAULT_MEMORY_SIZE,
             Self::DEFAULT_ITERATIONS,
             Self::DEFAULT_ACCEPT_RATE,
@@ -77,6 +133,9 @@ Cross-Entropy Search options (--algorithm ce):
             Self::DEFAULT_N_WEIGHTS,
             Self::DEFAULT_AVERAGED_RUNS,
             Self::DEFAULT_EARLY_STOP_TARGET,
+            Self::DEFAULT_REL_IMPROVE_THRESHOLD,
+            Self::DEFAULT_MAX_RESTARTS,
+            CmaConfig::DEFAULT_POPULATION,
         )
     }
 }
@@ -93,9 +152,19 @@ impl Default for OptimizeConfig {
             bounds: Self::DEFAULT_BOUNDS,
             n_weights: Self::DEFAULT_N_WEIGHTS,
             averaged: false,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
             averaged_runs: Self::DEFAULT_AVERAGED_RUNS,
+            aggregation: Aggregation::Mean,
+            scoring: ScoringMode::Rows,
+            normalize: false,
+            elitism: true,
+            restart_patience: 0,
+            max_restarts: Self::DEFAULT_MAX_RESTARTS,
             early_stop_patience: 0,
             early_stop_target: Self::DEFAULT_EARLY_STOP_TARGET,
+            rel_improve_window: 0,
+            rel_improve_threshold: Self::DEFAULT_REL_IMPROVE_THRESHOLD,
         }
     }
 }
@@ -140,17 +209,26 @@ fn optimize_weights_with_rng<R: Rng + ?Sized>(
     rng: &mut R,
     log_csv: Option<&Path>,
 ) -> io::Result<OptimizeResult> {
+    let mut stdout = io::stdout();
     let mut solver = HarmonySearch::new(
         config.memory_size,
         config.iterations,
         config.accept_rate,
         config.pitch_adj_rate,
         config.bandwidth,
-    );
-
-    println!(
-        "Starting HSA optimization ({} iterations, n_weights={}, averaged={})...",
-        config.iterations, config.n_weights, config.averaged,
+    )
+    .with_elitism(config.elitism)
+    .with_restarts(config.restart_patience, config.max_restarts)
+    .with_plateau_stop(config.rel_improve_window, config.rel_improve_threshold)
+    .with_move_cache(config.cache_moves)
+    .with_verbosity(config.verbosity);
+
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Starting HSA optimization ({} iterations, n_weights={}, averaged={})...",
+            config.iterations, config.n_weights, config.averaged,
+        ),
     );
 
     let mut log_writer = if let Some(path) = log_csv {
@@ -161,29 +239,50 @@ fn optimize_weights_with_rng<R: Rng + ?Sized>(
         None
     };
 
-    let result = solver.optimize_with_rng(
+    let mut result = solver.optimize_with_rng(
         config.sim_length,
         config.bounds,
         config.n_weights,
         config.averaged,
         config.averaged_runs,
+        config.aggregation,
+        config.scoring,
         config.early_stop_patience,
         config.early_stop_target,
         rng,
         log_writer.as_mut().map(|writer| writer as &mut dyn Write),
+        &mut stdout,
     );
+    if config.normalize {
+        weights::normalize(&mut result.weights);
+    }
 
     println!(
         "Best fitness: {:.5} (iterations: {})",
         result.best_score, result.iterations
     );
-    println!(
-        "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
-        result.weights[0], result.weights[1], result.weights[2]
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!(
+            "Best weights (first 3): [{:.3}, {:.3}, {:.3}, ...]",
+            result.weights[0], result.weights[1], result.weights[2]
+        ),
+    );
+    result.spread = averaged_spread(
+        rng,
+        result.weights,
+        config.averaged.then_some(config.averaged_runs),
+        config.sim_length,
+        config.n_weights,
+        config.scoring,
+        config.verbosity,
     );
 
-    weights::save(output, &result.weights)?;
-    println!("Weights saved to {}", output.display());
+    weights::save_with_scoring(output, &result.weights, Some(config.scoring))?;
+    config.verbosity.progress(
+        &mut stdout,
+        format_args!("Weights saved to {}", output.display()),
+    );
 
     Ok(result)
 }
@@ -197,6 +296,18 @@ pub struct HarmonySearch {
     pub band_width: f64,
     pub harm_mem: Vec<[f64; weights::NUM_WEIGHTS]>,
     pub fitness_mem: Vec<f64>,
+    /// Return the best harmony ever evaluated, even if it left the memory.
+    pub elitism: bool,
+    /// Stagnant iterations before the memory is reinitialized (0 = never).
+    pub restart_patience: usize,
+    pub max_restarts: usize,
+    /// Window of the rolling-mean stop rule (0 = off, see [`PlateauStop`]).
+    pub plateau_window: usize,
+    pub plateau_threshold: f64,
+    /// Share a [`MoveCache`] between the games of an averaged evaluation.
+    pub cache_moves: bool,
+    /// How much progress to print.
+    pub verbosity: Verbosity,
 }
 
 #[derive(Debug, Clone)]
@@ -204,6 +315,8 @@ pub struct OptimizeResult {
     pub weights: [f64; weights::NUM_WEIGHTS],
     pub best_score: f64,
     pub iterations: usize,
+    /// Per-run spread of the best weights, when fitness is averaged.
+    pub spread: Option<SampleSummary>,
 }
 
 impl HarmonySearch {
@@ -237,84 +350,123 @@ impl HarmonySearch {
             band_width,
             harm_mem: Vec::with_capacity(hm_mem_size),
             fitness_mem: Vec::with_capacity(hm_mem_size),
+            elitism: true,
+            restart_patience: 0,
+            max_restarts: 0,
+            plateau_window: 0,
+            plateau_threshold: 0.0,
+            cache_moves: false,
+            verbosity: Verbosity::Normal,
         }
     }
 
+    /// Shares found moves between the games of an averaged evaluation.
+    /// Fitness is unchanged; only repeated searches are skipped.
+    #[must_use]
+    pub const fn with_move_cache(mut self, cache_moves: bool) -> Self {
+        self.cache_moves = cache_moves;
+        self
+    }
+
+    /// Sets how much progress is printed while optimizing.
+    #[must_use]
+    pub const fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Reinitializes the memory after `patience` iterations without
+    /// improvement, at most `max_restarts` times. A `patience` of zero
+    /// disables restarts.
+    #[must_use]
+    pub const fn with_restarts(mut self, patience: usize, max_restarts: usize) -> Self {
+        self.restart_patience = patience;
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Stops once the mean best fitness over `window` iterations gains less
+    /// than `threshold` (relative) on the window before it. A `window` of
+    /// zero disables the rule.
+    #[must_use]
+    pub const fn with_plateau_stop(mut self, window: usize, threshold: f64) -> Self {
+        self.plateau_window = window;
+        self.plateau_threshold = threshold;
+        self
+    }
+
+    /// Sets whether the best-ever harmony is kept apart from the memory.
+    #[must_use]
+    pub const fn with_elitism(mut self, elitism: bool) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
     /// Runs the Harmony Search optimization loop.
     ///
+    /// With [`HarmonySearch::elitism`], or once a restart has happened, the
+    /// result is the best harmony ever evaluated; otherwise it is the best
+    /// one left in memory.
+    ///
     /// # Panics
     ///
     /// Panics if `fitness_mem` is empty at the end of optimization (happens only when `hm_mem_size` is 0).
     pub fn optimize_with_rng<R: Rng + ?Sized>(
         &mut self,
         sim_length: usize,
-        bounds: (f64, f64),
+        bounds: Bounds,
         n_weights: usize,
         averaged: bool,
         averaged_runs: usize,
+        aggregation: Aggregation,
+        scoring: ScoringMode,
         early_stop_patience: usize,
         early_stop_target: f64,
         rng: &mut R,
         mut log: Option<&mut dyn Write>,
+        progress: &mut dyn Write,
     ) -> OptimizeResult {
-        let (min_bound, max_bound) = bounds;
         let mut best_fitness = f64::NEG_INFINITY;
         let mut no_improve = 0usize;
         let mut iterations_used = 0usize;
-
-        self.harm_mem.clear();
-        self.fitness_mem.clear();
-
-        // Initialization
-        for _ in 0..self.hm_mem_size {
-            let mut harmony = [0.0; weights::NUM_WEIGHTS];
-            for val in &mut harmony {
-                *val = rng.random_range(min_bound..=max_bound);
+        let mut elite: Option<([f64; weights::NUM_WEIGHTS], f64)> = None;
+        let mut restarts = 0usize;
+        let mut plateau = PlateauStop::new(self.plateau_window, self.plateau_threshold);
+        let cache_moves = self.cache_moves;
+        let mut keep_elite = |harmony: [f64; weights::NUM_WEIGHTS], fitness: f64| {
+            if elite.is_none_or(|(_, best)| fitness > best) {
+                elite = Some((harmony, fitness));
             }
-            self.harm_mem.push(harmony);
-            self.fitness_mem.push(evaluate_weights(
+        };
+        let evaluate = |rng: &mut R, harmony: [f64; weights::NUM_WEIGHTS]| {
+            evaluate_weights(
                 rng,
                 harmony,
                 sim_length,
                 n_weights,
                 averaged,
                 averaged_runs,
-            ));
+                aggregation,
+                scoring,
+                cache_moves,
+            )
+        };
+
+        // Initialization
+        self.fill_memory(&bounds, rng, &evaluate);
+        for (&harmony, &fitness) in self.harm_mem.iter().zip(&self.fitness_mem) {
+            keep_elite(harmony, fitness);
         }
 
         // Optimization Loop
         for cnt in 0..self.max_iter {
             iterations_used = cnt + 1;
-            let mut new_harmony = [0.0; weights::NUM_WEIGHTS];
-
-            for (i, note) in new_harmony.iter_mut().enumerate() {
-                if rng.random::<f64>() < self.accept_rate {
-                    // Memory Consideration
-                    let random_mem_idx = rng.random_range(0..self.hm_mem_size);
-                    let mut value = self.harm_mem[random_mem_idx][i];
-
-                    // Pitch Adjustment
-                    if rng.random::<f64>() < self.pitch_adj_rate {
-                        let adjustment = rng.random_range(-1.0..=1.0) * self.band_width; // TODO: maybe Gaussian
-                        value += adjustment;
-                    }
-                    *note = value;
-                } else {
-                    // Random Selection
-                    *note = rng.random_range(min_bound..=max_bound);
-                }
-            }
-
-            let new_fitness = evaluate_weights(
-                rng,
-                new_harmony,
-                sim_length,
-                n_weights,
-                averaged,
-                averaged_runs,
-            );
+            let new_harmony = self.improvise(&bounds, rng);
+            let new_fitness = evaluate(rng, new_harmony);
 
-            println!("Iteration {cnt}: {new_fitness}");
+            self.verbosity
+                .progress(progress, format_args!("Iteration {cnt}: {new_fitness}"));
+            keep_elite(new_harmony, new_fitness);
 
             // Maximization Logic: Find min (worst) to replace
             let (worst_idx, &worst_fitness) = self
@@ -337,7 +489,7 @@ impl HarmonySearch {
             if best > best_fitness {
                 best_fitness = best;
                 no_improve = 0;
-            } else if early_stop_patience > 0 {
+            } else {
                 no_improve += 1;
             }
 
@@ -347,8 +499,94 @@ impl HarmonySearch {
             if early_stop_patience > 0 && no_improve >= early_stop_patience {
                 break;
             }
+            if plateau.record(best_fitness) {
+                break;
+            }
+            if self.restart_patience > 0
+                && no_improve >= self.restart_patience
+                && restarts < self.max_restarts
+            {
+                restarts += 1;
+                self.verbosity.progress(
+                    progress,
+                    format_args!(
+                        "Restart {restarts}: reinitializing memory after {no_improve} stagnant iterations"
+                    ),
+                );
+                self.fill_memory(&bounds, rng, &evaluate);
+                for (&harmony, &fitness) in self.harm_mem.iter().zip(&self.fitness_mem) {
+                    keep_elite(harmony, fitness);
+                }
+                no_improve = 0;
+                plateau.reset();
+            }
         }
 
+        // A restart discards the old memory, so only the elite still holds its best
+        let (best_weights, best_fitness) = match elite {
+            Some(elite) if self.elitism || restarts > 0 => elite,
+            _ => self.memory_best(),
+        };
+
+        OptimizeResult {
+            weights: best_weights,
+            best_score: best_fitness,
+            iterations: iterations_used,
+            spread: None,
+        }
+    }
+
+    /// Builds a new harmony note by note from memory, pitch adjustment or random selection.
+    fn improvise<R: Rng + ?Sized>(
+        &self,
+        bounds: &Bounds,
+        rng: &mut R,
+    ) -> [f64; weights::NUM_WEIGHTS] {
+        let mut new_harmony = [0.0; weights::NUM_WEIGHTS];
+
+        for (i, note) in new_harmony.iter_mut().enumerate() {
+            if rng.random::<f64>() < self.accept_rate {
+                // Memory Consideration
+                let random_mem_idx = rng.random_range(0..self.hm_mem_size);
+                let mut value = self.harm_mem[random_mem_idx][i];
+
+                // Pitch Adjustment
+                if rng.random::<f64>() < self.pitch_adj_rate {
+                    let adjustment = rng.random_range(-1.0..=1.0) * self.band_width; // TODO: maybe Gaussian
+                    value += adjustment;
+                }
+                *note = value;
+            } else {
+                // Random Selection
+                let (min_bound, max_bound) = bounds.get(i);
+                *note = rng.random_range(min_bound..=max_bound);
+            }
+        }
+        new_harmony
+    }
+
+    /// Replaces the memory with random harmonies drawn within `bounds`.
+    fn fill_memory<R: Rng + ?Sized>(
+        &mut self,
+        bounds: &Bounds,
+        rng: &mut R,
+        evaluate: &impl Fn(&mut R, [f64; weights::NUM_WEIGHTS]) -> f64,
+    ) {
+        self.harm_mem.clear();
+        self.fitness_mem.clear();
+        for _ in 0..self.hm_mem_size {
+            let harmony: [f64; weights::NUM_WEIGHTS] = std::array::from_fn(|i| {
+                let (min_bound, max_bound) = bounds.get(i);
+                rng.random_range(min_bound..=max_bound)
+            });
+            let fitness = evaluate(rng, harmony);
+            self.harm_mem.push(harmony);
+            self.fitness_mem.push(fitness);
+        }
+    }
+
+    /// Returns the best harmony currently in memory and its fitness.
+    fn memory_best(&self) -> ([f64; weights::NUM_WEIGHTS], f64) {
         // Maximization Logic: Return max (best)
         let (best_idx, &best_fitness) = self
             .fitness_mem
@@ -356,16 +594,23 @@ impl HarmonySearch {
             .enumerate()
             .max_by(|a, b| a.1.total_cmp(b.1))
             .expect("Fitness memory should not be empty");
-
-        OptimizeResult {
-            weights: self.harm_mem[best_idx],
-            best_score: best_fitness,
-            iterations: iterations_used,
-        }
+        (self.harm_mem[best_idx], best_fitness)
     }
 }
 
-fn fitness_stats(fitnesses: &[f64]) -> (f64, f64, f64) {
+/// Normalized log-rank recombination weights for `n` ranked samples, best
+/// first, as in CMA-ES: `ln(n + 1/2) - ln(rank)`, scaled to sum to 1.
+pub(crate) fn log_rank_weights(n: usize) -> Vec<f64> {
+    let to_f64 = |x: usize| f64::from(u32::try_from(x).unwrap_or(u32::MAX));
+    let raw: Vec<f64> = (1..=n)
+        .map(|rank| (to_f64(n) + 0.5).ln() - to_f64(rank).ln())
+        .collect();
+    let total: f64 = raw.iter().sum();
+    raw.iter().map(|w| w / total).collect()
+}
+
+/// Returns `(best, mean, worst)` of a set of fitness values.
+pub(crate) fn fitness_stats(fitnesses: &[f64]) -> (f64, f64, f64) {
     let best = fitnesses
         .iter()
         .copied()
@@ -385,24 +630,277 @@ fn fitness_stats(fitnesses: &[f64]) -> (f64, f64, f64) {
     (best, mean, worst)
 }
 
-fn evaluate_weights<R: Rng + ?Sized>(
+/// Scores a weight vector by simulating one game, or by combining
+/// `averaged_runs` games with `aggregation` when `averaged` is set.
+/// Each game is scored according to `scoring`.
+pub(crate) fn evaluate_weights<R: Rng + ?Sized>(
     rng: &mut R,
     weights: [f64; weights::NUM_WEIGHTS],
     sim_length: usize,
     n_weights: usize,
     averaged: bool,
     averaged_runs: usize,
+    aggregation: Aggregation,
+    scoring: ScoringMode,
+    cache_moves: bool,
 ) -> f64 {
+    let simulator = || {
+        Simulator::new(weights, sim_length)
+            .with_n_weights(n_weights)
+            .with_scoring(scoring)
+    };
     if averaged {
-        let total: f64 = (0..averaged_runs)
-            .map(|_| {
-                let sim = Simulator::new(weights, sim_length).with_n_weights(n_weights);
-                f64::from(sim.simulate_game_with_rng(rng))
-            })
-            .sum();
-        total / f64::from(u32::try_from(averaged_runs).unwrap_or(u32::MAX))
+        let mut scores = evaluate_runs(
+            rng,
+            weights,
+            sim_length,
+            n_weights,
+            averaged_runs,
+            scoring,
+            cache_moves,
+        );
+        aggregation.apply(&mut scores)
     } else {
-        let sim = Simulator::new(weights, sim_length).with_n_weights(n_weights);
-        f64::from(sim.simulate_game_with_rng(rng))
+        simulator().fitness_with_rng(rng)
+    }
+}
+
+/// Plays `runs` games with `weights` and returns each game's fitness.
+/// With `cache_moves`, the games share a [`MoveCache`].
+pub(crate) fn evaluate_runs<R: Rng + ?Sized>(
+    rng: &mut R,
+    weights: [f64; weights::NUM_WEIGHTS],
+    sim_length: usize,
+    n_weights: usize,
+    runs: usize,
+    scoring: ScoringMode,
+    cache_moves: bool,
+) -> Vec<f64> {
+    let mut cache = MoveCache::new();
+    (0..runs)
+        .map(|_| {
+            let simulator = Simulator::new(weights, sim_length)
+                .with_n_weights(n_weights)
+                .with_scoring(scoring);
+            if cache_moves {
+                simulator.fitness_cached_with_rng(rng, &mut cache)
+            } else {
+                simulator.fitness_with_rng(rng)
+            }
+        })
+        .collect()
+}
+
+/// When `averaged_runs` is set, replays the final weights over that many fresh
+/// games and prints the spread of their fitness, so a stable weight set can be
+/// told from a lucky one.
+pub(crate) fn averaged_spread<R: Rng + ?Sized>(
+    rng: &mut R,
+    weights: [f64; weights::NUM_WEIGHTS],
+    averaged_runs: Option<usize>,
+    sim_length: usize,
+    n_weights: usize,
+    scoring: ScoringMode,
+    verbosity: Verbosity,
+) -> Option<SampleSummary> {
+    let runs = averaged_runs?;
+    let spread = summarize(&evaluate_runs(
+        rng, weights, sim_length, n_weights, runs, scoring, false,
+    ))?;
+    verbosity.progress(
+        &mut io::stdout(),
+        format_args!(
+            "Averaged fitness over {runs} runs: mean={:.5}, std_dev={:.5}, 95% CI=[{:.5}, {:.5}]",
+            spread.mean, spread.std_dev, spread.ci_low, spread.ci_high
+        ),
+    );
+    Some(spread)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initialization_respects_per_weight_bounds() {
+        let ranges: [(f64, f64); weights::NUM_WEIGHTS] = std::array::from_fn(|i| {
+            let i = f64::from(u32::try_from(i).unwrap_or(u32::MAX));
+            if i < 8.0 {
+                (-i - 1.0, -i)
+            } else {
+                (i, i + 0.5)
+            }
+        });
+        let bounds = Bounds(ranges);
+        let mut solver = HarmonySearch::new(8, 0, 0.95, 0.99, 0.1);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+
+        let _ = solver.optimize_with_rng(
+            5,
+            bounds,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Rows,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            None,
+            &mut io::sink(),
+        );
+
+        for harmony in &solver.harm_mem {
+            for (i, &w) in harmony.iter().enumerate() {
+                let (min, max) = bounds.get(i);
+                assert!(
+                    (min..=max).contains(&w),
+                    "weight {i} = {w} outside [{min}, {max}]"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn elitism_result_beats_every_logged_best() {
+        let mut solver = HarmonySearch::new(3, 15, 0.95, 0.99, 0.3);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+        let mut log = Vec::new();
+
+        let result = solver.optimize_with_rng(
+            20,
+            OptimizeConfig::DEFAULT_BOUNDS,
+            weights::NUM_WEIGHTS,
+            false,
+            1,
+            Aggregation::Mean,
+            ScoringMode::Survival,
+            0,
+            f64::INFINITY,
+            &mut rng,
+            Some(&mut log),
+            &mut io::sink(),
+        );
+
+        let log = String::from_utf8(log).expect("utf-8 log");
+        for line in log.lines() {
+            let best: f64 = line
+                .split(',')
+                .nth(1)
+                .expect("best column")
+                .parse()
+                .expect("float");
+            // The log rounds to five decimals
+            assert!(result.best_score >= best - 1e-5, "{line}");
+        }
+        assert_eq!(log.lines().count(), 15);
+    }
+
+    #[test]
+    fn estimated_games_counts_averaged_runs() {
+        let config = OptimizeConfig {
+            memory_size: 5,
+            iterations: 10,
+            ..OptimizeConfig::default()
+        };
+        assert_eq!(config.estimated_games(), 15);
+
+        let averaged = OptimizeConfig {
+            averaged: true,
+            averaged_runs: 4,
+            ..config
+        };
+        assert_eq!(averaged.estimated_games(), 15 * 4 + 4);
+    }
+
+    #[test]
+    fn cached_averaged_fitness_matches_uncached() {
+        let weights: [f64; weights::NUM_WEIGHTS] = std::array::from_fn(|i| {
+            let i = f64::from(u32::try_from(i).unwrap_or(u32::MAX));
+            (i % 3.0) - 1.5
+        });
+        let fitness = |cache_moves| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(21);
+            evaluate_weights(
+                &mut rng,
+                weights,
+                40,
+                weights::NUM_WEIGHTS,
+                true,
+                4,
+                Aggregation::Mean,
+                ScoringMode::Survival,
+                cache_moves,
+            )
+        };
+
+        assert_eq!(fitness(true).to_bits(), fitness(false).to_bits());
+    }
+
+    #[test]
+    fn restart_replaces_stagnant_memory() {
+        // Five-piece games clear no rows, so every iteration stagnates
+        let run = |iterations, patience| {
+            let mut solver =
+                HarmonySearch::new(4, iterations, 1.0, 0.0, 0.1).with_restarts(patience, 1);
+            let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+            let _ = solver.optimize_with_rng(
+                5,
+                OptimizeConfig::DEFAULT_BOUNDS,
+                weights::NUM_WEIGHTS,
+                false,
+                1,
+                Aggregation::Mean,
+                ScoringMode::Rows,
+                0,
+                f64::INFINITY,
+                &mut rng,
+                None,
+                &mut io::sink(),
+            );
+            solver.harm_mem
+        };
+        let initial = run(0, 0);
+        let from_initial = |memory: &[[f64; weights::NUM_WEIGHTS]]| {
+            memory
+                .iter()
+                .flatten()
+                .all(|w| initial.iter().flatten().any(|v| v.to_bits() == w.to_bits()))
+        };
+
+        assert!(from_initial(&run(3, 0)));
+        assert!(!from_initial(&run(3, 1)));
+    }
+
+    #[test]
+    fn quiet_solver_prints_no_progress_but_still_logs() {
+        let run = |verbosity| {
+            let mut solver = HarmonySearch::new(3, 4, 0.9, 0.3, 0.1).with_verbosity(verbosity);
+            let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+            let mut log = Vec::new();
+            let mut progress = Vec::new();
+            let _ = solver.optimize_with_rng(
+                5,
+                OptimizeConfig::DEFAULT_BOUNDS,
+                weights::NUM_WEIGHTS,
+                false,
+                1,
+                Aggregation::Mean,
+                ScoringMode::Rows,
+                0,
+                f64::INFINITY,
+                &mut rng,
+                Some(&mut log),
+                &mut progress,
+            );
+            (log, progress)
+        };
+
+        let (log, progress) = run(Verbosity::Quiet);
+        assert!(progress.is_empty());
+        assert_eq!(String::from_utf8_lossy(&log).lines().count(), 4);
+
+        let (_, progress) = run(Verbosity::Normal);
+        assert!(String::from_utf8_lossy(&progress).contains("Iteration 3:"));
     }
 }
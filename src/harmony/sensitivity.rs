@@ -0,0 +1,251 @@
+use rand::SeedableRng;
+
+use crate::agent::simulator::Simulator;
+use crate::eval_fns::eval_names;
+use crate::weights;
+
+/// Fitness lost when a single weight is zeroed.
+#[derive(Debug, Clone)]
+pub struct Sensitivity {
+    pub index: usize,
+    pub name: &'static str,
+    pub fitness_drop: f64,
+}
+
+/// Result of a one-at-a-time sensitivity pass.
+#[derive(Debug, Clone)]
+pub struct SensitivityReport {
+    /// Mean rows cleared with the unmodified weights.
+    pub baseline: f64,
+    /// Weights ordered from most to least important.
+    pub ranking: Vec<Sensitivity>,
+}
+
+/// Ranks the first `n_weights` weights by how much fitness drops when each one
+/// is zeroed. Every variant is played on the same `seeds`, so differences come
+/// from the weights alone.
+#[must_use]
+pub fn sensitivity_ranking(
+    weights: &[f64; weights::NUM_WEIGHTS],
+    sim_length: usize,
+    n_weights: usize,
+    seeds: &[u64],
+) -> SensitivityReport {
+    let baseline = mean_rows(weights, sim_length, n_weights, seeds);
+
+    let mut ranking: Vec<Sensitivity> = (0..n_weights.min(weights::NUM_WEIGHTS))
+        .map(|index| {
+            let mut zeroed = *weights;
+            zeroed[index] = 0.0;
+            Sensitivity {
+                index,
+                name: eval_names()[index],
+                fitness_drop: baseline - mean_rows(&zeroed, sim_length, n_weights, seeds),
+            }
+        })
+        .collect();
+
+    ranking.sort_by(|a, b| b.fitness_drop.total_cmp(&a.fitness_drop));
+
+    SensitivityReport { baseline, ranking }
+}
+
+/// Fitness around a weight when it is nudged by `±delta`.
+#[derive(Debug, Clone)]
+pub struct Perturbation {
+    pub index: usize,
+    pub name: &'static str,
+    pub minus_delta_fitness: f64,
+    pub baseline: f64,
+    pub plus_delta_fitness: f64,
+    /// Central-difference gradient of fitness with respect to the weight.
+    pub sensitivity: f64,
+}
+
+/// Perturbs each of the first `n_weights` weights by `±delta` while holding the
+/// others fixed, and returns the fitness gradients sorted by magnitude.
+///
+/// # Panics
+///
+/// Panics if `delta` is not positive.
+#[must_use]
+pub fn perturbation_sensitivity(
+    weights: &[f64; weights::NUM_WEIGHTS],
+    sim_length: usize,
+    n_weights: usize,
+    seeds: &[u64],
+    delta: f64,
+) -> Vec<Perturbation> {
+    assert!(delta > 0.0, "delta must be > 0");
+    let baseline = mean_rows(weights, sim_length, n_weights, seeds);
+    let nudged = |index: usize, offset: f64| {
+        let mut w = *weights;
+        w[index] += offset;
+        mean_rows(&w, sim_length, n_weights, seeds)
+    };
+
+    let mut results: Vec<Perturbation> = (0..n_weights.min(weights::NUM_WEIGHTS))
+        .map(|index| {
+            let minus_delta_fitness = nudged(index, -delta);
+            let plus_delta_fitness = nudged(index, delta);
+            Perturbation {
+                index,
+                name: eval_names()[index],
+                minus_delta_fitness,
+                baseline,
+                plus_delta_fitness,
+                sensitivity: (plus_delta_fitness - minus_delta_fitness) / (2.0 * delta),
+            }
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.sensitivity.abs().total_cmp(&a.sensitivity.abs()));
+    results
+}
+
+/// Mean fitness at one point of a [`fitness_landscape`] grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LandscapePoint {
+    /// Value of the first swept weight.
+    pub x: f64,
+    /// Value of the second swept weight.
+    pub y: f64,
+    pub fitness: f64,
+}
+
+/// Sweeps two weights over a `steps x steps` grid spanning `range`.
+///
+/// Both ends of the range are included and the other weights stay at
+/// `weights`. Each point holds the mean rows cleared over `seeds`; points are
+/// ordered row by row along `x`.
+///
+/// # Panics
+///
+/// Panics if the axes are equal or not below `NUM_WEIGHTS`, or if `steps < 2`.
+#[must_use]
+pub fn fitness_landscape(
+    weights: &[f64; weights::NUM_WEIGHTS],
+    axes: (usize, usize),
+    range: (f64, f64),
+    steps: usize,
+    sim_length: usize,
+    n_weights: usize,
+    seeds: &[u64],
+) -> Vec<LandscapePoint> {
+    let (x_axis, y_axis) = axes;
+    assert!(
+        x_axis != y_axis && x_axis < weights::NUM_WEIGHTS && y_axis < weights::NUM_WEIGHTS,
+        "axes must be two distinct weight indices"
+    );
+    assert!(steps >= 2, "steps must be >= 2");
+
+    let (min, max) = range;
+    let last = f64::from(u32::try_from(steps - 1).unwrap_or(u32::MAX));
+    let values: Vec<f64> = (0..steps)
+        .map(|i| {
+            let t = f64::from(u32::try_from(i).unwrap_or(u32::MAX)) / last;
+            (max - min).mul_add(t, min)
+        })
+        .collect();
+
+    values
+        .iter()
+        .flat_map(|&x| values.iter().map(move |&y| (x, y)))
+        .map(|(x, y)| {
+            let mut w = *weights;
+            w[x_axis] = x;
+            w[y_axis] = y;
+            LandscapePoint {
+                x,
+                y,
+                fitness: mean_rows(&w, sim_length, n_weights, seeds),
+            }
+        })
+        .collect()
+}
+
+fn mean_rows(
+    weights: &[f64; weights::NUM_WEIGHTS],
+    sim_length: usize,
+    n_weights: usize,
+    seeds: &[u64],
+) -> f64 {
+    if seeds.is_empty() {
+        return 0.0;
+    }
+    let total: u32 = seeds
+        .iter()
+        .map(|&seed| {
+            let sim = Simulator::new(*weights, sim_length).with_n_weights(n_weights);
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            sim.simulate_game_with_rng(&mut rng)
+        })
+        .sum();
+    f64::from(total) / f64::from(u32::try_from(seeds.len()).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranking_is_sorted_and_zero_weights_do_not_matter() {
+        let mut w = [-0.5; weights::NUM_WEIGHTS];
+        w[3] = 0.0;
+        let n_weights = 6;
+
+        let report = sensitivity_ranking(&w, 20, n_weights, &[1, 2]);
+
+        assert_eq!(report.ranking.len(), n_weights);
+        assert!(
+            report
+                .ranking
+                .windows(2)
+                .all(|pair| pair[0].fitness_drop >= pair[1].fitness_drop)
+        );
+        let unused = report
+            .ranking
+            .iter()
+            .find(|s| s.index == 3)
+            .expect("weight 3 should be ranked");
+        assert!(unused.fitness_drop.abs() < f64::EPSILON);
+        assert_eq!(unused.name, "altitude_diff");
+    }
+
+    #[test]
+    fn landscape_covers_the_grid_and_holds_other_weights() {
+        let w = [-0.5; weights::NUM_WEIGHTS];
+        let seeds = [3];
+
+        let points = fitness_landscape(&w, (0, 1), (-1.0, 1.0), 2, 20, 6, &seeds);
+
+        let cells: Vec<(f64, f64)> = points.iter().map(|p| (p.x, p.y)).collect();
+        assert_eq!(cells, [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)]);
+        for p in &points {
+            let mut expected = w;
+            expected[0] = p.x;
+            expected[1] = p.y;
+            let direct = mean_rows(&expected, 20, 6, &seeds);
+            assert!((p.fitness - direct).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn perturbations_are_sorted_by_magnitude() {
+        let w = [-0.5; weights::NUM_WEIGHTS];
+        let n_weights = 4;
+
+        let results = perturbation_sensitivity(&w, 20, n_weights, &[1, 2], 0.25);
+
+        assert_eq!(results.len(), n_weights);
+        assert!(
+            results
+                .windows(2)
+                .all(|pair| pair[0].sensitivity.abs() >= pair[1].sensitivity.abs())
+        );
+        for p in &results {
+            let expected = (p.plus_delta_fitness - p.minus_delta_fitness) / 0.5;
+            assert!((p.sensitivity - expected).abs() < 1e-9);
+        }
+    }
+}
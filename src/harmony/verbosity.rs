@@ -0,0 +1,23 @@
+use std::fmt;
+use std::io::Write;
+
+/// How much an optimizer prints while it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verbosity {
+    /// Only the final result line.
+    Quiet,
+    /// Startup banner, per-iteration progress and result details.
+    #[default]
+    Normal,
+}
+
+impl Verbosity {
+    /// Writes one progress line to `out`, unless quiet.
+    ///
+    /// Progress is best-effort, so write errors are ignored.
+    pub(crate) fn progress(self, out: &mut dyn Write, args: fmt::Arguments<'_>) {
+        if self == Self::Normal {
+            let _ = writeln!(out, "{args}");
+        }
+    }
+}
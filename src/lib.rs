@@ -2,6 +2,14 @@ pub mod agent;
 pub mod cli;
 pub mod eval_fns;
 pub mod game;
+#[cfg(feature = "native")]
 pub mod harmony;
+pub mod prelude;
+pub mod records;
+#[cfg(feature = "image-export")]
+pub mod render;
+pub mod stats;
+#[cfg(feature = "native")]
 pub mod tui;
+pub mod wasm;
 pub mod weights;
@@ -0,0 +1,27 @@
+//! Commonly used types and functions, for glob import.
+//!
+//! ```
+//! use harmonomino::prelude::*;
+//!
+//! let mut weights = [0.0; NUM_WEIGHTS];
+//! weights[0] = -1.0;
+//! weights[1] = -4.0;
+//!
+//! let board = Board::new();
+//! assert!(find_best_move(&board, Tetromino::T, &weights, NUM_WEIGHTS).is_some());
+//!
+//! let rows = Simulator::new(weights, 20)
+//!     .with_scoring(ScoringMode::Rows)
+//!     .simulate_game();
+//! assert!(rows <= 20 * 4 / Board::WIDTH as u32);
+//! ```
+
+pub use crate::agent::simulator::Simulator;
+pub use crate::agent::{GameStats, Placement, ScoringMode, find_best_move, find_best_placement};
+pub use crate::eval_fns::calculate_weighted_score;
+pub use crate::game::{Board, FallingPiece, GameState, MoveResult, Rotation, Tetromino};
+#[cfg(feature = "native")]
+pub use crate::harmony::{CeConfig, OptimizeConfig, optimize_weights, optimize_weights_ce};
+pub use crate::weights::{NUM_WEIGHTS, WeightsError, normalize as normalize_weights};
+#[cfg(feature = "native")]
+pub use crate::weights::{load as load_weights, save as save_weights};
@@ -0,0 +1,569 @@
+//! Per-game evaluation records, their CSV/JSON serialization, seed lists and result file naming.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::agent::simulator::ScoringMode;
+
+/// Outcome of one evaluation game for one weight set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalRecord {
+    pub weight_id: String,
+    pub seed: u64,
+    pub rows_cleared: u32,
+    /// Number of single, double, triple and tetris clears.
+    pub clears_by_size: [u32; 4],
+    pub scoring_mode: ScoringMode,
+    pub fitness: f64,
+}
+
+impl EvalRecord {
+    /// Header row matching [`EvalRecord::to_csv`].
+    pub const CSV_HEADER: &str =
+        "weight_id,seed,rows_cleared,singles,doubles,triples,tetrises,scoring_mode,fitness";
+
+    /// Formats the record as one CSV row (without a trailing newline).
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let [singles, doubles, triples, tetrises] = self.clears_by_size;
+        format!(
+            "{},{},{},{singles},{doubles},{triples},{tetrises},{},{}",
+            self.weight_id, self.seed, self.rows_cleared, self.scoring_mode, self.fitness
+        )
+    }
+
+    /// Formats the record as a JSON object.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let fitness = if self.fitness.is_finite() {
+            self.fitness.to_string()
+        } else {
+            "null".to_string()
+        };
+        let [singles, doubles, triples, tetrises] = self.clears_by_size;
+        format!(
+            r#"{{"weight_id":{},"scoring_mode":"{}","seed":{},"rows_cleared":{},"singles":{singles},"doubles":{doubles},"triples":{triples},"tetrises":{tetrises},"fitness":{fitness}}}"#,
+            json_string(&self.weight_id),
+            self.scoring_mode,
+            self.seed,
+            self.rows_cleared,
+        )
+    }
+}
+
+/// Writes `records` as CSV with a header row.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_csv<W: Write>(mut writer: W, records: &[EvalRecord]) -> io::Result<()> {
+    writeln!(writer, "{}", EvalRecord::CSV_HEADER)?;
+    for record in records {
+        writeln!(writer, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Appends records to an eval CSV one row at a time, so an interrupted run
+/// can resume where it stopped.
+#[derive(Debug)]
+pub struct CsvAppender {
+    writer: BufWriter<File>,
+    done: HashSet<(String, u64)>,
+}
+
+impl CsvAppender {
+    /// Opens `path` for appending, creating it with a header if it is missing
+    /// or empty. Rows already in the file are remembered by `(weight_id, seed)`,
+    /// and an unfinished last line left by a crash is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the file has a different header or a
+    /// row without a valid seed, or an I/O error if it cannot be read or written.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let complete = content.rfind('\n').map_or("", |end| &content[..=end]);
+
+        let mut done = HashSet::new();
+        let mut lines = complete.lines();
+        if let Some(header) = lines.next()
+            && header != EvalRecord::CSV_HEADER
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} has a different header: {header}", path.display()),
+            ));
+        }
+        for line in lines {
+            let mut fields = line.split(',');
+            let weight_id = fields.next().unwrap_or_default();
+            let seed = fields
+                .next()
+                .and_then(|seed| seed.parse().ok())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid row in {}: {line}", path.display()),
+                    )
+                })?;
+            done.insert((weight_id.to_string(), seed));
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        file.set_len(complete.len() as u64)?;
+        let mut writer = BufWriter::new(file);
+        if complete.is_empty() {
+            writeln!(writer, "{}", EvalRecord::CSV_HEADER)?;
+            writer.flush()?;
+        }
+        Ok(Self { writer, done })
+    }
+
+    /// Whether the file already holds a row for `weight_id` and `seed`.
+    #[must_use]
+    pub fn contains(&self, weight_id: &str, seed: u64) -> bool {
+        self.done.contains(&(weight_id.to_string(), seed))
+    }
+
+    /// Writes one row and flushes it to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn append(&mut self, record: &EvalRecord) -> io::Result<()> {
+        writeln!(self.writer, "{}", record.to_csv())?;
+        self.writer.flush()?;
+        self.done.insert((record.weight_id.clone(), record.seed));
+        Ok(())
+    }
+}
+
+/// Writes `records` as a JSON array of objects, one per line.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_json<W: Write>(mut writer: W, records: &[EvalRecord]) -> io::Result<()> {
+    writeln!(writer, "[")?;
+    for (i, record) in records.iter().enumerate() {
+        let separator = if i + 1 < records.len() { "," } else { "" };
+        writeln!(writer, "  {}{separator}", record.to_json())?;
+    }
+    writeln!(writer, "]")
+}
+
+/// Creates a new file named `<stem>_<timestamp>.<extension>` in `dir`,
+/// creating `dir` if needed. The timestamp is UTC, e.g. `20240101T120000`.
+///
+/// Never overwrites: if the name is taken, a `_2`, `_3`, ... suffix is added.
+///
+/// # Errors
+///
+/// Returns an error if the directory or file cannot be created.
+pub fn create_timestamped(dir: &Path, stem: &str, extension: &str) -> io::Result<(PathBuf, File)> {
+    fs::create_dir_all(dir)?;
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let base = format!("{stem}_{}", format_timestamp(secs));
+
+    for attempt in 1u32.. {
+        let name = if attempt == 1 {
+            format!("{base}.{extension}")
+        } else {
+            format!("{base}_{attempt}.{extension}")
+        };
+        let path = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+    unreachable!("ran out of file name suffixes")
+}
+
+/// Parses a comma-separated seed list such as `1, 2, 3`.
+///
+/// Empty entries (e.g. from a trailing comma) are skipped, and anything after
+/// a `#` on a line is a comment.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error if an entry is not a number or the list
+/// holds no seeds.
+pub fn parse_seeds_csv(value: &str) -> io::Result<Vec<u64>> {
+    let seeds = value
+        .lines()
+        .filter_map(|line| line.split('#').next())
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry.parse::<u64>().map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid seed '{entry}': {e}"),
+                )
+            })
+        })
+        .collect::<io::Result<Vec<u64>>>()?;
+    if seeds.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "seeds CSV must not be empty",
+        ));
+    }
+    Ok(seeds)
+}
+
+/// Reads one seed per line, skipping blank lines and `#` comments.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, a line is not a number, or
+/// the file holds no seeds.
+pub fn parse_seeds_file(path: &Path) -> io::Result<Vec<u64>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let mut seeds = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let seed: u64 = trimmed.parse().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid seed '{trimmed}': {e}"),
+            )
+        })?;
+        seeds.push(seed);
+    }
+    if seeds.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "seeds file did not contain any seeds",
+        ));
+    }
+    Ok(seeds)
+}
+
+/// Expands a `START:END[:STEP]` seed range (end exclusive, step 1 by default).
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error if a part is not a number, or unless
+/// `START < END` and `STEP > 0`.
+pub fn parse_seed_range(spec: &str) -> io::Result<Vec<u64>> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+    let parts: Vec<&str> = spec.split(':').collect();
+    if !(2..=3).contains(&parts.len()) {
+        return Err(invalid(format!(
+            "invalid seed range '{spec}': expected START:END or START:END:STEP"
+        )));
+    }
+    let numbers = parts
+        .iter()
+        .map(|part| {
+            part.trim()
+                .parse::<u64>()
+                .map_err(|e| invalid(format!("invalid seed range '{spec}': '{part}': {e}")))
+        })
+        .collect::<io::Result<Vec<u64>>>()?;
+    let (start, end) = (numbers[0], numbers[1]);
+    let step = numbers.get(2).copied().unwrap_or(1);
+
+    if start >= end {
+        return Err(invalid(format!(
+            "invalid seed range '{spec}': START ({start}) must be < END ({end})"
+        )));
+    }
+    if step == 0 {
+        return Err(invalid(format!(
+            "invalid seed range '{spec}': STEP must be > 0"
+        )));
+    }
+    let step = usize::try_from(step).unwrap_or(usize::MAX);
+    Ok((start..end).step_by(step).collect())
+}
+
+/// Formats seconds since the Unix epoch as a compact UTC timestamp (`YYYYMMDDTHHMMSS`).
+fn format_timestamp(secs: u64) -> String {
+    let days = secs / 86_400;
+    let time = secs % 86_400;
+    // Civil-from-days conversion (Howard Hinnant), valid for dates after 1970
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// Quotes and escapes `value` as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_csv_skips_trailing_commas_and_comments() {
+        assert_eq!(parse_seeds_csv("1,2,3,").expect("valid list"), [1, 2, 3]);
+        assert_eq!(
+            parse_seeds_csv("1, 2, 3  # notes").expect("valid list"),
+            [1, 2, 3]
+        );
+        assert_eq!(parse_seeds_csv("4,,5").expect("valid list"), [4, 5]);
+    }
+
+    #[test]
+    fn seeds_csv_rejects_invalid_and_empty_lists() {
+        for value in ["1,x,3", "1;2", "", " , ", "# only a comment"] {
+            let err = parse_seeds_csv(value).expect_err(value);
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn seed_range_expands_with_optional_step() {
+        assert_eq!(
+            parse_seed_range("0:5").expect("valid range"),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            parse_seed_range("0:10:2").expect("valid range"),
+            [0, 2, 4, 6, 8]
+        );
+        assert_eq!(parse_seed_range("7:8").expect("valid range"), [7]);
+    }
+
+    #[test]
+    fn seed_range_rejects_bad_bounds() {
+        for spec in ["5:5", "6:2", "0:10:0", "0", "0:1:2:3", "a:5", "0:-1"] {
+            let err = parse_seed_range(spec).expect_err(spec);
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{spec}");
+        }
+    }
+
+    fn records() -> Vec<EvalRecord> {
+        vec![
+            EvalRecord {
+                weight_id: "hsa_0".to_string(),
+                seed: 1000,
+                rows_cleared: 42,
+                clears_by_size: [10, 4, 0, 6],
+                scoring_mode: ScoringMode::Rows,
+                fitness: 42.0,
+            },
+            EvalRecord {
+                weight_id: "ce_1".to_string(),
+                seed: 1001,
+                rows_cleared: 7,
+                clears_by_size: [7, 0, 0, 0],
+                scoring_mode: ScoringMode::Survival,
+                fitness: 7.3,
+            },
+        ]
+    }
+
+    fn parse_fields(record: &[(String, String)]) -> EvalRecord {
+        let get = |key: &str| {
+            record
+                .iter()
+                .find(|(k, _)| k == key)
+                .map_or_else(|| panic!("missing field {key}"), |(_, v)| v.as_str())
+        };
+        EvalRecord {
+            weight_id: get("weight_id").to_string(),
+            seed: get("seed").parse().expect("seed"),
+            rows_cleared: get("rows_cleared").parse().expect("rows_cleared"),
+            clears_by_size: ["singles", "doubles", "triples", "tetrises"]
+                .map(|key| get(key).parse().expect(key)),
+            scoring_mode: get("scoring_mode").parse().expect("scoring_mode"),
+            fitness: get("fitness").parse().expect("fitness"),
+        }
+    }
+
+    fn parse_csv(text: &str) -> Vec<EvalRecord> {
+        let mut lines = text.lines();
+        let header: Vec<String> = lines
+            .next()
+            .expect("header")
+            .split(',')
+            .map(String::from)
+            .collect();
+        lines
+            .map(|line| {
+                let fields: Vec<(String, String)> = header
+                    .iter()
+                    .cloned()
+                    .zip(line.split(',').map(String::from))
+                    .collect();
+                parse_fields(&fields)
+            })
+            .collect()
+    }
+
+    /// Parses the flat objects produced by [`write_json`] (no nesting or escapes).
+    fn parse_json(text: &str) -> Vec<EvalRecord> {
+        text.split('{')
+            .skip(1)
+            .map(|object| {
+                let body = object.split('}').next().expect("object body");
+                let fields: Vec<(String, String)> = body
+                    .split(',')
+                    .map(|pair| {
+                        let (key, value) = pair.split_once(':').expect("key:value");
+                        (
+                            key.trim().trim_matches('"').to_string(),
+                            value.trim().trim_matches('"').to_string(),
+                        )
+                    })
+                    .collect();
+                parse_fields(&fields)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn json_and_csv_round_trip_to_the_same_records() {
+        let records = records();
+        let mut csv = Vec::new();
+        let mut json = Vec::new();
+        write_csv(&mut csv, &records).expect("write csv");
+        write_json(&mut json, &records).expect("write json");
+
+        let from_csv = parse_csv(&String::from_utf8(csv).expect("utf8"));
+        let from_json = parse_json(&String::from_utf8(json).expect("utf8"));
+
+        assert_eq!(from_csv, records);
+        assert_eq!(from_json, records);
+    }
+
+    #[test]
+    fn timestamps_are_utc_calendar_dates() {
+        assert_eq!(format_timestamp(0), "19700101T000000");
+        assert_eq!(format_timestamp(1_704_110_400), "20240101T120000");
+        assert_eq!(format_timestamp(1_709_164_799), "20240228T235959");
+        assert_eq!(format_timestamp(1_709_251_200), "20240301T000000");
+    }
+
+    fn record(weight_id: &str, seed: u64) -> EvalRecord {
+        EvalRecord {
+            weight_id: weight_id.to_string(),
+            seed,
+            rows_cleared: 1,
+            clears_by_size: [1, 0, 0, 0],
+            scoring_mode: ScoringMode::Rows,
+            fitness: 1.0,
+        }
+    }
+
+    #[test]
+    fn appender_resumes_with_only_the_missing_seeds() {
+        let path =
+            std::env::temp_dir().join(format!("harmonomino-append-{}.csv", std::process::id()));
+        let partial = [record("a", 0), record("a", 2)];
+        let mut existing = Vec::new();
+        write_csv(&mut existing, &partial).expect("write");
+        // A crash mid-row leaves an unfinished last line behind
+        existing.extend_from_slice(b"a,3,1,1,0");
+        fs::write(&path, existing).expect("seed file");
+
+        let mut appender = CsvAppender::open(&path).expect("open");
+        let mut computed = Vec::new();
+        for seed in 0..5 {
+            if !appender.contains("a", seed) {
+                computed.push(seed);
+                appender.append(&record("a", seed)).expect("append");
+            }
+        }
+        drop(appender);
+        let content = fs::read_to_string(&path).expect("read");
+        fs::remove_file(&path).expect("cleanup");
+
+        assert_eq!(computed, [1, 3, 4]);
+        let seeds: Vec<&str> = content
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').nth(1).expect("seed"))
+            .collect();
+        assert_eq!(seeds, ["0", "2", "1", "3", "4"]);
+        assert_eq!(content.lines().next(), Some(EvalRecord::CSV_HEADER));
+    }
+
+    #[test]
+    fn appender_rejects_a_foreign_header() {
+        let path =
+            std::env::temp_dir().join(format!("harmonomino-foreign-{}.csv", std::process::id()));
+        fs::write(&path, "seed,rows\n1,2\n").expect("seed file");
+        let err = CsvAppender::open(&path).expect_err("different header");
+        fs::remove_file(&path).expect("cleanup");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn timestamped_files_never_collide() {
+        let dir = std::env::temp_dir().join(format!("harmonomino-records-{}", std::process::id()));
+        let (first, _) = create_timestamped(&dir, "optimized_weights", "csv").expect("first");
+        let (second, _) = create_timestamped(&dir, "optimized_weights", "csv").expect("second");
+        fs::remove_dir_all(&dir).expect("cleanup");
+
+        assert_ne!(first, second);
+        let name = first.file_name().and_then(|n| n.to_str()).expect("name");
+        assert!(name.starts_with("optimized_weights_"), "{name}");
+        assert_eq!(first.extension().and_then(|e| e.to_str()), Some("csv"));
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(json_string("tab\t"), r#""tab\t""#);
+    }
+
+    #[test]
+    fn empty_json_is_an_empty_array() {
+        let mut json = Vec::new();
+        write_json(&mut json, &[]).expect("write json");
+        assert_eq!(String::from_utf8(json).expect("utf8"), "[\n]\n");
+    }
+}
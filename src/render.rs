@@ -0,0 +1,96 @@
+//! Image export of boards for papers and documentation.
+
+use std::fmt::Write as _;
+
+use crate::game::{Board, FallingPiece};
+
+/// Side length of one cell in SVG user units.
+pub const CELL_SIZE: usize = 20;
+
+/// Fill color of locked cells.
+const LOCKED_FILL: &str = "#a0a0a0";
+
+/// Background color of the playfield.
+const BACKGROUND_FILL: &str = "#111111";
+
+/// Renders `board` as an SVG document, drawing `piece` on top in its color.
+///
+/// Every occupied cell becomes one `<rect class="cell">`, so the output is
+/// easy to post-process.
+#[must_use]
+pub fn to_svg(board: &Board, piece: Option<&FallingPiece>) -> String {
+    let width = board.width() * CELL_SIZE;
+    let height = board.height() * CELL_SIZE;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"  <rect width="{width}" height="{height}" fill="{BACKGROUND_FILL}"/>"#
+    );
+
+    for (row, cells) in board.rows_bottom_up() {
+        for (col, _) in cells.iter().enumerate().filter(|&(_, &occupied)| occupied) {
+            push_cell(&mut svg, board, col, row, LOCKED_FILL);
+        }
+    }
+
+    if let Some(piece) = piece {
+        let (r, g, b) = piece.tetromino.rgb();
+        let fill = format!("#{r:02x}{g:02x}{b:02x}");
+        for (col, row) in piece.cells() {
+            if let (Ok(col), Ok(row)) = (usize::try_from(col), usize::try_from(row))
+                && col < board.width()
+                && row < board.height()
+            {
+                push_cell(&mut svg, board, col, row, &fill);
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Appends one cell, flipping rows so row 0 is drawn at the bottom.
+fn push_cell(svg: &mut String, board: &Board, col: usize, row: usize, fill: &str) {
+    let x = col * CELL_SIZE;
+    let y = (board.height() - 1 - row) * CELL_SIZE;
+    let _ = writeln!(
+        svg,
+        r#"  <rect class="cell" x="{x}" y="{y}" width="{CELL_SIZE}" height="{CELL_SIZE}" fill="{fill}"/>"#
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Tetromino;
+
+    #[test]
+    fn svg_has_one_rect_per_filled_cell() {
+        let mut board = Board::new();
+        board[0].fill(true);
+        board[0][4] = false;
+        board[1][2] = true;
+        let piece = FallingPiece::spawn(Tetromino::T);
+
+        let svg = to_svg(&board, Some(&piece));
+
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches(r#"class="cell""#).count(), 10 + 4);
+        assert_eq!(svg.matches("#a000f0").count(), 4);
+    }
+
+    #[test]
+    fn bottom_row_is_drawn_last() {
+        let mut board = Board::new();
+        board[0][0] = true;
+        let svg = to_svg(&board, None);
+        let y = (Board::HEIGHT - 1) * CELL_SIZE;
+        assert!(svg.contains(&format!(r#"x="0" y="{y}""#)));
+    }
+}
@@ -0,0 +1,217 @@
+//! Statistical tests and summaries for samples of game scores.
+
+/// Result of a two-sided Mann–Whitney U test.
+#[derive(Debug, Clone, Copy)]
+pub struct MannWhitney {
+    /// U statistic of the first sample.
+    pub u: f64,
+    /// Normal-approximation z-score (tie-corrected).
+    pub z: f64,
+    /// Two-sided p-value.
+    pub p_value: f64,
+}
+
+/// Runs a two-sided Mann–Whitney U test on `sample_a` and `sample_b`.
+///
+/// Uses the normal approximation with a tie correction, which is accurate
+/// for samples of roughly 20 or more values each. Returns a p-value of 1.0
+/// when either sample is empty or all values are tied.
+#[must_use]
+pub fn mann_whitney_u(sample_a: &[f64], sample_b: &[f64]) -> MannWhitney {
+    let n1 = len_f64(sample_a);
+    let n2 = len_f64(sample_b);
+    if sample_a.is_empty() || sample_b.is_empty() {
+        return MannWhitney {
+            u: 0.0,
+            z: 0.0,
+            p_value: 1.0,
+        };
+    }
+
+    let mut combined: Vec<(f64, bool)> = sample_a
+        .iter()
+        .map(|&v| (v, true))
+        .chain(sample_b.iter().map(|&v| (v, false)))
+        .collect();
+    combined.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // Assign average ranks to tied groups
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < combined.len() {
+        let mut end = start;
+        while end + 1 < combined.len() && combined[end + 1].0.total_cmp(&combined[start].0).is_eq()
+        {
+            end += 1;
+        }
+        let group = &combined[start..=end];
+        let avg_rank = f64::midpoint(to_f64(start + 1), to_f64(end + 1));
+        let size = len_f64(group);
+        tie_term += size.powi(3) - size;
+        rank_sum_a += avg_rank * to_f64(group.iter().filter(|&&(_, in_a)| in_a).count());
+        start = end + 1;
+    }
+
+    let n = n1 + n2;
+    let u = n1.mul_add(-(n1 + 1.0) / 2.0, rank_sum_a);
+    let mean = n1 * n2 / 2.0;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+
+    if variance <= 0.0 {
+        return MannWhitney {
+            u,
+            z: 0.0,
+            p_value: 1.0,
+        };
+    }
+
+    let z = (u - mean) / variance.sqrt();
+    let p_value = erfc(z.abs() / std::f64::consts::SQRT_2).min(1.0);
+    MannWhitney { u, z, p_value }
+}
+
+/// Spread of a sample of scores around its mean.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleSummary {
+    pub mean: f64,
+    /// Sample standard deviation (n - 1 denominator).
+    pub std_dev: f64,
+    /// Lower bound of the 95% confidence interval of the mean.
+    pub ci_low: f64,
+    /// Upper bound of the 95% confidence interval of the mean.
+    pub ci_high: f64,
+}
+
+/// Summarizes `samples` with a Student-t 95% confidence interval of the mean.
+///
+/// Returns `None` for fewer than two samples, where the spread is undefined.
+#[must_use]
+pub fn summarize(samples: &[f64]) -> Option<SampleSummary> {
+    if samples.len() < 2 {
+        return None;
+    }
+    let n = len_f64(samples);
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    let half_width = t_critical_95(samples.len() - 1) * std_dev / n.sqrt();
+    Some(SampleSummary {
+        mean,
+        std_dev,
+        ci_low: mean - half_width,
+        ci_high: mean + half_width,
+    })
+}
+
+/// Two-sided 95% critical value of Student's t with `df` degrees of freedom.
+///
+/// Exact to three decimals up to 30 degrees of freedom; larger samples use
+/// the next lower tabulated value, falling back to the normal 1.96.
+const fn t_critical_95(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    match df {
+        0 => f64::INFINITY,
+        1..=30 => TABLE[df - 1],
+        31..=40 => 2.042,
+        41..=60 => 2.021,
+        61..=120 => 2.000,
+        _ => 1.96,
+    }
+}
+
+/// Complementary error function (Chebyshev approximation, relative error < 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / 0.5f64.mul_add(x.abs(), 1.0);
+    let poly = [
+        -1.265_512_23,
+        1.000_023_68,
+        0.374_091_96,
+        0.096_784_18,
+        -0.186_288_06,
+        0.278_868_07,
+        -1.135_203_98,
+        1.488_515_87,
+        -0.822_152_23,
+        0.170_872_77,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0_f64, |acc, &c| acc.mul_add(t, c));
+    let ans = t * (-x).mul_add(x, poly).exp();
+    if x >= 0.0 { ans } else { 2.0 - ans }
+}
+
+fn len_f64<T>(values: &[T]) -> f64 {
+    to_f64(values.len())
+}
+
+fn to_f64(n: usize) -> f64 {
+    f64::from(u32::try_from(n).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarize_known_sample() {
+        let s = summarize(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).expect("two or more");
+        assert!((s.mean - 5.0).abs() < 1e-12);
+        // Sum of squared deviations is 32 over 7 degrees of freedom
+        assert!((s.std_dev - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+        let half = 2.365 * s.std_dev / 8.0_f64.sqrt();
+        assert!((s.ci_low - (5.0 - half)).abs() < 1e-12);
+        assert!((s.ci_high - (5.0 + half)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn summarize_needs_two_samples() {
+        assert!(summarize(&[]).is_none());
+        assert!(summarize(&[3.0]).is_none());
+        let s = summarize(&[3.0, 3.0]).expect("two samples");
+        assert!(s.std_dev.abs() < f64::EPSILON);
+        assert!((s.ci_low - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn erfc_matches_known_values() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157_299_2).abs() < 1e-6);
+        assert!((erfc(-1.0) - 1.842_700_8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn identical_samples_are_not_significant() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = mann_whitney_u(&a, &a);
+        assert!((result.u - 12.5).abs() < 1e-9);
+        assert!(result.p_value > 0.99);
+    }
+
+    #[test]
+    fn separated_samples_are_significant() {
+        let a: Vec<f64> = (0..30).map(f64::from).collect();
+        let b: Vec<f64> = (100..130).map(f64::from).collect();
+        let result = mann_whitney_u(&a, &b);
+        assert!(result.u.abs() < 1e-9);
+        assert!(result.z < 0.0);
+        assert!(result.p_value < 1e-6);
+    }
+
+    #[test]
+    fn all_ties_give_p_of_one() {
+        let result = mann_whitney_u(&[3.0; 4], &[3.0; 6]);
+        assert!((result.p_value - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn empty_sample_gives_p_of_one() {
+        let result = mann_whitney_u(&[], &[1.0]);
+        assert!((result.p_value - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -1,19 +1,154 @@
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use ratatui::Frame;
+use ratatui::crossterm::event::KeyCode;
 
+use crate::agent::find_best_placement;
 use crate::game::{GamePhase, GameState};
+use crate::weights;
 
 use super::event_loop::TuiApp;
-use super::ui;
+use super::theme::Theme;
+use super::ui::{self, ColorScheme};
+
+/// What the Down key does in the solo TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropMode {
+    /// Move the piece down one row.
+    #[default]
+    Soft,
+    /// Drop the piece to the bottom without locking it.
+    Sonic,
+    /// Ignore Down; only hard drop moves the piece down.
+    HardOnly,
+}
+
+impl FromStr for DropMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "soft" => Ok(Self::Soft),
+            "sonic" => Ok(Self::Sonic),
+            "hard-only" => Ok(Self::HardOnly),
+            other => Err(format!(
+                "unknown drop mode '{other}': expected soft, sonic, or hard-only"
+            )),
+        }
+    }
+}
+
+/// An entry of the solo pause menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseEntry {
+    Resume,
+    Restart,
+    Quit,
+}
+
+impl PauseEntry {
+    /// Menu entries, top to bottom.
+    pub const ALL: [Self; 3] = [Self::Resume, Self::Restart, Self::Quit];
+
+    /// Returns the text shown for this entry.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Resume => "Resume",
+            Self::Restart => "Restart",
+            Self::Quit => "Quit",
+        }
+    }
+}
+
+/// Amount a weight changes per +/- press in tuning mode.
+pub const WEIGHT_STEP: f64 = 0.1;
+
+/// Ticks cleared rows stay highlighted before they collapse.
+pub const FLASH_TICKS: u8 = 2;
+
+/// Tick rate while cleared rows flash, giving a flash of about 150ms.
+pub const FLASH_TICK_RATE: Duration = Duration::from_millis(75);
+
+/// A piece action, buffered while cleared rows flash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Input {
+    Left,
+    Right,
+    SoftDrop,
+    HardDrop,
+    RotateCw,
+    RotateCcw,
+}
+
+/// Holds back the collapse of cleared rows for [`FLASH_TICKS`] ticks and
+/// keeps the input pressed meanwhile.
+#[derive(Debug, Default)]
+pub struct ClearFlash {
+    ticks_left: u8,
+    buffered: Vec<Input>,
+}
+
+impl ClearFlash {
+    /// Returns true while rows are flashing.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.ticks_left > 0
+    }
+
+    /// Starts a flash of [`FLASH_TICKS`] ticks.
+    pub const fn start(&mut self) {
+        self.ticks_left = FLASH_TICKS;
+    }
+
+    /// Queues `input` to be replayed once the rows collapse.
+    pub fn buffer(&mut self, input: Input) {
+        self.buffered.push(input);
+    }
+
+    /// Advances the flash by one tick.
+    ///
+    /// Returns the buffered input, in order, on the tick the flash ends and
+    /// `None` otherwise.
+    pub fn tick(&mut self) -> Option<Vec<Input>> {
+        if !self.is_active() {
+            return None;
+        }
+        self.ticks_left -= 1;
+        (self.ticks_left == 0).then(|| std::mem::take(&mut self.buffered))
+    }
+}
 
 /// Application state wrapping `GameState` with timing for the TUI.
+#[allow(clippy::struct_excessive_bools)]
 pub struct App {
     pub game: GameState,
     pub last_tick: Instant,
     pub tick_rate: Duration,
     pub should_quit: bool,
     pub paused: bool,
+    /// Selected entry of the pause menu, indexing [`PauseEntry::ALL`].
+    pub menu_index: usize,
+    pub show_grid: bool,
+    pub show_eval: bool,
+    pub drop_mode: DropMode,
+    pub color_scheme: ColorScheme,
+    /// Colors loaded with `--theme`, overriding the palette's.
+    pub theme: Option<Theme>,
+    /// Agent weights used for the eval-debug panel's weighted contributions.
+    pub weights: Option<[f64; weights::NUM_WEIGHTS]>,
+    /// Whether the agent is playing while the weights are edited live.
+    pub tuning: bool,
+    /// Selected weight in tuning mode.
+    pub weight_index: usize,
+    /// Where the tuning mode saves weights.
+    pub weights_path: PathBuf,
+    /// Result of the last save, shown in the tuning panel.
+    pub status: Option<String>,
+    /// Flash of the rows cleared by the last lock, if one is running.
+    pub flash: ClearFlash,
 }
 
 impl App {
@@ -21,13 +156,205 @@ impl App {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            game: GameState::new(),
+            game: new_game(),
             last_tick: Instant::now(),
             tick_rate: Duration::from_millis(500),
             should_quit: false,
             paused: false,
+            menu_index: 0,
+            show_grid: false,
+            show_eval: false,
+            drop_mode: DropMode::Soft,
+            color_scheme: ColorScheme::Classic,
+            theme: None,
+            weights: None,
+            tuning: false,
+            weight_index: 0,
+            weights_path: PathBuf::from("weights.txt"),
+            status: None,
+            flash: ClearFlash::default(),
+        }
+    }
+
+    /// Sets what the Down key does.
+    #[must_use]
+    pub const fn with_drop_mode(mut self, drop_mode: DropMode) -> Self {
+        self.drop_mode = drop_mode;
+        self
+    }
+
+    /// Sets the palette used to draw pieces.
+    #[must_use]
+    pub const fn with_color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = color_scheme;
+        self
+    }
+
+    /// Draws with `theme` instead of the palette's colors.
+    #[must_use]
+    pub const fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the loaded theme, or the colors of the current palette.
+    #[must_use]
+    pub fn theme(&self) -> Theme {
+        self.theme
+            .unwrap_or_else(|| Theme::from_scheme(self.color_scheme))
+    }
+
+    /// Drops a loaded theme to show the current palette, or switches to the
+    /// other palette when no theme is loaded.
+    pub const fn toggle_palette(&mut self) {
+        if self.theme.take().is_none() {
+            self.color_scheme = self.color_scheme.toggled();
+        }
+    }
+
+    /// Sets the weights shown in the eval-debug panel.
+    #[must_use]
+    pub const fn with_weights(mut self, weights: [f64; weights::NUM_WEIGHTS]) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Sets where the tuning mode saves weights (default: `weights.txt`).
+    #[must_use]
+    pub fn with_weights_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.weights_path = path.into();
+        self
+    }
+
+    /// Enters or leaves tuning mode. Without loaded weights, tuning starts from zeros.
+    fn toggle_tuning(&mut self) {
+        self.tuning = !self.tuning;
+        if self.tuning {
+            self.weights.get_or_insert([0.0; weights::NUM_WEIGHTS]);
         }
+        self.status = None;
     }
+
+    /// Adds `delta` to the selected weight; the agent uses it from its next piece.
+    fn nudge_weight(&mut self, delta: f64) {
+        if let Some(w) = self.weights.as_mut() {
+            w[self.weight_index] += delta;
+        }
+    }
+
+    /// Writes the current weights to [`App::weights_path`].
+    fn save_weights(&mut self) {
+        let Some(w) = self.weights else {
+            return;
+        };
+        self.status = Some(match weights::save(&self.weights_path, &w) {
+            Ok(()) => format!("Saved to {}", self.weights_path.display()),
+            Err(e) => format!("Save failed: {e}"),
+        });
+    }
+
+    /// Places the current piece where the agent would, using the current weights.
+    fn agent_place(&mut self) {
+        let (Some(piece), Some(w)) = (self.game.current, self.weights) else {
+            return;
+        };
+        if let Some(placement) =
+            find_best_placement(&self.game.board, piece.tetromino, &w, weights::NUM_WEIGHTS)
+        {
+            self.game.current = Some(placement.piece);
+        }
+        self.game.hard_drop();
+    }
+
+    /// Applies `input` to the game, or buffers it while rows flash.
+    fn play(&mut self, input: Input) {
+        if self.paused {
+            return;
+        }
+        if self.flash.is_active() {
+            self.flash.buffer(input);
+            return;
+        }
+        if !self.game.is_active() {
+            return;
+        }
+        match input {
+            Input::Left => {
+                self.game.move_left();
+            }
+            Input::Right => {
+                self.game.move_right();
+            }
+            Input::SoftDrop => match self.drop_mode {
+                DropMode::Soft => {
+                    self.game.move_down();
+                }
+                DropMode::Sonic => {
+                    self.game.sonic_drop();
+                }
+                DropMode::HardOnly => {}
+            },
+            Input::HardDrop => {
+                self.game.hard_drop();
+            }
+            Input::RotateCw => {
+                self.game.rotate_cw();
+            }
+            Input::RotateCcw => {
+                self.game.rotate_ccw();
+            }
+        }
+        self.start_flash_if_clearing();
+    }
+
+    /// Starts the flash once a lock has left full rows on the board.
+    fn start_flash_if_clearing(&mut self) {
+        if self.game.phase == GamePhase::Clearing && !self.flash.is_active() {
+            self.flash.start();
+            self.last_tick = Instant::now();
+        }
+    }
+
+    /// Advances a running flash, collapsing the rows and replaying the
+    /// buffered input when it ends.
+    fn tick_flash(&mut self) {
+        if let Some(inputs) = self.flash.tick() {
+            self.game.collapse_rows();
+            for input in inputs {
+                self.play(input);
+            }
+        }
+    }
+
+    /// Handles a key in tuning mode, where the agent plays instead of the user.
+    fn handle_tuning_key(&mut self, code: KeyCode) {
+        let len = weights::NUM_WEIGHTS;
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.weight_index = (self.weight_index + len - 1) % len;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.weight_index = (self.weight_index + 1) % len;
+            }
+            KeyCode::Char('+' | '=') | KeyCode::Right => self.nudge_weight(WEIGHT_STEP),
+            KeyCode::Char('-' | '_') | KeyCode::Left => self.nudge_weight(-WEIGHT_STEP),
+            KeyCode::Char('s') => self.save_weights(),
+            KeyCode::Char('t') | KeyCode::Esc => self.toggle_tuning(),
+            KeyCode::Char('q') => self.quit(),
+            KeyCode::Char('r') => self.restart(),
+            KeyCode::Enter if self.game.phase == GamePhase::GameOver => self.restart(),
+            KeyCode::Char('p') => self.toggle_pause(),
+            other => self.handle_extra_key(other),
+        }
+    }
+}
+
+/// Creates a fresh game with the interactive lock delay and deferred clears
+/// enabled.
+fn new_game() -> GameState {
+    GameState::new()
+        .with_lock_delay(GameState::DEFAULT_LOCK_DELAY)
+        .with_deferred_clears()
 }
 
 impl Default for App {
@@ -44,7 +371,11 @@ impl TuiApp for App {
         self.last_tick
     }
     fn tick_rate(&self) -> Duration {
-        self.tick_rate
+        if self.flash.is_active() {
+            FLASH_TICK_RATE
+        } else {
+            self.tick_rate
+        }
     }
     fn should_quit(&self) -> bool {
         self.should_quit
@@ -55,14 +386,22 @@ impl TuiApp for App {
     }
 
     fn on_tick(&mut self) {
-        if !self.paused && self.game.phase == GamePhase::Falling {
-            self.game.tick();
+        if self.flash.is_active() {
+            self.tick_flash();
+        } else if !self.paused && self.game.phase == GamePhase::Falling {
+            if self.tuning {
+                self.agent_place();
+            } else if self.game.lock_if_expired(Instant::now()).is_none() {
+                self.game.tick();
+            }
+            self.start_flash_if_clearing();
         }
         self.last_tick = Instant::now();
     }
 
     fn restart(&mut self) {
-        self.game = GameState::new();
+        self.game = new_game();
+        self.flash = ClearFlash::default();
         self.last_tick = Instant::now();
         self.paused = false;
     }
@@ -74,42 +413,140 @@ impl TuiApp for App {
     fn toggle_pause(&mut self) {
         if self.game.is_active() {
             self.paused = !self.paused;
+            self.menu_index = 0;
         }
     }
 
     fn move_left(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.move_left();
-        }
+        self.play(Input::Left);
     }
 
     fn move_right(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.move_right();
-        }
+        self.play(Input::Right);
     }
 
     fn soft_drop(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.move_down();
-        }
+        self.play(Input::SoftDrop);
     }
 
     fn hard_drop(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.hard_drop();
-        }
+        self.play(Input::HardDrop);
     }
 
     fn rotate_cw(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.rotate_cw();
-        }
+        self.play(Input::RotateCw);
     }
 
     fn rotate_ccw(&mut self) {
-        if !self.paused && self.game.is_active() {
-            self.game.rotate_ccw();
+        self.play(Input::RotateCcw);
+    }
+
+    fn handle_extra_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('g') => self.show_grid = !self.show_grid,
+            KeyCode::Char('h') => self.show_eval = !self.show_eval,
+            KeyCode::Char('c') => self.toggle_palette(),
+            KeyCode::Char('t') => self.toggle_tuning(),
+            _ => {}
+        }
+    }
+
+    fn menu_open(&self) -> bool {
+        self.paused || self.tuning
+    }
+
+    fn handle_menu_key(&mut self, code: KeyCode) {
+        if !self.paused {
+            self.handle_tuning_key(code);
+            return;
+        }
+        let len = PauseEntry::ALL.len();
+        match code {
+            KeyCode::Up | KeyCode::Char('w' | 'k') => {
+                self.menu_index = (self.menu_index + len - 1) % len;
+            }
+            KeyCode::Down | KeyCode::Char('s' | 'j') => {
+                self.menu_index = (self.menu_index + 1) % len;
+            }
+            KeyCode::Enter => match PauseEntry::ALL[self.menu_index] {
+                PauseEntry::Resume => self.toggle_pause(),
+                PauseEntry::Restart => self.restart(),
+                PauseEntry::Quit => self.quit(),
+            },
+            KeyCode::Char('p') | KeyCode::Esc => self.toggle_pause(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{FallingPiece, Rotation, Tetromino, seed_default_rng};
+
+    fn opening(app: &mut App) -> Vec<Tetromino> {
+        let mut pieces = Vec::new();
+        for _ in 0..5 {
+            pieces.extend(app.game.current.map(|p| p.tetromino));
+            app.game.hard_drop();
+        }
+        pieces
+    }
+
+    #[test]
+    fn palette_key_drops_a_loaded_theme() {
+        let theme: Theme = "I = #102030".parse().expect("valid theme");
+        let mut app = App::new().with_theme(theme);
+
+        app.handle_extra_key(KeyCode::Char('c'));
+        assert_eq!(app.theme, None);
+        assert_eq!(app.color_scheme, ColorScheme::Classic);
+        assert_eq!(app.theme(), Theme::default());
+
+        app.handle_extra_key(KeyCode::Char('c'));
+        assert_eq!(app.color_scheme, ColorScheme::ColorblindSafe);
+    }
+
+    #[test]
+    fn seeded_sessions_deal_the_same_pieces() {
+        seed_default_rng(42);
+        let first = opening(&mut App::new());
+        seed_default_rng(42);
+        let second = opening(&mut App::new());
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 5);
+    }
+
+    #[test]
+    fn cleared_rows_flash_then_collapse_and_replay_input() {
+        let mut app = App::new();
+        app.game = GameState::with_pieces(Tetromino::I, Tetromino::O).with_deferred_clears();
+        for col in 4..10 {
+            app.game.board[0][col] = true;
         }
+        app.game.current = Some(FallingPiece {
+            tetromino: Tetromino::I,
+            rotation: Rotation(0),
+            col: 0,
+            row: 1,
+        });
+
+        app.hard_drop();
+        assert!(app.flash.is_active());
+        assert_eq!(app.tick_rate(), FLASH_TICK_RATE);
+        assert_eq!(app.game.pending_clear, vec![0]);
+
+        app.move_left();
+        for _ in 1..FLASH_TICKS {
+            app.on_tick();
+            assert_eq!(app.game.phase, GamePhase::Clearing);
+        }
+        app.on_tick();
+
+        assert!(!app.flash.is_active());
+        assert_eq!(app.game.phase, GamePhase::Falling);
+        assert!(!app.game.board.is_row_full(0));
+        let spawn = FallingPiece::spawn_on(Tetromino::O, &app.game.board);
+        assert_eq!(app.game.current.map(|p| p.col), Some(spawn.col - 1));
     }
 }
@@ -28,6 +28,14 @@ pub trait TuiApp {
 
     /// Handle keys beyond the standard set. Default is a no-op.
     fn handle_extra_key(&mut self, _code: KeyCode) {}
+
+    /// Whether a menu is open and takes every key. Default is false.
+    fn menu_open(&self) -> bool {
+        false
+    }
+
+    /// Handle a key while the menu is open. Default is a no-op.
+    fn handle_menu_key(&mut self, _code: KeyCode) {}
 }
 
 /// Runs the shared TUI event loop for any [`TuiApp`].
@@ -59,6 +67,11 @@ pub fn run_event_loop(terminal: &mut DefaultTerminal, app: &mut impl TuiApp) ->
 }
 
 fn handle_key(app: &mut impl TuiApp, code: KeyCode) {
+    if app.menu_open() {
+        app.handle_menu_key(code);
+        return;
+    }
+
     match code {
         KeyCode::Char('q') | KeyCode::Esc => app.quit(),
         KeyCode::Char('r') => app.restart(),
@@ -1,11 +1,13 @@
 mod app;
 mod event_loop;
+mod theme;
 pub(crate) mod ui;
 mod versus_app;
 mod versus_ui;
 
-pub use app::App;
+pub use app::{App, DropMode, PauseEntry};
 pub use event_loop::{TuiApp, run_event_loop};
-pub use ui::draw;
-pub use versus_app::VersusApp;
+pub use theme::Theme;
+pub use ui::{ColorScheme, draw};
+pub use versus_app::{Difficulty, VersusApp};
 pub use versus_ui::draw_versus;
@@ -0,0 +1,210 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use ratatui::style::Color;
+
+use crate::game::Tetromino;
+
+use super::ui::{ColorScheme, tetromino_color};
+
+/// Colors used to draw boards and previews.
+///
+/// Built from a [`ColorScheme`] by default, or loaded from a `--theme` file
+/// of `key = value` lines:
+///
+/// ```text
+/// # Piece colors by letter, as #rrggbb or r,g,b
+/// I = #00f0f0
+/// T = 160, 0, 240
+/// locked = #808080
+/// ghost = #404040
+/// grid = #404040
+/// flash = #ffffff
+/// empty = reset
+/// glyphs = true
+/// ```
+///
+/// Keys left out keep their classic color. Values may be quoted, so a flat
+/// `theme.toml` parses too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Piece colors, in [`Tetromino::ALL`] order.
+    pub pieces: [Color; 7],
+    /// Color of locked cells.
+    pub locked: Color,
+    /// Color of the ghost piece.
+    pub ghost: Color,
+    /// Background of empty cells.
+    pub empty: Color,
+    /// Color of the grid dots on empty cells.
+    pub grid: Color,
+    /// Color of rows flashing before they are cleared.
+    pub flash: Color,
+    /// Whether each piece's letter is drawn on its cells.
+    pub glyphs: bool,
+}
+
+impl Theme {
+    /// Returns the colors of a built-in scheme.
+    #[must_use]
+    pub const fn from_scheme(scheme: ColorScheme) -> Self {
+        let mut pieces = [Color::Reset; 7];
+        let mut i = 0;
+        while i < Tetromino::ALL.len() {
+            pieces[i] = tetromino_color(Tetromino::ALL[i], scheme);
+            i += 1;
+        }
+        Self {
+            pieces,
+            locked: Color::Gray,
+            ghost: Color::DarkGray,
+            empty: Color::Reset,
+            grid: Color::DarkGray,
+            flash: Color::White,
+            glyphs: matches!(scheme, ColorScheme::ColorblindSafe),
+        }
+    }
+
+    /// Returns the color of `tetromino`.
+    #[must_use]
+    pub const fn piece(&self, tetromino: Tetromino) -> Color {
+        self.pieces[tetromino as usize]
+    }
+
+    /// Returns the glyph drawn on a piece's cells, if this theme uses one.
+    #[must_use]
+    pub const fn glyph(&self, tetromino: Tetromino) -> Option<char> {
+        if self.glyphs {
+            Some(tetromino.to_char())
+        } else {
+            None
+        }
+    }
+
+    /// Loads a theme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or
+    /// [`io::ErrorKind::InvalidData`] for unknown keys or invalid values.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        fs::read_to_string(path)?.parse().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::from_scheme(ColorScheme::Classic)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut theme = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected key = value", i + 1));
+            };
+            let (key, value) = (key.trim(), value.trim().trim_matches('"'));
+            let color = || parse_color(value).ok_or_else(|| invalid_value(i, value));
+
+            match key {
+                "locked" => theme.locked = color()?,
+                "ghost" => theme.ghost = color()?,
+                "empty" => theme.empty = color()?,
+                "grid" => theme.grid = color()?,
+                "flash" => theme.flash = color()?,
+                "glyphs" => theme.glyphs = value.parse().map_err(|_| invalid_value(i, value))?,
+                _ => {
+                    let mut chars = key.chars();
+                    let piece = chars
+                        .next()
+                        .filter(|_| chars.next().is_none())
+                        .and_then(Tetromino::from_char)
+                        .ok_or_else(|| format!("line {}: unknown key '{key}'", i + 1))?;
+                    theme.pieces[piece as usize] = color()?;
+                }
+            }
+        }
+        Ok(theme)
+    }
+}
+
+fn invalid_value(line_index: usize, value: &str) -> String {
+    format!("line {}: invalid value '{value}'", line_index + 1)
+}
+
+/// Parses `#rrggbb`, `r,g,b` or `reset` (the terminal default).
+fn parse_color(value: &str) -> Option<Color> {
+    if value == "reset" {
+        return Some(Color::Reset);
+    }
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let rgb = u32::from_str_radix(hex, 16).ok()?;
+        let [_, r, g, b] = rgb.to_be_bytes();
+        return Some(Color::Rgb(r, g, b));
+    }
+    let channels: Vec<u8> = value
+        .split(',')
+        .map(|c| c.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    match channels[..] {
+        [r, g, b] => Some(Color::Rgb(r, g, b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_file_parses_into_colors() {
+        let theme: Theme = "\
+# Custom theme
+I = #102030
+T = \"1, 2, 3\"
+ghost = 50,50,50
+flash = #ff0000
+empty = #000000
+glyphs = true
+"
+        .parse()
+        .expect("valid theme");
+
+        assert_eq!(theme.piece(Tetromino::I), Color::Rgb(0x10, 0x20, 0x30));
+        assert_eq!(theme.piece(Tetromino::T), Color::Rgb(1, 2, 3));
+        assert_eq!(theme.ghost, Color::Rgb(50, 50, 50));
+        assert_eq!(theme.flash, Color::Rgb(255, 0, 0));
+        assert_eq!(theme.empty, Color::Rgb(0, 0, 0));
+        assert_eq!(theme.glyph(Tetromino::S), Some('S'));
+
+        let classic = Theme::default();
+        assert_eq!(theme.piece(Tetromino::L), classic.piece(Tetromino::L));
+        assert_eq!((theme.locked, theme.grid), (classic.locked, classic.grid));
+    }
+
+    #[test]
+    fn theme_rejects_bad_lines() {
+        assert!("X = #ffffff".parse::<Theme>().is_err());
+        assert!("I = #fff".parse::<Theme>().is_err());
+        assert!("I = 1,2".parse::<Theme>().is_err());
+        assert!("ghost".parse::<Theme>().is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use ratatui::{
     Frame,
     layout::{Constraint, Flex, Layout, Rect},
@@ -6,41 +8,111 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::game::{Board, FallingPiece, GamePhase, Tetromino};
+use crate::eval_fns::{eval_labels, feature_vector};
+use crate::game::{Board, FallingPiece, GameOverReason, GamePhase, Tetromino};
+use crate::weights;
 
-use super::App;
+use super::{App, PauseEntry, Theme};
 
 /// Info panel width.
 pub const INFO_PANEL_WIDTH: u16 = 20;
 
+/// Eval-debug panel width.
+const EVAL_PANEL_WIDTH: u16 = 34;
+
+/// Minimum width of the game board area.
+const MIN_GAME_WIDTH: u16 = 24;
+
 /// Minimum cell dimensions.
 const MIN_CELL_WIDTH: u16 = 2;
 const MIN_CELL_HEIGHT: u16 = 1;
 
-/// Returns the color for a tetromino type.
-pub const fn tetromino_color(tetromino: Tetromino) -> Color {
-    match tetromino {
-        Tetromino::I => Color::Cyan,
-        Tetromino::O => Color::Yellow,
-        Tetromino::T => Color::Magenta,
-        Tetromino::S => Color::Green,
-        Tetromino::Z => Color::Red,
-        Tetromino::J => Color::Blue,
-        Tetromino::L => Color::LightRed, // Orange-ish
+/// Palette used to draw pieces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    /// Standard guideline colors.
+    #[default]
+    Classic,
+    /// Okabe-Ito colors, with each piece's letter drawn on its cells so
+    /// pieces can be told apart without relying on hue.
+    ColorblindSafe,
+}
+
+impl ColorScheme {
+    /// Returns the other scheme.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Classic => Self::ColorblindSafe,
+            Self::ColorblindSafe => Self::Classic,
+        }
+    }
+
+    /// Returns the glyph drawn on a piece's cells, if this scheme uses one.
+    #[must_use]
+    pub const fn glyph(self, tetromino: Tetromino) -> Option<char> {
+        match self {
+            Self::Classic => None,
+            Self::ColorblindSafe => Some(tetromino.to_char()),
+        }
     }
 }
 
-/// Calculates optimal cell dimensions to fit the board in the given area.
+impl FromStr for ColorScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Self::Classic),
+            "colorblind" => Ok(Self::ColorblindSafe),
+            other => Err(format!(
+                "unknown palette '{other}': expected classic or colorblind"
+            )),
+        }
+    }
+}
+
+/// Returns the color for a tetromino type in the given scheme.
+///
+/// Classic uses named colors so 16- and 256-color terminals draw them
+/// faithfully; image export uses [`Tetromino::rgb`] instead.
+pub const fn tetromino_color(tetromino: Tetromino, scheme: ColorScheme) -> Color {
+    match scheme {
+        ColorScheme::Classic => match tetromino {
+            Tetromino::I => Color::Cyan,
+            Tetromino::O => Color::Yellow,
+            Tetromino::T => Color::Magenta,
+            Tetromino::S => Color::Green,
+            Tetromino::Z => Color::Red,
+            Tetromino::J => Color::Blue,
+            Tetromino::L => Color::LightRed, // Orange-ish
+        },
+        ColorScheme::ColorblindSafe => {
+            let (r, g, b) = match tetromino {
+                Tetromino::I => (86, 180, 233),
+                Tetromino::O => (240, 228, 66),
+                Tetromino::T => (204, 121, 167),
+                Tetromino::S => (0, 158, 115),
+                Tetromino::Z => (213, 94, 0),
+                Tetromino::J => (0, 114, 178),
+                Tetromino::L => (230, 159, 0),
+            };
+            Color::Rgb(r, g, b)
+        }
+    }
+}
+
+/// Calculates optimal cell dimensions to fit `board` in the given area.
 /// Returns `(cell_width, cell_height)` that maintains roughly square cells.
 #[allow(clippy::cast_possible_truncation)]
-fn calculate_cell_size(area: Rect) -> (u16, u16) {
+fn calculate_cell_size(area: Rect, board: &Board) -> (u16, u16) {
     // Available space (subtract 2 for borders)
     let available_width = area.width.saturating_sub(2);
     let available_height = area.height.saturating_sub(2);
 
     // Calculate max cell size that fits
-    let max_cell_width = available_width / Board::WIDTH as u16;
-    let max_cell_height = available_height / Board::HEIGHT as u16;
+    let max_cell_width = available_width / board.width() as u16;
+    let max_cell_height = available_height / board.height() as u16;
 
     // Terminal chars are ~2x taller than wide, so ideal ratio is width = height * 2
     // Find the best fit that maintains aspect ratio
@@ -65,13 +137,32 @@ fn calculate_cell_size(area: Rect) -> (u16, u16) {
 
 /// Main draw function for the TUI.
 pub fn draw(frame: &mut Frame, app: &App) {
-    let area = frame.area();
+    let mut area = frame.area();
+
+    // Only show the eval or tuning panel if it fits next to the board and info panel
+    if (app.show_eval || app.tuning)
+        && area.width >= MIN_GAME_WIDTH + INFO_PANEL_WIDTH + EVAL_PANEL_WIDTH
+    {
+        let [rest, eval_area] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(EVAL_PANEL_WIDTH)])
+                .split(area)[..]
+        else {
+            return;
+        };
+        if app.tuning {
+            draw_tuning_panel(frame, app, eval_area);
+        } else {
+            draw_eval_panel(frame, app, eval_area);
+        }
+        area = rest;
+    }
 
     // Main layout: game area (fill) | info panel (right)
-    let [game_area, info_area] =
-        Layout::horizontal([Constraint::Min(24), Constraint::Length(INFO_PANEL_WIDTH)]).split(area)
-            [..]
-    else {
+    let [game_area, info_area] = Layout::horizontal([
+        Constraint::Min(MIN_GAME_WIDTH),
+        Constraint::Length(INFO_PANEL_WIDTH),
+    ])
+    .split(area)[..] else {
         return;
     };
 
@@ -80,9 +171,9 @@ pub fn draw(frame: &mut Frame, app: &App) {
 
     // Draw overlays for game over or pause
     if app.game.phase == GamePhase::GameOver {
-        draw_game_over(frame, game_area);
+        draw_game_over(frame, game_area, app.game.game_over_reason);
     } else if app.paused {
-        draw_paused(frame, game_area);
+        draw_pause_menu(frame, game_area, app.menu_index);
     }
 }
 
@@ -98,11 +189,18 @@ fn draw_board(frame: &mut Frame, app: &App, area: Rect) {
         ghost_cells.as_ref(),
         area,
         " TETRIS ",
+        app.show_grid,
+        &app.theme(),
+        &app.game.pending_clear,
     );
 }
 
 /// Renders a board with optional current and ghost pieces into the given area.
-#[allow(clippy::cast_possible_truncation)]
+///
+/// With `show_grid`, empty cells get a faint dot in their top-left corner so
+/// column and row alignment stays visible. Rows in `flash_rows` are drawn
+/// solid white.
+#[allow(clippy::cast_possible_truncation, clippy::too_many_arguments)]
 pub fn render_board(
     frame: &mut Frame,
     board: &Board,
@@ -110,12 +208,15 @@ pub fn render_board(
     ghost: Option<&[(i8, i8); 4]>,
     area: Rect,
     title: &str,
+    show_grid: bool,
+    theme: &Theme,
+    flash_rows: &[usize],
 ) {
-    let (cell_width, cell_height) = calculate_cell_size(area);
+    let (cell_width, cell_height) = calculate_cell_size(area, board);
 
     // Calculate actual board dimensions
-    let board_width = Board::WIDTH as u16 * cell_width + 2;
-    let board_height = Board::HEIGHT as u16 * cell_height + 2;
+    let board_width = board.width() as u16 * cell_width + 2;
+    let board_height = board.height() as u16 * cell_height + 2;
 
     // Center the board
     let centered = center_rect(area, board_width, board_height);
@@ -129,20 +230,28 @@ pub fn render_board(
     frame.render_widget(block, centered);
 
     // Build the display line by line
-    let mut lines: Vec<Line> = Vec::with_capacity(Board::HEIGHT * cell_height as usize);
+    let mut lines: Vec<Line> = Vec::with_capacity(board.height() * cell_height as usize);
 
-    for display_row in 0..Board::HEIGHT {
-        let board_row = Board::HEIGHT - 1 - display_row;
+    for display_row in 0..board.height() {
+        let board_row = board.height() - 1 - display_row;
 
         // Generate cell_height lines for this row
-        for _line_in_cell in 0..cell_height {
-            let mut spans: Vec<Span> = Vec::with_capacity(Board::WIDTH);
-
-            for col in 0..Board::WIDTH {
-                let (cell_type, color) = get_cell_appearance(board, col, board_row, current, ghost);
-
-                let cell_text = render_cell(cell_type, cell_width);
-                spans.push(styled_span(cell_text, cell_type, color));
+        for line_in_cell in 0..cell_height {
+            let mut spans: Vec<Span> = Vec::with_capacity(board.width());
+            let grid_line = show_grid && line_in_cell == 0;
+            let flashing = flash_rows.contains(&board_row);
+
+            for col in 0..board.width() {
+                let (cell_type, color, glyph) = if flashing {
+                    (CellType::Filled, Some(theme.flash), None)
+                } else {
+                    get_cell_appearance(board, col, board_row, current, ghost, theme)
+                };
+                // Tall cells only carry the glyph on their first line
+                let glyph = glyph.filter(|_| line_in_cell == 0);
+
+                let cell_text = render_cell(cell_type, cell_width, grid_line, glyph);
+                spans.push(styled_span(cell_text, cell_type, color, glyph, theme));
             }
 
             lines.push(Line::from(spans));
@@ -154,15 +263,27 @@ pub fn render_board(
 }
 
 /// Creates a styled span for a cell.
-fn styled_span(text: String, cell_type: CellType, color: Option<Color>) -> Span<'static> {
+/// Filled cells with a glyph are drawn as a dark letter on the piece color,
+/// and empty cells use the theme's grid and background colors.
+fn styled_span(
+    text: String,
+    cell_type: CellType,
+    color: Option<Color>,
+    glyph: Option<char>,
+    theme: &Theme,
+) -> Span<'static> {
     match cell_type {
-        CellType::Empty => Span::raw(text),
+        CellType::Empty => Span::styled(text, Style::default().fg(theme.grid).bg(theme.empty)),
+        CellType::Filled if glyph.is_some() => {
+            let c = color.unwrap_or(Color::White);
+            Span::styled(text, Style::default().fg(Color::Black).bg(c))
+        }
         CellType::Filled => {
             let c = color.unwrap_or(Color::White);
             Span::styled(text, Style::default().fg(c))
         }
         CellType::Ghost => {
-            let c = color.unwrap_or(Color::DarkGray);
+            let c = color.unwrap_or(theme.ghost);
             Span::styled(text, Style::default().fg(c))
         }
     }
@@ -187,7 +308,8 @@ fn center_rect(area: Rect, width: u16, height: u16) -> Rect {
     centered
 }
 
-/// Determines what to display for a cell.
+/// Determines what to display for a cell: its type, color, and the glyph
+/// the theme draws on it. The ghost shares the current piece's glyph.
 #[allow(clippy::cast_possible_truncation)]
 fn get_cell_appearance(
     board: &Board,
@@ -195,21 +317,21 @@ fn get_cell_appearance(
     board_row: usize,
     current_cells: Option<&([(i8, i8); 4], Tetromino)>,
     ghost_cells: Option<&[(i8, i8); 4]>,
-) -> (CellType, Option<Color>) {
+    theme: &Theme,
+) -> (CellType, Option<Color>, Option<char>) {
+    let pos = (col as i8, board_row as i8);
+    let glyph = current_cells.and_then(|(_, tetromino)| theme.glyph(*tetromino));
+
     if board[board_row][col] {
-        (CellType::Filled, Some(Color::Gray))
-    } else if let Some((cells, tetromino)) = current_cells {
-        if cells.contains(&(col as i8, board_row as i8)) {
-            (CellType::Filled, Some(tetromino_color(*tetromino)))
-        } else if ghost_cells.is_some_and(|g| g.contains(&(col as i8, board_row as i8))) {
-            (CellType::Ghost, Some(Color::DarkGray))
-        } else {
-            (CellType::Empty, None)
-        }
-    } else if ghost_cells.is_some_and(|g| g.contains(&(col as i8, board_row as i8))) {
-        (CellType::Ghost, Some(Color::DarkGray))
+        (CellType::Filled, Some(theme.locked), None)
+    } else if let Some((cells, tetromino)) = current_cells
+        && cells.contains(&pos)
+    {
+        (CellType::Filled, Some(theme.piece(*tetromino)), glyph)
+    } else if ghost_cells.is_some_and(|g| g.contains(&pos)) {
+        (CellType::Ghost, Some(theme.ghost), glyph)
     } else {
-        (CellType::Empty, None)
+        (CellType::Empty, None, None)
     }
 }
 
@@ -221,12 +343,52 @@ enum CellType {
 }
 
 /// Renders a cell using block characters.
-fn render_cell(cell_type: CellType, width: u16) -> String {
-    match cell_type {
-        CellType::Empty => " ".repeat(width as usize),
-        CellType::Filled => "█".repeat(width as usize),
-        CellType::Ghost => "░".repeat(width as usize),
+/// Empty cells on a grid line start with a dot marking the cell corner, and
+/// piece cells with a glyph start with that glyph.
+fn render_cell(cell_type: CellType, width: u16, grid_line: bool, glyph: Option<char>) -> String {
+    let rest = width as usize - 1;
+    match (cell_type, glyph) {
+        (CellType::Empty, _) if grid_line => format!("·{}", " ".repeat(rest)),
+        (CellType::Empty, _) => " ".repeat(width as usize),
+        (CellType::Filled, Some(g)) => format!("{g}{}", " ".repeat(rest)),
+        (CellType::Filled, None) => "█".repeat(width as usize),
+        (CellType::Ghost, Some(g)) => format!("{g}{}", "░".repeat(rest)),
+        (CellType::Ghost, None) => "░".repeat(width as usize),
+    }
+}
+
+/// Builds the preview lines for a piece in its spawn orientation.
+pub fn preview_lines(tetromino: Tetromino, theme: &Theme) -> Vec<Line<'static>> {
+    let cells = FallingPiece::spawn(tetromino).cells();
+
+    let min_col = cells.iter().map(|(c, _)| *c).min().unwrap_or(0);
+    let max_col = cells.iter().map(|(c, _)| *c).max().unwrap_or(0);
+    let min_row = cells.iter().map(|(_, r)| *r).min().unwrap_or(0);
+    let max_row = cells.iter().map(|(_, r)| *r).max().unwrap_or(0);
+
+    let color = theme.piece(tetromino);
+    let glyph = theme.glyph(tetromino);
+    let mut lines: Vec<Line> = Vec::new();
+
+    for row in (min_row..=max_row).rev() {
+        let mut spans: Vec<Span> = Vec::new();
+        for col in min_col..=max_col {
+            if cells.contains(&(col, row)) {
+                let text = render_cell(CellType::Filled, 2, false, glyph);
+                spans.push(styled_span(
+                    text,
+                    CellType::Filled,
+                    Some(color),
+                    glyph,
+                    theme,
+                ));
+            } else {
+                spans.push(Span::raw("  "));
+            }
+        }
+        lines.push(Line::from(spans));
     }
+    lines
 }
 
 /// Draws the info panel.
@@ -259,30 +421,7 @@ fn draw_next_piece(frame: &mut Frame, app: &App, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let piece = FallingPiece::spawn(app.game.next);
-    let cells = piece.cells();
-
-    // NOTE: duplicate logic with board.rs/visualize_cells; could refactor?
-    let min_col = cells.iter().map(|(c, _)| *c).min().unwrap_or(0);
-    let max_col = cells.iter().map(|(c, _)| *c).max().unwrap_or(0);
-    let min_row = cells.iter().map(|(_, r)| *r).min().unwrap_or(0);
-    let max_row = cells.iter().map(|(_, r)| *r).max().unwrap_or(0);
-
-    let color = tetromino_color(app.game.next);
-    let mut lines: Vec<Line> = Vec::new();
-
-    for row in (min_row..=max_row).rev() {
-        let mut spans: Vec<Span> = Vec::new();
-        for col in min_col..=max_col {
-            if cells.contains(&(col, row)) {
-                spans.push(Span::styled("██", Style::default().fg(color)));
-            } else {
-                spans.push(Span::raw("  "));
-            }
-        }
-        lines.push(Line::from(spans));
-    }
-
+    let lines = preview_lines(app.game.next, &app.theme());
     let paragraph = Paragraph::new(lines).centered();
     frame.render_widget(paragraph, inner);
 }
@@ -297,7 +436,7 @@ fn draw_score(frame: &mut Frame, app: &App, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let score = app.game.rows_cleared * 100;
+    let score = app.game.rows_cleared * 100 + app.game.score;
     let paragraph = Paragraph::new(format!("{score}"))
         .centered()
         .style(Style::default().fg(Color::White).bold());
@@ -320,6 +459,94 @@ fn draw_lines(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, inner);
 }
 
+/// Draws the eval-debug panel: each heuristic's raw value on the current
+/// board and its weighted contribution for the loaded weights.
+fn draw_eval_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .title(" Eval ")
+        .title_style(Style::default().fg(Color::Cyan));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Value (4) and contribution (8) columns plus separators take 14 chars
+    let name_width = usize::from(inner.width).saturating_sub(14);
+    let values = feature_vector(&app.game.board);
+    let mut total = 0.0;
+    let mut lines: Vec<Line> = Vec::with_capacity(values.len() + 3);
+    lines.push(Line::from(""));
+
+    for (i, (name, &value)) in eval_labels().iter().zip(values.iter()).enumerate() {
+        let name: String = name.chars().take(name_width).collect();
+        let contribution = app.weights.map(|w| f64::from(value) * w[i]);
+        total += contribution.unwrap_or(0.0);
+
+        let (text, color) = match contribution {
+            Some(c) if c < 0.0 => (format!("{c:.2}"), Color::Red),
+            Some(c) => (format!("{c:.2}"), Color::Green),
+            None => ("-".to_string(), Color::DarkGray),
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("{name:<name_width$} {value:>4} ")),
+            Span::styled(format!("{text:>8}"), Style::default().fg(color)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let total = app
+        .weights
+        .map_or_else(|| "-".to_string(), |_| format!("{total:.2}"));
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("{:<w$}", "Score", w = name_width + 6),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(format!("{total:>8}"), Style::default().bold()),
+    ]));
+
+    let paragraph = Paragraph::new(lines);
+    frame.render_widget(paragraph, inner);
+}
+
+/// Draws the tuning panel: every weight with the selected one highlighted,
+/// plus the keys for editing and saving them.
+fn draw_tuning_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .title(" Tuning ")
+        .title_style(Style::default().fg(Color::Cyan));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Weight column (8) plus separator takes 9 chars
+    let name_width = usize::from(inner.width).saturating_sub(9);
+    let weights = app.weights.unwrap_or([0.0; weights::NUM_WEIGHTS]);
+    let mut lines: Vec<Line> = Vec::with_capacity(weights.len() + 5);
+    lines.push(Line::from(""));
+
+    for (i, (name, weight)) in eval_labels().iter().zip(weights.iter()).enumerate() {
+        let name: String = name.chars().take(name_width).collect();
+        let text = format!("{name:<name_width$} {weight:>8.3}");
+        lines.push(if i == app.weight_index {
+            Line::from(text.black().on_yellow())
+        } else {
+            Line::from(text)
+        });
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from("↑↓ select  +/- adjust".dark_gray()));
+    lines.push(Line::from("S save  T/Esc exit".dark_gray()));
+    if let Some(status) = &app.status {
+        lines.push(Line::from(status.as_str().yellow()));
+    }
+
+    let paragraph = Paragraph::new(lines);
+    frame.render_widget(paragraph, inner);
+}
+
 /// Draws the controls help.
 fn draw_controls(frame: &mut Frame, area: Rect) {
     let block = Block::default()
@@ -352,6 +579,22 @@ fn draw_controls(frame: &mut Frame, area: Rect) {
             Span::raw("Rotate CCW"),
         ]),
         Line::from(""),
+        Line::from(vec![
+            Span::styled("G ", Style::default().fg(Color::Yellow)),
+            Span::raw("Grid"),
+        ]),
+        Line::from(vec![
+            Span::styled("H ", Style::default().fg(Color::Yellow)),
+            Span::raw("Eval panel"),
+        ]),
+        Line::from(vec![
+            Span::styled("C ", Style::default().fg(Color::Yellow)),
+            Span::raw("Palette"),
+        ]),
+        Line::from(vec![
+            Span::styled("T ", Style::default().fg(Color::Yellow)),
+            Span::raw("Tune weights"),
+        ]),
         Line::from(vec![
             Span::styled("P ", Style::default().fg(Color::Yellow)),
             Span::raw("Pause"),
@@ -371,7 +614,7 @@ fn draw_controls(frame: &mut Frame, area: Rect) {
 }
 
 /// Draws a game over overlay.
-fn draw_game_over(frame: &mut Frame, area: Rect) {
+fn draw_game_over(frame: &mut Frame, area: Rect, reason: Option<GameOverReason>) {
     let popup_area = center_rect(area, 24, 9);
 
     let bg = Block::default().style(Style::default().bg(Color::Black));
@@ -385,7 +628,7 @@ fn draw_game_over(frame: &mut Frame, area: Rect) {
     let text = vec![
         Line::from(""),
         Line::from("GAME OVER".bold().red()),
-        Line::from(""),
+        Line::from(reason.map(|r| r.to_string()).unwrap_or_default()),
         Line::from(""),
         Line::from(vec![
             Span::styled("R", Style::default().fg(Color::Green)),
@@ -401,9 +644,9 @@ fn draw_game_over(frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, popup_area);
 }
 
-/// Draws a paused overlay.
-fn draw_paused(frame: &mut Frame, area: Rect) {
-    let popup_area = center_rect(area, 20, 7);
+/// Draws the pause menu with the entry at `selected` highlighted.
+fn draw_pause_menu(frame: &mut Frame, area: Rect, selected: usize) {
+    let popup_area = center_rect(area, 20, 10);
 
     let bg = Block::default().style(Style::default().bg(Color::Black));
     frame.render_widget(bg, popup_area);
@@ -413,15 +656,21 @@ fn draw_paused(frame: &mut Frame, area: Rect) {
         .border_style(Style::default().fg(Color::Yellow))
         .title(" Paused ");
 
-    let text = vec![
+    let mut text = vec![
         Line::from(""),
         Line::from("PAUSED".bold().yellow()),
         Line::from(""),
-        Line::from(vec![
-            Span::styled("P", Style::default().fg(Color::Yellow)),
-            Span::raw(" Resume"),
-        ]),
     ];
+    for (i, entry) in PauseEntry::ALL.iter().enumerate() {
+        let line = if i == selected {
+            Line::from(format!("> {} <", entry.label()).black().on_yellow())
+        } else {
+            Line::from(entry.label())
+        };
+        text.push(line);
+    }
+    text.push(Line::from(""));
+    text.push(Line::from("↑↓ Enter".dark_gray()));
 
     let paragraph = Paragraph::new(text).centered().block(block);
     frame.render_widget(paragraph, popup_area);
@@ -1,16 +1,95 @@
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use ratatui::Frame;
 use ratatui::crossterm::event::KeyCode;
 
-use crate::agent::find_best_move;
-use crate::game::{Board, GamePhase, GameState, MoveResult, Tetromino};
+use crate::agent::find_best_move_noisy;
+use crate::game::{Board, GamePhase, GameState, MoveResult, Tetromino, garbage_lines};
 use crate::weights;
 
 use super::event_loop::TuiApp;
+use super::theme::Theme;
+use super::ui::ColorScheme;
 use super::versus_ui;
 
+/// How strongly the versus agent plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    /// The agent always plays its best placement.
+    #[default]
+    Hard,
+}
+
+impl Difficulty {
+    /// Standard deviation of the noise added to the agent's placement scores.
+    #[must_use]
+    pub const fn temperature(self) -> f64 {
+        match self {
+            Self::Easy => 20.0,
+            Self::Medium => 5.0,
+            Self::Hard => 0.0,
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Self::Easy),
+            "medium" => Ok(Self::Medium),
+            "hard" => Ok(Self::Hard),
+            other => Err(format!(
+                "unknown difficulty '{other}': expected easy, medium, or hard"
+            )),
+        }
+    }
+}
+
+/// Length of the countdown before each versus game.
+pub const COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// How long "GO" stays on screen once play has started.
+pub const GO_SHOWN: Duration = Duration::from_millis(500);
+
+/// Start sequence of a versus game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Seconds left before play starts. Input and gravity are on hold.
+    Countdown(u8),
+    /// Play has just started and "GO" is still shown.
+    Go,
+    Playing,
+}
+
+impl Phase {
+    /// The phase `elapsed` after the countdown started.
+    #[must_use]
+    pub fn at(elapsed: Duration) -> Self {
+        match COUNTDOWN.checked_sub(elapsed) {
+            Some(left) if !left.is_zero() =>
+            {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Self::Countdown(left.as_secs_f64().ceil() as u8)
+            }
+            _ if elapsed < COUNTDOWN + GO_SHOWN => Self::Go,
+            _ => Self::Playing,
+        }
+    }
+
+    /// Whether pieces fall and respond to input.
+    #[must_use]
+    pub const fn is_live(self) -> bool {
+        !matches!(self, Self::Countdown(_))
+    }
+}
+
 /// Application state for the versus mode: user vs agent.
+#[allow(clippy::struct_excessive_bools)]
 pub struct VersusApp {
     pub user_game: GameState,
     pub agent_board: Board,
@@ -21,6 +100,13 @@ pub struct VersusApp {
     pub tick_rate: Duration,
     pub should_quit: bool,
     pub paused: bool,
+    pub show_grid: bool,
+    pub color_scheme: ColorScheme,
+    /// Colors loaded with `--theme`, overriding the palette's.
+    pub theme: Option<Theme>,
+    pub difficulty: Difficulty,
+    /// When the countdown of the current game started.
+    pub started_at: Instant,
 }
 
 impl VersusApp {
@@ -28,7 +114,7 @@ impl VersusApp {
     #[must_use]
     pub fn new(weights: [f64; weights::NUM_WEIGHTS]) -> Self {
         Self {
-            user_game: GameState::new(),
+            user_game: new_game(),
             agent_board: Board::new(),
             agent_rows_cleared: 0,
             agent_game_over: false,
@@ -37,47 +123,130 @@ impl VersusApp {
             tick_rate: Duration::from_millis(500),
             should_quit: false,
             paused: false,
+            show_grid: false,
+            color_scheme: ColorScheme::Classic,
+            theme: None,
+            difficulty: Difficulty::Hard,
+            started_at: Instant::now(),
         }
     }
 
+    /// Sets how strongly the agent plays.
+    #[must_use]
+    pub const fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the palette used to draw pieces.
+    #[must_use]
+    pub const fn with_color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = color_scheme;
+        self
+    }
+
+    /// Draws with `theme` instead of the palette's colors.
+    #[must_use]
+    pub const fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the loaded theme, or the colors of the current palette.
+    #[must_use]
+    pub fn theme(&self) -> Theme {
+        self.theme
+            .unwrap_or_else(|| Theme::from_scheme(self.color_scheme))
+    }
+
+    /// Drops a loaded theme to show the current palette, or switches to the
+    /// other palette when no theme is loaded.
+    pub const fn toggle_palette(&mut self) {
+        if self.theme.take().is_none() {
+            self.color_scheme = self.color_scheme.toggled();
+        }
+    }
+
+    /// Current phase of the start sequence.
+    #[must_use]
+    pub fn phase(&self) -> Phase {
+        Phase::at(self.started_at.elapsed())
+    }
+
+    /// Whether the user's moves should be applied right now.
+    fn accepts_input(&self) -> bool {
+        !self.paused && self.user_game.is_active() && self.phase().is_live()
+    }
+
     /// Syncs the agent board to match the user's current state.
-    pub const fn sync_agent(&mut self) {
-        self.agent_board = self.user_game.board;
+    pub fn sync_agent(&mut self) {
+        self.agent_board = self.user_game.board.clone();
         self.agent_rows_cleared = self.user_game.rows_cleared;
         self.agent_game_over = false;
     }
 
     /// After any user action that may lock a piece, feed the same piece to the agent.
+    ///
+    /// Line clears on either side send garbage to the opponent.
     fn handle_lock(&mut self, result: MoveResult, piece: Option<Tetromino>) {
-        if matches!(result, MoveResult::Locked { .. })
-            && let Some(tetromino) = piece
+        let MoveResult::Locked { rows_cleared, .. } = result else {
+            return;
+        };
+
+        self.send_garbage_to_agent(garbage_lines(rows_cleared));
+
+        if let Some(tetromino) = piece {
+            let agent_cleared = self.agent_place(tetromino);
+            let mut rng = rand::rng();
+            self.user_game
+                .receive_garbage(garbage_lines(agent_cleared), &mut rng);
+        }
+    }
+
+    /// Pushes garbage rows onto the agent board, ending its game on a top-out.
+    fn send_garbage_to_agent(&mut self, count: u32) {
+        if self.agent_game_over || count == 0 {
+            return;
+        }
+        let mut rng = rand::rng();
+        if self
+            .agent_board
+            .add_garbage_rows(count as usize, None, &mut rng)
         {
-            self.agent_place(tetromino);
+            self.agent_game_over = true;
         }
     }
 
-    /// Lets the agent place the given piece optimally.
-    fn agent_place(&mut self, piece: Tetromino) {
+    /// Lets the agent place the given piece, as well as its difficulty allows.
+    /// Returns the number of rows the agent cleared.
+    fn agent_place(&mut self, piece: Tetromino) -> u32 {
         if self.agent_game_over {
-            return;
+            return 0;
         }
-        match find_best_move(
+        let mut rng = rand::rng();
+        if let Some((board, rows_cleared)) = find_best_move_noisy(
             &self.agent_board,
             piece,
             &self.weights,
             weights::NUM_WEIGHTS,
+            self.difficulty.temperature(),
+            &mut rng,
         ) {
-            Some((board, rows_cleared)) => {
-                self.agent_board = board;
-                self.agent_rows_cleared += rows_cleared;
-            }
-            None => {
-                self.agent_game_over = true;
-            }
+            self.agent_board = board;
+            self.agent_rows_cleared += rows_cleared;
+            rows_cleared
+        } else {
+            self.agent_game_over = true;
+            0
         }
     }
 }
 
+/// Creates a fresh user game with the interactive lock delay enabled.
+fn new_game() -> GameState {
+    GameState::new().with_lock_delay(GameState::DEFAULT_LOCK_DELAY)
+}
+
 impl TuiApp for VersusApp {
     fn game_phase(&self) -> GamePhase {
         self.user_game.phase
@@ -97,21 +266,25 @@ impl TuiApp for VersusApp {
     }
 
     fn on_tick(&mut self) {
-        if !self.paused && self.user_game.phase == GamePhase::Falling {
+        if !self.paused && self.user_game.phase == GamePhase::Falling && self.phase().is_live() {
             let piece = self.user_game.current.map(|p| p.tetromino);
-            let result = self.user_game.tick();
+            let result = self
+                .user_game
+                .lock_if_expired(Instant::now())
+                .unwrap_or_else(|| self.user_game.tick());
             self.handle_lock(result, piece);
         }
         self.last_tick = Instant::now();
     }
 
     fn restart(&mut self) {
-        self.user_game = GameState::new();
+        self.user_game = new_game();
         self.agent_board = Board::new();
         self.agent_rows_cleared = 0;
         self.agent_game_over = false;
         self.last_tick = Instant::now();
         self.paused = false;
+        self.started_at = Instant::now();
     }
 
     fn quit(&mut self) {
@@ -119,25 +292,25 @@ impl TuiApp for VersusApp {
     }
 
     fn toggle_pause(&mut self) {
-        if self.user_game.is_active() {
+        if self.user_game.is_active() && self.phase().is_live() {
             self.paused = !self.paused;
         }
     }
 
     fn move_left(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             self.user_game.move_left();
         }
     }
 
     fn move_right(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             self.user_game.move_right();
         }
     }
 
     fn soft_drop(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             let piece = self.user_game.current.map(|p| p.tetromino);
             let result = self.user_game.move_down();
             self.handle_lock(result, piece);
@@ -145,7 +318,7 @@ impl TuiApp for VersusApp {
     }
 
     fn hard_drop(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             let piece = self.user_game.current.map(|p| p.tetromino);
             let result = self.user_game.hard_drop();
             self.handle_lock(result, piece);
@@ -153,20 +326,43 @@ impl TuiApp for VersusApp {
     }
 
     fn rotate_cw(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             self.user_game.rotate_cw();
         }
     }
 
     fn rotate_ccw(&mut self) {
-        if !self.paused && self.user_game.is_active() {
+        if self.accepts_input() {
             self.user_game.rotate_ccw();
         }
     }
 
     fn handle_extra_key(&mut self, code: KeyCode) {
-        if code == KeyCode::Backspace {
-            self.sync_agent();
+        match code {
+            KeyCode::Backspace => self.sync_agent(),
+            KeyCode::Char('g') => self.show_grid = !self.show_grid,
+            KeyCode::Char('c') => self.toggle_palette(),
+            _ => {}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_ticks_down_then_goes_live() {
+        let at = |millis| Phase::at(Duration::from_millis(millis));
+
+        assert_eq!(at(0), Phase::Countdown(3));
+        assert_eq!(at(999), Phase::Countdown(3));
+        assert_eq!(at(1000), Phase::Countdown(2));
+        assert_eq!(at(2500), Phase::Countdown(1));
+        assert_eq!(at(3000), Phase::Go);
+        assert_eq!(at(3499), Phase::Go);
+        assert_eq!(at(3500), Phase::Playing);
+        assert!(!at(2999).is_live());
+        assert!(at(3000).is_live());
+    }
+}
@@ -6,10 +6,10 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::game::{FallingPiece, GamePhase};
+use crate::game::{FallingPiece, GameOverReason, GamePhase};
 
-use super::ui::{INFO_PANEL_WIDTH, render_board, tetromino_color};
-use super::versus_app::VersusApp;
+use super::ui::{INFO_PANEL_WIDTH, preview_lines, render_board};
+use super::versus_app::{Phase, VersusApp};
 
 /// Main draw function for versus mode.
 pub fn draw_versus(frame: &mut Frame, app: &VersusApp) {
@@ -26,6 +26,7 @@ pub fn draw_versus(frame: &mut Frame, app: &VersusApp) {
     };
 
     // User board with current piece + ghost
+    let theme = app.theme();
     let ghost_cells = app.user_game.ghost_piece().map(FallingPiece::cells);
     let current_cells = app.user_game.current.map(|p| (p.cells(), p.tetromino));
     render_board(
@@ -35,6 +36,9 @@ pub fn draw_versus(frame: &mut Frame, app: &VersusApp) {
         ghost_cells.as_ref(),
         user_area,
         " USER ",
+        app.show_grid,
+        &theme,
+        &[],
     );
 
     // Agent board (no falling piece)
@@ -43,19 +47,60 @@ pub fn draw_versus(frame: &mut Frame, app: &VersusApp) {
     } else {
         " AGENT "
     };
-    render_board(frame, &app.agent_board, None, None, agent_area, agent_title);
+    render_board(
+        frame,
+        &app.agent_board,
+        None,
+        None,
+        agent_area,
+        agent_title,
+        app.show_grid,
+        &theme,
+        &[],
+    );
 
     // Center info panel
     draw_versus_info(frame, app, info_area);
 
     // Overlays
     if app.user_game.phase == GamePhase::GameOver {
-        draw_versus_game_over(frame, user_area);
+        draw_versus_game_over(frame, user_area, app.user_game.game_over_reason);
     } else if app.paused {
         draw_versus_paused(frame, user_area);
+    } else {
+        match app.phase() {
+            Phase::Countdown(remaining) => {
+                draw_countdown(frame, area, &remaining.to_string(), Color::Yellow);
+            }
+            Phase::Go => draw_countdown(frame, area, "GO", Color::Green),
+            Phase::Playing => {}
+        }
     }
 }
 
+/// Draws the start countdown centered over both boards.
+fn draw_countdown(frame: &mut Frame, area: Rect, label: &str, color: Color) {
+    let popup_area = center_popup(area, 12, 5);
+
+    let bg = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(bg, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            label.to_string(),
+            Style::default().fg(color).bold(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text).centered().block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
 /// Draws the center info panel for versus mode.
 fn draw_versus_info(frame: &mut Frame, app: &VersusApp, area: Rect) {
     let block = Block::default().borders(Borders::LEFT | Borders::RIGHT);
@@ -86,29 +131,7 @@ fn draw_next_piece(frame: &mut Frame, app: &VersusApp, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let piece = FallingPiece::spawn(app.user_game.next);
-    let cells = piece.cells();
-
-    let min_col = cells.iter().map(|(c, _)| *c).min().unwrap_or(0);
-    let max_col = cells.iter().map(|(c, _)| *c).max().unwrap_or(0);
-    let min_row = cells.iter().map(|(_, r)| *r).min().unwrap_or(0);
-    let max_row = cells.iter().map(|(_, r)| *r).max().unwrap_or(0);
-
-    let color = tetromino_color(app.user_game.next);
-    let mut lines: Vec<Line> = Vec::new();
-
-    for row in (min_row..=max_row).rev() {
-        let mut spans: Vec<Span> = Vec::new();
-        for col in min_col..=max_col {
-            if cells.contains(&(col, row)) {
-                spans.push(Span::styled("██", Style::default().fg(color)));
-            } else {
-                spans.push(Span::raw("  "));
-            }
-        }
-        lines.push(Line::from(spans));
-    }
-
+    let lines = preview_lines(app.user_game.next, &app.theme());
     let paragraph = Paragraph::new(lines).centered();
     frame.render_widget(paragraph, inner);
 }
@@ -210,6 +233,14 @@ fn draw_versus_controls(frame: &mut Frame, area: Rect) {
             Span::styled("⌫ ", Style::default().fg(Color::Yellow)),
             Span::raw("Sync agent"),
         ]),
+        Line::from(vec![
+            Span::styled("G ", Style::default().fg(Color::Yellow)),
+            Span::raw("Grid"),
+        ]),
+        Line::from(vec![
+            Span::styled("C ", Style::default().fg(Color::Yellow)),
+            Span::raw("Palette"),
+        ]),
         Line::from(vec![
             Span::styled("P ", Style::default().fg(Color::Yellow)),
             Span::raw("Pause"),
@@ -229,7 +260,7 @@ fn draw_versus_controls(frame: &mut Frame, area: Rect) {
 }
 
 /// Draws a game over overlay on the user board.
-fn draw_versus_game_over(frame: &mut Frame, area: Rect) {
+fn draw_versus_game_over(frame: &mut Frame, area: Rect, reason: Option<GameOverReason>) {
     let popup_area = center_popup(area, 24, 9);
 
     let bg = Block::default().style(Style::default().bg(Color::Black));
@@ -243,7 +274,7 @@ fn draw_versus_game_over(frame: &mut Frame, area: Rect) {
     let text = vec![
         Line::from(""),
         Line::from("GAME OVER".bold().red()),
-        Line::from(""),
+        Line::from(reason.map(|r| r.to_string()).unwrap_or_default()),
         Line::from(""),
         Line::from(vec![
             Span::styled("R", Style::default().fg(Color::Green)),
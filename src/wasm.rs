@@ -0,0 +1,123 @@
+//! Minimal entry point for embedding the agent, e.g. in a WebAssembly build.
+//!
+//! Everything here uses plain strings and slices so it can be wrapped by
+//! `wasm-bindgen` or any other FFI layer. It only depends on the core library,
+//! so it compiles with `--no-default-features` for `wasm32-unknown-unknown`.
+
+use crate::agent::simulator::{Placement, find_best_placement};
+use crate::game::{Board, Tetromino};
+use crate::weights;
+
+/// Parses a board from text, top row first.
+///
+/// `#` or `1` marks an occupied cell and `.` or `0` an empty one. Whitespace
+/// is ignored, so rows may be separated by newlines. Exactly
+/// `Board::WIDTH * Board::HEIGHT` cells are required.
+///
+/// # Errors
+///
+/// Returns an error on unknown characters or a wrong cell count.
+pub fn parse_board(text: &str) -> Result<Board, String> {
+    let cells: Vec<bool> = text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '#' | '1' => Ok(true),
+            '.' | '0' => Ok(false),
+            other => Err(format!("invalid board cell '{other}'")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    if cells.len() != Board::WIDTH * Board::HEIGHT {
+        return Err(format!(
+            "expected {} board cells, found {}",
+            Board::WIDTH * Board::HEIGHT,
+            cells.len()
+        ));
+    }
+
+    let mut board = Board::new();
+    for (i, row) in cells.chunks(Board::WIDTH).enumerate() {
+        board[Board::HEIGHT - 1 - i].copy_from_slice(row);
+    }
+    Ok(board)
+}
+
+/// Parses a piece from its letter (`I`, `O`, `T`, `S`, `Z`, `J` or `L`).
+///
+/// # Errors
+///
+/// Returns an error for any other character.
+pub fn parse_piece(letter: char) -> Result<Tetromino, String> {
+    Tetromino::from_char(letter).ok_or_else(|| format!("invalid piece '{letter}'"))
+}
+
+/// Chooses the agent's placement for `piece` on a serialized `board`.
+///
+/// `weights` must hold exactly [`weights::NUM_WEIGHTS`] values. Returns
+/// `Ok(None)` if the piece cannot be placed anywhere.
+///
+/// # Errors
+///
+/// Returns an error if the board, piece or weights are malformed.
+pub fn best_placement(
+    board: &str,
+    piece: char,
+    weights: &[f64],
+) -> Result<Option<Placement>, String> {
+    let board = parse_board(board)?;
+    let piece = parse_piece(piece)?;
+    let weights: [f64; weights::NUM_WEIGHTS] = weights.try_into().map_err(|_| {
+        format!(
+            "expected {} weights, found {}",
+            weights::NUM_WEIGHTS,
+            weights.len()
+        )
+    })?;
+    Ok(find_best_placement(
+        &board,
+        piece,
+        &weights,
+        weights::NUM_WEIGHTS,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_board_text() -> String {
+        ".".repeat(Board::WIDTH * Board::HEIGHT)
+    }
+
+    #[test]
+    fn parse_board_reads_top_row_first() {
+        let mut text = empty_board_text();
+        // Last character is the bottom-right cell
+        text.replace_range(text.len() - 1.., "#");
+        let board = parse_board(&text).expect("valid board");
+        assert!(board[0][Board::WIDTH - 1]);
+        assert_eq!(board.cell_count(), 1);
+    }
+
+    #[test]
+    fn parse_board_rejects_bad_input() {
+        assert!(parse_board("##").is_err());
+        assert!(parse_board(&empty_board_text().replace('.', "x")).is_err());
+    }
+
+    #[test]
+    fn best_placement_lands_on_the_floor() {
+        let weights = [-1.0; weights::NUM_WEIGHTS];
+        let placement = best_placement(&empty_board_text(), 'o', &weights)
+            .expect("valid input")
+            .expect("piece fits");
+        assert_eq!(placement.piece.row, 0);
+        assert_eq!(placement.board.cell_count(), 4);
+    }
+
+    #[test]
+    fn best_placement_rejects_wrong_weight_count() {
+        assert!(best_placement(&empty_board_text(), 'T', &[1.0]).is_err());
+    }
+}
@@ -1,44 +1,172 @@
-use std::fmt::Write as _;
-use std::path::Path;
-use std::{fs, io};
+use std::fmt;
+use std::io::{self, Read};
+#[cfg(feature = "native")]
+use std::{fmt::Write as _, fs, path::Path};
+
+use crate::agent::ScoringMode;
 
 /// Number of evaluation function weights.
-pub const NUM_WEIGHTS: usize = 16;
+pub const NUM_WEIGHTS: usize = 27;
+
+/// Weight counts of files written by earlier releases, before newer
+/// evaluators were appended.
+pub const OLDER_COUNTS: [usize; 1] = [16];
+
+/// Errors produced while loading a weights file.
+#[derive(Debug)]
+pub enum WeightsError {
+    /// The file holds neither [`NUM_WEIGHTS`] values nor one of the
+    /// [`OLDER_COUNTS`].
+    WrongCount { found: usize },
+    /// A value is not a finite float (1-based line number).
+    NonFinite { line: usize, value: String },
+    /// The `# scoring:` header names an unknown [`ScoringMode`].
+    BadMode { mode: String },
+    /// The underlying reader failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for WeightsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongCount { found } => {
+                write!(f, "expected {NUM_WEIGHTS} weights, found {found}")
+            }
+            Self::NonFinite { line, value } => {
+                write!(f, "line {line}: {value:?} is not a finite weight")
+            }
+            Self::BadMode { mode } => {
+                write!(
+                    f,
+                    "unknown scoring mode {mode:?}: expected rows or survival"
+                )
+            }
+            Self::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for WeightsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WeightsError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<WeightsError> for io::Error {
+    fn from(e: WeightsError) -> Self {
+        match e {
+            WeightsError::Io(e) => e,
+            other => Self::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
+
+/// Path that [`load`] reads from stdin instead of a file.
+pub const STDIN_PATH: &str = "-";
+
+/// Comment prefix recording the [`ScoringMode`] a weights file was optimized for.
+const SCORING_HEADER: &str = "# scoring:";
+
+/// Loads weights from a text file, or from stdin if `path` is [`STDIN_PATH`].
+///
+/// Values are separated by whitespace or newlines, and lines starting with
+/// `#` are skipped.
+/// Files written by earlier releases hold one of the [`OLDER_COUNTS`]; the
+/// weights of evaluators added since then are zero.
+///
+/// # Errors
+///
+/// Returns [`WeightsError::Io`] if the file cannot be read,
+/// [`WeightsError::NonFinite`] for values that are not finite floats,
+/// [`WeightsError::WrongCount`] for any other number of values,
+/// and [`WeightsError::BadMode`] for an unknown `# scoring:` header.
+#[cfg(feature = "native")]
+pub fn load(path: &Path) -> Result<[f64; NUM_WEIGHTS], WeightsError> {
+    load_with_scoring(path).map(|(weights, _)| weights)
+}
 
-/// Loads weights from a text file.
+/// Like [`load`], also returning the mode from the file's `# scoring:`
+/// header, if it has one.
 ///
-/// Lines starting with `#` are skipped when parsing weight values.
+/// # Errors
+///
+/// Returns the same errors as [`load`].
+#[cfg(feature = "native")]
+pub fn load_with_scoring(
+    path: &Path,
+) -> Result<([f64; NUM_WEIGHTS], Option<ScoringMode>), WeightsError> {
+    if path == Path::new(STDIN_PATH) {
+        return load_from_with_scoring(io::stdin().lock());
+    }
+    load_from_with_scoring(fs::File::open(path)?)
+}
+
+/// Loads weights in the [`load`] format from any reader.
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be read, contains non-float values,
-/// or does not contain exactly [`NUM_WEIGHTS`] values.
-pub fn load(path: &Path) -> io::Result<[f64; NUM_WEIGHTS]> {
-    let contents = fs::read_to_string(path)?;
+/// Returns the same errors as [`load`].
+pub fn load_from<R: Read>(reader: R) -> Result<[f64; NUM_WEIGHTS], WeightsError> {
+    load_from_with_scoring(reader).map(|(weights, _)| weights)
+}
 
-    let values: Vec<f64> = contents
-        .lines()
-        .filter(|l| {
-            let t = l.trim();
-            !t.is_empty() && !t.starts_with('#')
-        })
-        .map(|l| {
-            l.trim()
-                .parse::<f64>()
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })
-        .collect::<io::Result<Vec<f64>>>()?;
+/// Like [`load_from`], also returning the mode from a `# scoring:` header.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load`].
+pub fn load_from_with_scoring<R: Read>(
+    mut reader: R,
+) -> Result<([f64; NUM_WEIGHTS], Option<ScoringMode>), WeightsError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+
+    let mut scoring = None;
+    let mut values = Vec::new();
+    for (i, l) in contents.lines().enumerate() {
+        let t = l.trim();
+        if let Some(mode) = t.strip_prefix(SCORING_HEADER) {
+            let mode = mode.trim();
+            scoring = Some(mode.parse().map_err(|_| WeightsError::BadMode {
+                mode: mode.to_string(),
+            })?);
+            continue;
+        }
+        if t.starts_with('#') {
+            continue;
+        }
+        for token in t.split_whitespace() {
+            match token.parse::<f64>() {
+                Ok(v) if v.is_finite() => values.push(v),
+                _ => {
+                    return Err(WeightsError::NonFinite {
+                        line: i + 1,
+                        value: token.to_string(),
+                    });
+                }
+            }
+        }
+    }
 
-    if values.len() != NUM_WEIGHTS {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!("expected {NUM_WEIGHTS} weights, found {}", values.len()),
-        ));
+    if values.len() != NUM_WEIGHTS && !OLDER_COUNTS.contains(&values.len()) {
+        return Err(WeightsError::WrongCount {
+            found: values.len(),
+        });
     }
 
+    // Evaluators are only ever appended, so older files map onto a prefix
     let mut weights = [0.0; NUM_WEIGHTS];
-    weights.copy_from_slice(&values);
-    Ok(weights)
+    weights[..values.len()].copy_from_slice(&values);
+    Ok((weights, scoring))
 }
 
 /// Saves weights to a text file.
@@ -46,10 +174,172 @@ pub fn load(path: &Path) -> io::Result<[f64; NUM_WEIGHTS]> {
 /// # Errors
 ///
 /// Returns an error if the file cannot be written.
+#[cfg(feature = "native")]
 pub fn save(path: &Path, weights: &[f64; NUM_WEIGHTS]) -> io::Result<()> {
+    save_with_scoring(path, weights, None)
+}
+
+/// Like [`save`], recording the mode the weights were optimized for in a
+/// `# scoring:` header that [`load_with_scoring`] reads back.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+#[cfg(feature = "native")]
+pub fn save_with_scoring(
+    path: &Path,
+    weights: &[f64; NUM_WEIGHTS],
+    scoring: Option<ScoringMode>,
+) -> io::Result<()> {
     let mut contents = String::new();
+    if let Some(scoring) = scoring {
+        let _ = writeln!(contents, "{SCORING_HEADER} {scoring}");
+    }
     for w in weights {
         let _ = writeln!(contents, "{w}");
     }
     fs::write(path, contents)
 }
+
+/// Rescales `weights` in place to unit L2 norm.
+///
+/// All-zero vectors are left untouched. Since the agent picks the highest
+/// scoring placement, scaling never changes its behavior; this only makes
+/// weight vectors from different runs comparable.
+pub fn normalize(weights: &mut [f64; NUM_WEIGHTS]) {
+    let norm = weights.iter().map(|w| w * w).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for w in weights.iter_mut() {
+            *w /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_fns::calculate_weighted_score;
+    use crate::game::Board;
+
+    fn sample_weights() -> [f64; NUM_WEIGHTS] {
+        std::array::from_fn(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f64;
+            (x - 7.0) * 1.5
+        })
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn load_from_skips_comments() {
+        let text: String = std::iter::once("# header\n".to_string())
+            .chain((0..NUM_WEIGHTS).map(|i| format!("{i}.5\n")))
+            .collect();
+        let w = load_from(text.as_bytes()).expect("valid weights");
+        assert!((w[0] - 0.5).abs() < f64::EPSILON);
+        assert!((w[NUM_WEIGHTS - 1] - (NUM_WEIGHTS as f64 - 0.5)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn load_from_pads_older_files_with_zeros() {
+        let text = "2.0\n".repeat(OLDER_COUNTS[0]);
+        let w = load_from(text.as_bytes()).expect("weights of an earlier release");
+        assert!(
+            w[..OLDER_COUNTS[0]]
+                .iter()
+                .all(|&x| (x - 2.0).abs() < f64::EPSILON)
+        );
+        assert!(w[OLDER_COUNTS[0]..].iter().all(|&x| x == 0.0));
+    }
+
+    #[test]
+    fn load_from_accepts_whitespace_separated_values() {
+        let text = format!("0.1 0.2\t-3\n\n  4e-1\n{}", "0 ".repeat(NUM_WEIGHTS - 4));
+        let w = load_from(text.as_bytes()).expect("piped weights");
+        assert_eq!(w[..4], [0.1, 0.2, -3.0, 0.4]);
+        let err = load_from(&b"0.1 inf 0.3"[..]).expect_err("infinite weight");
+        assert!(matches!(err, WeightsError::NonFinite { line: 1, ref value } if value == "inf"));
+    }
+
+    #[test]
+    fn load_from_rejects_wrong_count() {
+        let too_many = "1.0\n".repeat(NUM_WEIGHTS + 1);
+        let err = load_from(too_many.as_bytes()).expect_err("too many weights");
+        assert!(matches!(err, WeightsError::WrongCount { found } if found == NUM_WEIGHTS + 1));
+        let err = load_from("1.0\n".repeat(5).as_bytes()).expect_err("truncated file");
+        assert!(matches!(err, WeightsError::WrongCount { found: 5 }));
+        let err = load_from(&b"# only a comment\n"[..]).expect_err("no weights");
+        assert!(matches!(err, WeightsError::WrongCount { found: 0 }));
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn load_from_rejects_non_finite_values() {
+        let err = load_from(&b"# header\n1.0\nNaN\n"[..]).expect_err("NaN weight");
+        assert!(matches!(err, WeightsError::NonFinite { line: 3, ref value } if value == "NaN"));
+        let err = load_from(&b"abc\n"[..]).expect_err("non-float weight");
+        assert!(matches!(err, WeightsError::NonFinite { line: 1, .. }));
+    }
+
+    #[test]
+    fn load_from_reads_the_scoring_header() {
+        let values = "1.0\n".repeat(NUM_WEIGHTS);
+        let text = format!("# scoring: survival\n{values}");
+        let (w, scoring) = load_from_with_scoring(text.as_bytes()).expect("valid header");
+        assert_eq!(scoring, Some(ScoringMode::Survival));
+        assert!((w[0] - 1.0).abs() < f64::EPSILON);
+        let (_, scoring) = load_from_with_scoring(values.as_bytes()).expect("no header");
+        assert_eq!(scoring, None);
+    }
+
+    #[test]
+    fn load_from_rejects_bad_mode() {
+        let err = load_from(&b"# scoring: lines\n1.0\n"[..]).expect_err("unknown mode");
+        assert!(matches!(err, WeightsError::BadMode { ref mode } if mode == "lines"));
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn normalize_gives_unit_norm() {
+        let mut w = sample_weights();
+        normalize(&mut w);
+        let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn normalize_leaves_zero_vector_untouched() {
+        let mut w = [0.0; NUM_WEIGHTS];
+        normalize(&mut w);
+        assert!(w.iter().all(|&x| x == 0.0));
+    }
+
+    #[test]
+    fn normalize_preserves_placement_ordering() {
+        let boards: Vec<Board> = (0..Board::HEIGHT)
+            .map(|height| {
+                let mut cells = [[false; Board::WIDTH]; Board::HEIGHT];
+                for (row, cells_row) in cells.iter_mut().enumerate().take(height) {
+                    for (col, cell) in cells_row.iter_mut().enumerate() {
+                        *cell = (row + col + height) % 3 != 0;
+                    }
+                }
+                Board::from_cells(&cells)
+            })
+            .collect();
+
+        let raw = sample_weights();
+        let mut unit = raw;
+        normalize(&mut unit);
+
+        let order = |w: &[f64; NUM_WEIGHTS]| {
+            let mut idx: Vec<usize> = (0..boards.len()).collect();
+            idx.sort_by(|&a, &b| {
+                calculate_weighted_score(&boards[a], w)
+                    .total_cmp(&calculate_weighted_score(&boards[b], w))
+            });
+            idx
+        };
+        assert_eq!(order(&raw), order(&unit));
+    }
+}
@@ -0,0 +1,68 @@
+//! Deterministic fuzzing of evaluators and the agent over random boards.
+
+use harmonomino::agent::simulator::find_best_move;
+use harmonomino::eval_fns::{calculate_weighted_score, get_all_evaluators};
+use harmonomino::game::{Board, Tetromino};
+use harmonomino::weights;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SEED: u64 = 0x5EED;
+const BOARD_COUNT: usize = 2000;
+
+/// The agent is far slower than a single evaluation, so it sees every Nth board.
+const AGENT_STRIDE: usize = 10;
+
+/// Builds a board with each cell filled independently with probability `density`.
+/// Full rows are cleared, as they never survive a lock in a real game.
+fn random_board(rng: &mut StdRng, density: f64) -> Board {
+    let mut cells = [[false; Board::WIDTH]; Board::HEIGHT];
+    for row in &mut cells {
+        for cell in row.iter_mut() {
+            *cell = rng.random_bool(density);
+        }
+    }
+    let mut board = Board::from_cells(&cells);
+    board.clear_full_rows();
+    board
+}
+
+fn random_weights(rng: &mut StdRng) -> [f64; weights::NUM_WEIGHTS] {
+    std::array::from_fn(|_| rng.random_range(-1.0..=1.0))
+}
+
+/// Yields boards across the whole density range, including empty and full.
+fn boards() -> impl Iterator<Item = (Board, [f64; weights::NUM_WEIGHTS])> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    (0..BOARD_COUNT).map(move |i| {
+        #[allow(clippy::cast_precision_loss)]
+        let density = i as f64 / (BOARD_COUNT - 1) as f64;
+        (random_board(&mut rng, density), random_weights(&mut rng))
+    })
+}
+
+#[test]
+fn evaluators_never_panic_and_scores_are_finite() {
+    let evaluators = get_all_evaluators();
+    for (board, w) in boards() {
+        for evaluator in &evaluators {
+            let _ = evaluator.eval(&board);
+        }
+        let score = calculate_weighted_score(&board, &w);
+        assert!(score.is_finite(), "non-finite score {score} for {board:?}");
+    }
+}
+
+#[test]
+fn agent_never_panics_on_random_boards() {
+    for (board, w) in boards().step_by(AGENT_STRIDE) {
+        for piece in Tetromino::ALL {
+            if let Some((next, rows_cleared)) =
+                find_best_move(&board, piece, &w, weights::NUM_WEIGHTS)
+            {
+                assert!(rows_cleared <= 4, "cleared {rows_cleared} rows at once");
+                assert!((0..Board::HEIGHT).all(|row| !next.is_row_full(row)));
+            }
+        }
+    }
+}